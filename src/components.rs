@@ -19,5 +19,5 @@ mod skeleton_loading;
 
 pub use card::Card;
 pub use dialog::*;
-pub use kv_delegate::{INDEX_COLUMN_NAME, ZedisKvDelegate, ZedisKvFetcher};
+pub use kv_delegate::{DecodeInput, INDEX_COLUMN_NAME, ZedisKvDelegate, ZedisKvFetcher};
 pub use skeleton_loading::SkeletonLoading;
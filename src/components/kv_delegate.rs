@@ -13,7 +13,8 @@
 // limitations under the License.
 
 use crate::assets::CustomIconName;
-use crate::states::{RedisValue, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common};
+use crate::helpers::RedisBytes;
+use crate::states::{RedisValue, ZedisGlobalStore, ZedisServerState, decode_element, dialog_button_props, i18n_common};
 use crate::views::{KvTableColumn, KvTableColumnType};
 use gpui::{App, Edges, Entity, SharedString, Window, div, prelude::*, px};
 use gpui_component::{
@@ -29,6 +30,99 @@ use std::{cell::Cell, collections::HashMap, rc::Rc, sync::Arc};
 
 pub const INDEX_COLUMN_NAME: &str = "#";
 
+/// Maximum number of chars of the single-line preview of a decoded cell
+const PREVIEW_MAX_CHARS: usize = 200;
+
+/// A cell decoded by the value decoders (compressed, MessagePack, Protobuf, ...)
+#[derive(Clone, Debug)]
+pub struct DecodedCell {
+    /// Decode chain of the cell, e.g. `gzip › json`
+    pub format: SharedString,
+    /// Decoded text, shown in the detail pane
+    pub text: SharedString,
+    /// Single-line form of the text, shown in the cell
+    pub preview: SharedString,
+}
+
+/// An element of the selected key to decode, collected on the UI thread and decoded in the background.
+///
+/// It is also the key of the decoded cells, so a cell is decoded again only when its value changes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DecodeInput {
+    server_id: SharedString,
+    key: SharedString,
+    /// Field of a hash value
+    field: Option<RedisBytes>,
+    value: RedisBytes,
+    max_truncate_length: usize,
+}
+
+impl DecodeInput {
+    /// Creates the input of an element of the selected key, `field` is the field of a hash value.
+    pub fn new(
+        server_state: &Entity<ZedisServerState>,
+        field: Option<&RedisBytes>,
+        value: &RedisBytes,
+        cx: &App,
+    ) -> Option<Self> {
+        let state = server_state.read(cx);
+        let key = state.key()?;
+        Some(Self {
+            server_id: state.server_id().to_string().into(),
+            key: key.display_str().to_string().into(),
+            field: field.cloned(),
+            value: value.clone(),
+            max_truncate_length: cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length(),
+        })
+    }
+}
+
+/// Decode state of a cell
+#[derive(Clone, Debug)]
+enum CellDecode {
+    /// Decoding in the background, the value is shown as is meanwhile
+    Pending,
+    Done(Option<DecodedCell>),
+}
+
+impl DecodedCell {
+    /// Decodes the element with the value decoders, this may be slow and must not run on the UI thread.
+    ///
+    /// Returns `None` for plain text and for formats without text (e.g. images).
+    fn decode(input: &DecodeInput) -> Option<Self> {
+        let decoded = decode_element(
+            &input.server_id,
+            &input.key,
+            input.field.as_ref().map(|field| field.display_str()),
+            input.value.as_bytes(),
+            input.max_truncate_length,
+        )?;
+        if decoded.is_plain_text() {
+            return None;
+        }
+        let text = decoded.text.clone()?;
+        Some(Self {
+            format: decoded.format_chain().into(),
+            preview: to_preview(&text),
+            text,
+        })
+    }
+}
+
+/// Converts the decoded text to a single line, pretty-printed JSON is compacted.
+fn to_preview(text: &str) -> SharedString {
+    let preview = match serde_json::from_str::<serde_json::Value>(text) {
+        Ok(value) => value.to_string(),
+        Err(_) => text.lines().map(str::trim).collect::<Vec<_>>().join(" "),
+    };
+    if preview.chars().count() <= PREVIEW_MAX_CHARS {
+        return preview.into();
+    }
+    let mut preview = preview.chars().take(PREVIEW_MAX_CHARS).collect::<String>();
+    preview.push('…');
+    preview.into()
+}
+
 /// Trait defining the data fetching and manipulation interface for Key-Value data.
 /// Implementers allow the `ZedisKvDelegate` to display and edit various Redis data types (Hash, Set, List, ZSet).
 pub trait ZedisKvFetcher: 'static {
@@ -38,6 +132,11 @@ pub trait ZedisKvFetcher: 'static {
     /// Retrieves a value for a specific cell in the table.
     fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString>;
 
+    /// Returns the value of a cell to decode with the value decoders, `None` for cells shown as is.
+    fn decode_input(&self, _row_ix: usize, _col_ix: usize, _cx: &App) -> Option<DecodeInput> {
+        None
+    }
+
     /// Returns the total count of items available.
     fn count(&self) -> usize;
 
@@ -88,6 +187,10 @@ pub trait ZedisKvFetcher: 'static {
 
 pub type OnEditHandler = Box<dyn Fn(usize, Vec<SharedString>, &mut Window, &mut App) + 'static>;
 
+/// Receives the values of the value columns of a row, with the decode chain of the decoded ones.
+pub type OnDetailHandler =
+    Box<dyn Fn(usize, Vec<(SharedString, Option<SharedString>)>, &mut Window, &mut App) + 'static>;
+
 /// A Table Delegate that manages the display and editing of Key-Value pairs.
 /// It bridges the UI (Table) and the Data Source (ZedisKvFetcher).
 pub struct ZedisKvDelegate<T: ZedisKvFetcher> {
@@ -109,6 +212,10 @@ pub struct ZedisKvDelegate<T: ZedisKvFetcher> {
     edit_focus_done: bool,
     /// Callback function to be called when editing a row.
     on_edit: Option<OnEditHandler>,
+    /// Callback function to be called when expanding the decoded values of a row.
+    on_detail: Option<OnDetailHandler>,
+    /// Decoded cells of the selected key, keyed by their value.
+    decoded_cells: HashMap<DecodeInput, CellDecode>,
 }
 
 impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
@@ -160,6 +267,8 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
             edit_focus_done: false,
             readonly: false,
             on_edit: None,
+            on_detail: None,
+            decoded_cells: HashMap::new(),
         }
     }
 
@@ -171,6 +280,10 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
         self.on_edit = on_edit;
     }
 
+    pub fn set_on_detail(&mut self, on_detail: Option<OnDetailHandler>) {
+        self.on_detail = on_detail;
+    }
+
     /// Returns a cloned Arc reference to the current fetcher.
     pub fn fetcher(&self) -> Arc<T> {
         self.fetcher.clone()
@@ -183,6 +296,77 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
         self.processing = Rc::new(Cell::new(false));
     }
 
+    /// Returns the decoded cell if it has been decoded.
+    fn cached_decoded_cell(&self, row_ix: usize, col_ix: usize, cx: &App) -> Option<DecodedCell> {
+        let input = self.fetcher.decode_input(row_ix, col_ix, cx)?;
+        match self.decoded_cells.get(&input)? {
+            CellDecode::Done(decoded) => decoded.clone(),
+            CellDecode::Pending => None,
+        }
+    }
+
+    /// Returns the decoded cell, a cell not decoded yet is decoded in the background
+    /// and the table is refreshed when it is done.
+    fn decoded_cell(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        cx: &mut Context<TableState<Self>>,
+    ) -> Option<DecodedCell> {
+        let input = self.fetcher.decode_input(row_ix, col_ix, cx)?;
+        if let Some(state) = self.decoded_cells.get(&input) {
+            return match state {
+                CellDecode::Done(decoded) => decoded.clone(),
+                CellDecode::Pending => None,
+            };
+        }
+        // the cells of the previous key are not shown again
+        if self
+            .decoded_cells
+            .keys()
+            .next()
+            .is_some_and(|cached| cached.server_id != input.server_id || cached.key != input.key)
+        {
+            self.decoded_cells.clear();
+        }
+        self.decoded_cells.insert(input.clone(), CellDecode::Pending);
+        cx.spawn(async move |handle, cx| {
+            let task_input = input.clone();
+            let decoded = cx
+                .background_spawn(async move { DecodedCell::decode(&task_input) })
+                .await;
+            let _ = handle.update(cx, |this, cx| {
+                // skipped if the cache was cleared for another key meanwhile
+                if let Some(state) = this.delegate_mut().decoded_cells.get_mut(&input) {
+                    *state = CellDecode::Done(decoded);
+                    cx.notify();
+                }
+            });
+        })
+        .detach();
+        None
+    }
+
+    /// Returns the sorted indices of the value columns.
+    fn value_columns(&self) -> Vec<usize> {
+        let mut columns = self.value_states.keys().cloned().collect::<Vec<_>>();
+        columns.sort_unstable();
+        columns
+    }
+
+    /// Returns the values of the value columns of a row for the detail pane,
+    /// decoded cells are replaced by their text.
+    fn get_detail_values(&self, row_ix: usize, cx: &App) -> Vec<(SharedString, Option<SharedString>)> {
+        let fetcher = self.fetcher();
+        self.value_columns()
+            .into_iter()
+            .map(|col_ix| match self.cached_decoded_cell(row_ix, col_ix, cx) {
+                Some(decoded) => (decoded.text, Some(decoded.format)),
+                None => (fetcher.get(row_ix, col_ix).unwrap_or_default(), None),
+            })
+            .collect()
+    }
+
     /// Exits edit mode and resets related state flags.
     fn reset_edit(&mut self) {
         self.edit_focus_done = false;
@@ -262,6 +446,28 @@ impl<T: ZedisKvFetcher> ZedisKvDelegate<T> {
         let processing = self.processing.clone();
         let mut base = base;
 
+        // Detail button (only shown if a value of the row is decoded)
+        let has_decoded_cell = self
+            .value_columns()
+            .into_iter()
+            .any(|col_ix| self.decoded_cell(row_ix, col_ix, cx).is_some());
+        if self.on_detail.is_some() && has_decoded_cell && !is_editing {
+            let detail_btn = Button::new(("zedis-editor-table-action-detail-btn", row_ix))
+                .small()
+                .ghost()
+                .mr_2()
+                .icon(Icon::new(CustomIconName::Binary))
+                .tooltip(i18n_common(cx, "detail_tooltip"))
+                .on_click(cx.listener(move |this, _, window, cx| {
+                    let values = this.delegate().get_detail_values(row_ix, cx);
+                    if let Some(on_detail_handler) = this.delegate().on_detail.as_ref() {
+                        on_detail_handler(row_ix, values, window, cx);
+                    }
+                    cx.stop_propagation();
+                }));
+            base = base.child(detail_btn);
+        }
+
         // Edit/Save button (only shown if fetcher supports updates)
         if self.fetcher.can_update() {
             let icon = if is_editing {
@@ -438,9 +644,23 @@ impl<T: ZedisKvFetcher + 'static> TableDelegate for ZedisKvDelegate<T> {
             return base.child(Input::new(value_state).small().cleanable(true));
         }
 
+        // Decoded values are shown as a single-line preview with their decode chain
+        let align = column.align;
+        if let Some(decoded) = self.decoded_cell(row_ix, col_ix, cx) {
+            return base
+                .gap_2()
+                .child(
+                    Label::new(decoded.format)
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .flex_none(),
+                )
+                .child(Label::new(decoded.preview).text_align(align));
+        }
+
         // Default: Render value as label
         let value = self.fetcher.get(row_ix, col_ix).unwrap_or_else(|| "--".into());
-        base.child(Label::new(value).text_align(column.align))
+        base.child(Label::new(value).text_align(align))
     }
     /// Returns whether all data has been loaded (end of file).
     fn is_eof(&self, _: &App) -> bool {
@@ -21,7 +21,7 @@ use super::{
     ssh_cluster_connection::SshMultiplexedConnection,
 };
 use crate::error::Error;
-use crate::helpers::TtlCache;
+use crate::helpers::{RedisKey, TtlCache};
 use gpui::SharedString;
 use redis::{Cmd, FromRedisValue, InfoDict, Role, aio::MultiplexedConnection, cluster, cmd};
 use semver::Version;
@@ -290,8 +290,8 @@ impl RedisClient {
     /// * `pattern` - The pattern to match keys.
     /// * `count` - The count of keys to return.
     /// # Returns
-    /// * `(Vec<u64>, Vec<SharedString>)` - A tuple containing the new cursors and the keys.
-    pub async fn first_scan(&self, pattern: &str, count: u64) -> Result<(Vec<u64>, Vec<SharedString>)> {
+    /// * `(Vec<u64>, Vec<RedisKey>)` - A tuple containing the new cursors and the raw keys.
+    pub async fn first_scan(&self, pattern: &str, count: u64) -> Result<(Vec<u64>, Vec<RedisKey>)> {
         let master_count = self.count_masters()?;
         let cursors = vec![0; master_count];
 
@@ -304,8 +304,8 @@ impl RedisClient {
     /// * `pattern` - The pattern to match keys.
     /// * `count` - The count of keys to return.
     /// # Returns
-    /// * `(Vec<u64>, Vec<SharedString>)` - A tuple containing the new cursors and the keys.
-    pub async fn scan(&self, cursors: Vec<u64>, pattern: &str, count: u64) -> Result<(Vec<u64>, Vec<SharedString>)> {
+    /// * `(Vec<u64>, Vec<RedisKey>)` - A tuple containing the new cursors and the raw keys.
+    pub async fn scan(&self, cursors: Vec<u64>, pattern: &str, count: u64) -> Result<(Vec<u64>, Vec<RedisKey>)> {
         debug!("scan, cursors: {cursors:?}, pattern: {pattern}, count: {count}");
         let cmds: Vec<Cmd> = cursors
             .iter()
@@ -324,11 +324,8 @@ impl RedisClient {
         let mut keys = Vec::with_capacity(values[0].1.len() * values.len());
         for (cursor, keys_in_node) in values {
             cursors.push(cursor);
-            keys.extend(
-                keys_in_node
-                    .iter()
-                    .map(|k| String::from_utf8_lossy(k).to_string().into()),
-            );
+            // Keep the raw bytes, keys are not required to be valid UTF-8
+            keys.extend(keys_in_node.into_iter().map(RedisKey::from));
         }
         keys.sort_unstable();
         Ok((cursors, keys))
@@ -18,14 +18,20 @@ use redb::{Database, TableDefinition};
 use std::sync::OnceLock;
 use tracing::debug;
 
+mod avros;
+mod decoder_rules;
 mod history_manager;
 mod protos;
 
+pub use avros::*;
+pub use decoder_rules::*;
 pub use history_manager::*;
 pub use protos::*;
 
 const HISTORY_TABLE: TableDefinition<&str, &str> = TableDefinition::new("search_history");
 const PROTO_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("proto");
+const DECODER_RULE_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("decoder_rule");
+const AVRO_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("avro");
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
@@ -50,6 +56,8 @@ pub fn init_database() -> Result<()> {
     {
         write_txn.open_table(HISTORY_TABLE)?;
         write_txn.open_table(PROTO_TABLE)?;
+        write_txn.open_table(DECODER_RULE_TABLE)?;
+        write_txn.open_table(AVRO_TABLE)?;
     }
     write_txn.commit()?;
     debug!(path = db_path.display().to_string(), "database initialized success");
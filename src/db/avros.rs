@@ -0,0 +1,211 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{AVRO_TABLE, MatchMode, MatchPatternCache, get_database};
+use crate::error::Error;
+use crate::helpers::resolve_path;
+use apache_avro::{Schema, from_avro_datum, to_avro_datum, types::Value as AvroValue};
+use dashmap::DashMap;
+use redb::{ReadableDatabase, ReadableTable};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+use tracing::info;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+static AVRO_META_CACHE: LazyLock<DashMap<String, AvroConfig>> = LazyLock::new(DashMap::new);
+
+/// Compiled match patterns of the avro configs
+static AVRO_PATTERN_CACHE: LazyLock<MatchPatternCache> = LazyLock::new(MatchPatternCache::default);
+
+/// Magic byte of the schema registry wire format
+const SCHEMA_REGISTRY_MAGIC: u8 = 0;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvroConfig {
+    pub server_id: String,
+    pub name: String,
+    pub match_pattern: String,
+    pub mode: MatchMode,
+    /// Id of the schema in the schema registry, values are prefixed with
+    /// the magic byte and the big-endian id when it is set
+    pub schema_id: Option<u32>,
+    pub schema: Option<String>,
+}
+
+fn parse_schema(content: &str) -> Result<Schema> {
+    if content.trim().is_empty() {
+        return Err(Error::Invalid {
+            message: "schema is empty".to_string(),
+        });
+    }
+    let is_schema_file = Path::new(content)
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("avsc"));
+    let schema = if is_schema_file {
+        let file = resolve_path(content);
+        if !Path::new(&file).exists() {
+            return Err(Error::Invalid {
+                message: "schema file not found".to_string(),
+            });
+        }
+        Schema::parse_str(&fs::read_to_string(file)?)?
+    } else {
+        Schema::parse_str(content)?
+    };
+    Ok(schema)
+}
+
+fn avro_to_json(value: AvroValue) -> Result<String> {
+    let json = serde_json::Value::try_from(value)?;
+    Ok(serde_json::to_string_pretty(&json)?)
+}
+
+pub struct AvroManager;
+
+impl AvroManager {
+    pub fn init() -> Result<()> {
+        let db = get_database()?;
+        let read_txn = db.begin_read()?;
+        let table = read_txn.open_table(AVRO_TABLE)?;
+
+        for item in table.iter()? {
+            let (key, value) = item?;
+            let id = key.value();
+            let mut config: AvroConfig = serde_json::from_slice(value.value())?;
+            info!(
+                id,
+                name = config.name,
+                server_id = config.server_id,
+                match_pattern = config.match_pattern,
+                "load avro schema"
+            );
+            config.schema = None;
+            AVRO_PATTERN_CACHE.insert(id, &config.mode, &config.match_pattern);
+            AVRO_META_CACHE.insert(id.to_string(), config);
+        }
+        info!(count = AVRO_META_CACHE.len(), "load avro schemas success");
+
+        Ok(())
+    }
+    pub fn list_avros_with_id() -> Vec<(String, AvroConfig)> {
+        AVRO_META_CACHE
+            .iter()
+            .map(|item| (item.key().clone(), item.value().clone()))
+            .collect::<Vec<_>>()
+    }
+    pub fn parse_schema(content: &str) -> Result<Schema> {
+        parse_schema(content)
+    }
+    pub fn get_avro(id: &str) -> Result<AvroConfig> {
+        let db = get_database()?;
+        let read_txn = db.begin_read()?;
+        let table = read_txn.open_table(AVRO_TABLE)?;
+        let Some(v) = table.get(id)? else {
+            return Err(Error::Invalid {
+                message: "avro schema not found".to_string(),
+            });
+        };
+        let config: AvroConfig = serde_json::from_slice(v.value())?;
+        Ok(config)
+    }
+    pub fn delete_avro(id: &str) -> Result<()> {
+        let db = get_database()?;
+        let write_txn = db.begin_write()?;
+        {
+            let mut table = write_txn.open_table(AVRO_TABLE)?;
+            table.remove(id)?;
+        }
+        write_txn.commit()?;
+        AVRO_META_CACHE.remove(id);
+        AVRO_PATTERN_CACHE.remove(id);
+        Ok(())
+    }
+    pub fn match_key_to_name(server_id: &str, key: &str) -> Option<String> {
+        let item = AVRO_META_CACHE.iter().find(|item| {
+            item.server_id == server_id && AVRO_PATTERN_CACHE.is_match(item.key(), &item.mode, &item.match_pattern, key)
+        })?;
+        Some(item.key().to_string())
+    }
+    pub fn upsert_avro(id: &str, mut config: AvroConfig) -> Result<()> {
+        if config.name.is_empty() {
+            return Err(Error::Invalid {
+                message: "avro schema name is empty".to_string(),
+            });
+        }
+        let db = get_database()?;
+        let write_txn = db.begin_write()?;
+        {
+            let mut table = write_txn.open_table(AVRO_TABLE)?;
+            let json_val = serde_json::to_string(&config)?;
+            table.insert(id, json_val.as_bytes())?;
+        }
+        write_txn.commit()?;
+        config.schema = None;
+        AVRO_PATTERN_CACHE.insert(id, &config.mode, &config.match_pattern);
+        AVRO_META_CACHE.insert(id.to_string(), config);
+        Ok(())
+    }
+    fn get_schema(id: &str) -> Result<(Schema, Option<u32>)> {
+        let config = Self::get_avro(id)?;
+        let schema = parse_schema(&config.schema.unwrap_or_default())?;
+        Ok((schema, config.schema_id))
+    }
+    /// Decodes an Avro datum to JSON with the schema of the config.
+    pub fn decode_data(id: &str, data: &[u8]) -> Result<String> {
+        let (schema, schema_id) = Self::get_schema(id)?;
+        let mut datum = data;
+        if let Some(schema_id) = schema_id {
+            if data.len() < 5 || data[0] != SCHEMA_REGISTRY_MAGIC || data[1..5] != schema_id.to_be_bytes() {
+                return Err(Error::Invalid {
+                    message: "schema id mismatch".to_string(),
+                });
+            }
+            datum = &data[5..];
+        }
+        let value = from_avro_datum(&schema, &mut datum, None)?;
+        if !datum.is_empty() {
+            return Err(Error::Invalid {
+                message: "trailing data after avro datum".to_string(),
+            });
+        }
+        avro_to_json(value)
+    }
+    /// Encodes JSON back to an Avro datum with the schema of the config.
+    pub fn encode_data(id: &str, json: &str) -> Result<Vec<u8>> {
+        let (schema, schema_id) = Self::get_schema(id)?;
+        let json: serde_json::Value = serde_json::from_str(json)?;
+        let value = AvroValue::from(json).resolve(&schema)?;
+        let datum = to_avro_datum(&schema, value)?;
+        let Some(schema_id) = schema_id else {
+            return Ok(datum);
+        };
+        let mut data = Vec::with_capacity(datum.len() + 5);
+        data.push(SCHEMA_REGISTRY_MAGIC);
+        data.extend_from_slice(&schema_id.to_be_bytes());
+        data.extend_from_slice(&datum);
+        Ok(data)
+    }
+    /// Decodes all records of an Avro object container file to a JSON array,
+    /// the schema is embedded in the file.
+    pub fn decode_container(data: &[u8]) -> Result<String> {
+        let reader = apache_avro::Reader::new(data)?;
+        let records = reader
+            .map(|record| Ok(serde_json::Value::try_from(record?)?))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(serde_json::to_string_pretty(&records)?)
+    }
+}
@@ -0,0 +1,133 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::{DECODER_RULE_TABLE, MatchMode, MatchPatternCache, get_database};
+use crate::error::Error;
+use dashmap::DashMap;
+use redb::{ReadableDatabase, ReadableTable};
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use tracing::info;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+static DECODER_RULE_CACHE: LazyLock<DashMap<String, DecoderRuleConfig>> = LazyLock::new(DashMap::new);
+
+/// Compiled match patterns of the rules
+static DECODER_RULE_PATTERN_CACHE: LazyLock<MatchPatternCache> = LazyLock::new(MatchPatternCache::default);
+
+/// Forces the decoder and view mode of the keys matching the pattern
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecoderRuleConfig {
+    pub server_id: String,
+    pub name: String,
+    pub match_pattern: String,
+    pub mode: MatchMode,
+    /// Name of the forced decoder, `None` for auto detection
+    pub decoder: Option<String>,
+    /// Forced view mode (Auto, Plain or Hex)
+    pub view_mode: String,
+    /// Shell command of the command decoder, the value is piped to its stdin
+    /// and its stdout is shown as the text of the value
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Shell command converting the edited text back to the value on save
+    #[serde(default)]
+    pub reverse_command: Option<String>,
+}
+
+pub struct DecoderRuleManager;
+
+impl DecoderRuleManager {
+    pub fn init() -> Result<()> {
+        let db = get_database()?;
+        let read_txn = db.begin_read()?;
+        let table = read_txn.open_table(DECODER_RULE_TABLE)?;
+
+        for item in table.iter()? {
+            let (key, value) = item?;
+            let id = key.value();
+            let rule: DecoderRuleConfig = serde_json::from_slice(value.value())?;
+            info!(
+                id,
+                name = rule.name,
+                server_id = rule.server_id,
+                match_pattern = rule.match_pattern,
+                "load decoder rule"
+            );
+            Self::cache_rule(id, rule);
+        }
+        info!(count = DECODER_RULE_CACHE.len(), "load decoder rules success");
+
+        Ok(())
+    }
+    /// Returns the rules in creation order, which is also their match priority.
+    pub fn list_rules_with_id() -> Vec<(String, DecoderRuleConfig)> {
+        let mut rules = DECODER_RULE_CACHE
+            .iter()
+            .map(|item| (item.key().clone(), item.value().clone()))
+            .collect::<Vec<_>>();
+        rules.sort_by(|a, b| a.0.cmp(&b.0));
+        rules
+    }
+    pub fn get_rule(id: &str) -> Option<DecoderRuleConfig> {
+        DECODER_RULE_CACHE.get(id).map(|item| item.value().clone())
+    }
+    pub fn delete_rule(id: &str) -> Result<()> {
+        let db = get_database()?;
+        let write_txn = db.begin_write()?;
+        {
+            let mut table = write_txn.open_table(DECODER_RULE_TABLE)?;
+            table.remove(id)?;
+        }
+        write_txn.commit()?;
+        DECODER_RULE_CACHE.remove(id);
+        DECODER_RULE_PATTERN_CACHE.remove(id);
+        Ok(())
+    }
+    /// Returns the earliest created rule of the server matching the key.
+    ///
+    /// Rule ids are UUIDv7, so ordering by id is ordering by creation time.
+    pub fn match_key(server_id: &str, key: &str) -> Option<DecoderRuleConfig> {
+        DECODER_RULE_CACHE
+            .iter()
+            .filter(|item| {
+                item.server_id == server_id
+                    && DECODER_RULE_PATTERN_CACHE.is_match(item.key(), &item.mode, &item.match_pattern, key)
+            })
+            .min_by(|a, b| a.key().cmp(b.key()))
+            .map(|item| item.value().clone())
+    }
+    pub fn upsert_rule(id: &str, rule: DecoderRuleConfig) -> Result<()> {
+        if rule.name.is_empty() {
+            return Err(Error::Invalid {
+                message: "decoder rule name is empty".to_string(),
+            });
+        }
+        let db = get_database()?;
+        let write_txn = db.begin_write()?;
+        {
+            let mut table = write_txn.open_table(DECODER_RULE_TABLE)?;
+            let json_val = serde_json::to_string(&rule)?;
+            table.insert(id, json_val.as_bytes())?;
+        }
+        write_txn.commit()?;
+        Self::cache_rule(id, rule);
+        Ok(())
+    }
+    fn cache_rule(id: &str, rule: DecoderRuleConfig) {
+        DECODER_RULE_PATTERN_CACHE.insert(id, &rule.mode, &rule.match_pattern);
+        DECODER_RULE_CACHE.insert(id.to_string(), rule);
+    }
+}
@@ -16,20 +16,33 @@ use super::{PROTO_TABLE, get_database};
 use crate::error::Error;
 use crate::helpers::resolve_path;
 use dashmap::DashMap;
-use prost_reflect::{DescriptorPool, DynamicMessage};
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, prost::Message};
 use redb::{ReadableDatabase, ReadableTable};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
 use std::sync::LazyLock;
 use tempfile::TempDir;
-use tracing::info;
+use tracing::{debug, info};
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
 static PROTO_META_CACHE: LazyLock<DashMap<String, ProtoConfig>> = LazyLock::new(DashMap::new);
 
+/// Target message descriptors of the proto configs, resolved against all registered protos
+static PROTO_MESSAGE_CACHE: LazyLock<DashMap<String, MessageDescriptor>> = LazyLock::new(DashMap::new);
+
+/// Compiled match patterns of the proto configs
+static PROTO_PATTERN_CACHE: LazyLock<MatchPatternCache> = LazyLock::new(MatchPatternCache::default);
+
+/// Extensions of compiled `FileDescriptorSet` files
+const DESCRIPTOR_SET_EXTENSIONS: [&str; 3] = ["pb", "desc", "protoset"];
+
+/// Package of the well-known types, hidden from the message list
+const WELL_KNOWN_PACKAGE: &str = "google.protobuf.";
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum MatchMode {
     Prefix,
@@ -38,6 +51,49 @@ pub enum MatchMode {
     Exact,
 }
 
+impl MatchMode {
+    /// Returns true if the key matches the pattern in this mode.
+    pub fn is_match(&self, pattern: &str, key: &str) -> bool {
+        match self {
+            MatchMode::Exact => key == pattern,
+            MatchMode::Prefix => key.starts_with(pattern),
+            MatchMode::Suffix => key.ends_with(pattern),
+            MatchMode::Regex => Regex::new(pattern).is_ok_and(|re| re.is_match(key)),
+        }
+    }
+}
+
+/// Compiled patterns of the configs in regex mode, keyed by config id.
+///
+/// Keys are matched for every element of a collection, so the patterns are compiled
+/// when the configs are loaded or saved instead of on every match.
+#[derive(Default)]
+pub struct MatchPatternCache(DashMap<String, Regex>);
+
+impl MatchPatternCache {
+    /// Compiles the pattern of the config if it is in regex mode.
+    pub fn insert(&self, id: &str, mode: &MatchMode, pattern: &str) {
+        match (mode, Regex::new(pattern)) {
+            (MatchMode::Regex, Ok(regex)) => {
+                self.0.insert(id.to_string(), regex);
+            }
+            _ => {
+                self.0.remove(id);
+            }
+        }
+    }
+    pub fn remove(&self, id: &str) {
+        self.0.remove(id);
+    }
+    /// Returns true if the key matches the pattern of the config, an invalid regex matches nothing.
+    pub fn is_match(&self, id: &str, mode: &MatchMode, pattern: &str, key: &str) -> bool {
+        match mode {
+            MatchMode::Regex => self.0.get(id).is_some_and(|regex| regex.is_match(key)),
+            _ => mode.is_match(pattern, key),
+        }
+    }
+}
+
 impl From<usize> for MatchMode {
     fn from(value: usize) -> Self {
         match value {
@@ -70,11 +126,7 @@ pub struct ProtoConfig {
     pub target_message: Option<String>,
 }
 
-fn proto_to_json(pool: &DescriptorPool, message_name: &str, bytes: &[u8]) -> Result<String> {
-    let message_descriptor = pool.get_message_by_name(message_name).ok_or(Error::Invalid {
-        message: "message not found".to_string(),
-    })?;
-
+fn proto_to_json(message_descriptor: MessageDescriptor, bytes: &[u8]) -> Result<String> {
     let dynamic_msg = DynamicMessage::decode(message_descriptor, bytes)?;
 
     let json_output = serde_json::to_string_pretty(&dynamic_msg)?;
@@ -82,12 +134,50 @@ fn proto_to_json(pool: &DescriptorPool, message_name: &str, bytes: &[u8]) -> Res
     Ok(json_output)
 }
 
-fn parse_protobuf(content: &str, includes: &str) -> Result<(DescriptorPool, Vec<String>)> {
-    if content.is_empty() {
-        return Err(Error::Invalid {
-            message: "content is empty".to_string(),
-        });
+fn json_to_proto(message_descriptor: MessageDescriptor, json: &str) -> Result<Vec<u8>> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
+    let dynamic_msg = DynamicMessage::deserialize(message_descriptor, &mut deserializer)?;
+    deserializer.end()?;
+
+    Ok(dynamic_msg.encode_to_vec())
+}
+
+/// Collects the .proto files of the directory tree, hidden directories are skipped.
+fn collect_proto_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            let is_hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if !is_hidden {
+                collect_proto_files(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("proto")) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Loads a compiled `FileDescriptorSet` (e.g. `protoc --descriptor_set_out`).
+fn load_descriptor_set(file: &Path) -> Result<DescriptorPool> {
+    let data = fs::read(file)?;
+    if let Ok(pool) = DescriptorPool::decode(data.as_slice()) {
+        return Ok(pool);
     }
+    // Sets compiled without --include_imports miss the well-known types
+    let mut pool = DescriptorPool::global();
+    pool.decode_file_descriptor_set(data.as_slice())?;
+    Ok(pool)
+}
+
+/// Builds the descriptor pool of the proto content, which is one of:
+/// - A compiled descriptor set file (.pb, .desc or .protoset)
+/// - A directory, all the .proto files of the tree are compiled with it as the import root
+/// - A .proto file path
+/// - The text of a .proto file
+fn compile_protobuf(content: &str, includes: &str) -> Result<DescriptorPool> {
     let temp_dir = TempDir::new()?;
     let temp_path = temp_dir.path();
     let mut files = Vec::new();
@@ -95,10 +185,36 @@ fn parse_protobuf(content: &str, includes: &str) -> Result<(DescriptorPool, Vec<
         .split(",")
         .map(|item| Path::new(&resolve_path(item)).to_path_buf())
         .collect::<Vec<_>>();
-    let is_proto_file = Path::new(content)
+    let content_path = Path::new(content);
+    let is_descriptor_set = content_path.extension().is_some_and(|ext| {
+        DESCRIPTOR_SET_EXTENSIONS
+            .iter()
+            .any(|item| ext.eq_ignore_ascii_case(item))
+    });
+    let is_proto_file = content_path
         .extension()
         .is_some_and(|ext| ext.eq_ignore_ascii_case("proto"));
-    if is_proto_file {
+    let is_single_line = !content.contains('\n');
+    if is_descriptor_set {
+        let file = resolve_path(content);
+        let file_path = Path::new(&file);
+        if !file_path.exists() {
+            return Err(Error::Invalid {
+                message: "descriptor set file not found".to_string(),
+            });
+        }
+        return load_descriptor_set(file_path);
+    }
+    if is_single_line && Path::new(&resolve_path(content)).is_dir() {
+        let dir = Path::new(&resolve_path(content)).to_path_buf();
+        collect_proto_files(&dir, &mut files)?;
+        if files.is_empty() {
+            return Err(Error::Invalid {
+                message: "no proto file found in the directory".to_string(),
+            });
+        }
+        dirs.push(dir);
+    } else if is_proto_file {
         let file = resolve_path(content);
         let file_path = Path::new(&file);
         if !file_path.exists() {
@@ -111,16 +227,43 @@ fn parse_protobuf(content: &str, includes: &str) -> Result<(DescriptorPool, Vec<
             dirs.push(parent.to_path_buf());
         }
     } else {
-        let file_path = temp_path.join("main.proto");
+        // Named by the content, the pool of the Any resolution skips the files with a name it already has,
+        // so pasted protos must not share one name
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let file_path = temp_path.join(format!("{:016x}.proto", hasher.finish()));
         fs::write(&file_path, content)?;
         files.push(file_path);
         dirs.push(temp_path.to_path_buf());
     }
     let file_descriptor_set = protox::compile(files, dirs)?;
-    let pool = prost_reflect::DescriptorPool::from_file_descriptor_set(file_descriptor_set)?;
+    Ok(DescriptorPool::from_file_descriptor_set(file_descriptor_set)?)
+}
+
+/// Adds the files of the other pool to the pool.
+///
+/// Files shared by the protos (e.g. the well-known types) are kept from the own pool.
+fn merge_pool(pool: &mut DescriptorPool, other: &DescriptorPool) -> Result<()> {
+    let files = other
+        .file_descriptor_protos()
+        .filter(|file| pool.get_file_by_name(file.name()).is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+    pool.add_file_descriptor_protos(files)?;
+    Ok(())
+}
+
+fn parse_protobuf(content: &str, includes: &str) -> Result<(DescriptorPool, Vec<String>)> {
+    if content.is_empty() {
+        return Err(Error::Invalid {
+            message: "content is empty".to_string(),
+        });
+    }
+    let pool = compile_protobuf(content.trim(), includes)?;
     let messages = pool
         .all_messages()
         .map(|message| message.full_name().to_string())
+        .filter(|name| !name.starts_with(WELL_KNOWN_PACKAGE))
         .collect::<Vec<_>>();
     Ok((pool, messages))
 }
@@ -145,6 +288,7 @@ impl ProtoManager {
                 "load proto"
             );
             config.content = None;
+            PROTO_PATTERN_CACHE.insert(id, &config.mode, &config.match_pattern);
             PROTO_META_CACHE.insert(id.to_string(), config);
         }
         info!(count = PROTO_META_CACHE.len(), "load protos success");
@@ -182,26 +326,16 @@ impl ProtoManager {
         }
         write_txn.commit()?;
         PROTO_META_CACHE.remove(id);
+        PROTO_PATTERN_CACHE.remove(id);
+        // Every cached message may resolve Any fields with the removed proto
+        PROTO_MESSAGE_CACHE.clear();
         Ok(())
     }
     pub fn match_key_to_name(server_id: &str, key: &str) -> Option<String> {
         let cache = &PROTO_META_CACHE;
         let item = cache.iter().find(|item| {
-            if item.server_id != server_id {
-                return false;
-            }
-            match item.mode {
-                MatchMode::Exact => key == item.match_pattern,
-                MatchMode::Prefix => key.starts_with(&item.match_pattern),
-                MatchMode::Suffix => key.ends_with(&item.match_pattern),
-                MatchMode::Regex => {
-                    if let Ok(re) = Regex::new(&item.match_pattern) {
-                        re.is_match(key)
-                    } else {
-                        false
-                    }
-                }
-            }
+            item.server_id == server_id
+                && PROTO_PATTERN_CACHE.is_match(item.key(), &item.mode, &item.match_pattern, key)
         })?;
         Some(item.key().to_string())
     }
@@ -220,30 +354,52 @@ impl ProtoManager {
         }
         write_txn.commit()?;
         proto.content = None;
+        PROTO_PATTERN_CACHE.insert(id, &proto.mode, &proto.match_pattern);
         PROTO_META_CACHE.insert(id.to_string(), proto);
+        PROTO_MESSAGE_CACHE.clear();
         Ok(())
     }
-    pub fn decode_data(id: &str, data: &[u8]) -> Result<String> {
-        let proto = {
-            let db = get_database()?;
-            let read_txn = db.begin_read()?;
-            let table = read_txn.open_table(PROTO_TABLE)?;
-            let Some(v) = table.get(id)? else {
-                return Err(Error::Invalid {
-                    message: "proto not found".to_string(),
-                });
-            };
-            let proto: ProtoConfig = serde_json::from_slice(v.value())?;
-            proto
-        };
-        let content = proto.content.unwrap_or_default();
+    /// Compiles the descriptor pool of a proto config.
+    fn load_pool(id: &str) -> Result<(ProtoConfig, DescriptorPool, Vec<String>)> {
+        let proto = Self::get_proto(id)?;
+        let content = proto.content.clone().unwrap_or_default();
         if content.trim().is_empty() {
             return Err(Error::Invalid {
                 message: "proto content is empty".to_string(),
             });
         };
-        let includes = proto.includes.unwrap_or_default();
+        let includes = proto.includes.clone().unwrap_or_default();
         let (pool, messages) = parse_protobuf(&content, &includes)?;
+        Ok((proto, pool, messages))
+    }
+    /// Returns the descriptor of the target message of a proto config,
+    /// the first message of the proto when no target message is configured.
+    ///
+    /// The files of all the other proto configs are added to the pool,
+    /// so `google.protobuf.Any` fields resolve types defined by any of them.
+    fn get_target_message(id: &str) -> Result<MessageDescriptor> {
+        if let Some(message) = PROTO_MESSAGE_CACHE.get(id) {
+            return Ok(message.clone());
+        }
+        let (proto, mut pool, messages) = Self::load_pool(id)?;
+        let other_ids = PROTO_META_CACHE
+            .iter()
+            .map(|item| item.key().clone())
+            .filter(|other_id| other_id != id)
+            .collect::<Vec<_>>();
+        for other_id in other_ids {
+            let other_pool = match Self::load_pool(&other_id) {
+                Ok((_, other_pool, _)) => other_pool,
+                Err(e) => {
+                    debug!(error = %e, id = other_id, "skip proto of any resolution");
+                    continue;
+                }
+            };
+            if let Err(e) = merge_pool(&mut pool, &other_pool) {
+                debug!(error = %e, id = other_id, "skip proto of any resolution");
+            }
+        }
+
         let mut target_message = proto.target_message.unwrap_or_default();
         if target_message.is_empty() {
             target_message = messages.first().map(|item| item.to_string()).unwrap_or_default();
@@ -254,6 +410,47 @@ impl ProtoManager {
                 message: "target message is empty".to_string(),
             });
         }
-        proto_to_json(&pool, &target_message, data)
+        let message = pool.get_message_by_name(&target_message).ok_or(Error::Invalid {
+            message: "message not found".to_string(),
+        })?;
+        PROTO_MESSAGE_CACHE.insert(id.to_string(), message.clone());
+        Ok(message)
+    }
+    pub fn decode_data(id: &str, data: &[u8]) -> Result<String> {
+        proto_to_json(Self::get_target_message(id)?, data)
+    }
+    /// Encodes the JSON form of the target message back to protobuf bytes.
+    pub fn encode_data(id: &str, json: &str) -> Result<Vec<u8>> {
+        json_to_proto(Self::get_target_message(id)?, json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_match_pattern_cache() {
+        let cache = MatchPatternCache::default();
+        cache.insert("1", &MatchMode::Regex, "^user:[0-9]+$");
+        assert!(cache.is_match("1", &MatchMode::Regex, "^user:[0-9]+$", "user:42"));
+        assert!(!cache.is_match("1", &MatchMode::Regex, "^user:[0-9]+$", "user:me"));
+        // switched to prefix mode, the compiled pattern is dropped
+        cache.insert("1", &MatchMode::Prefix, "user:");
+        assert!(cache.is_match("1", &MatchMode::Prefix, "user:", "user:me"));
+        cache.insert("2", &MatchMode::Regex, "(");
+        assert!(!cache.is_match("2", &MatchMode::Regex, "(", "("));
+        cache.remove("1");
+        assert!(!cache.is_match("1", &MatchMode::Regex, "^user:[0-9]+$", "user:42"));
+    }
+
+    #[test]
+    fn test_merge_pasted_protos() -> Result<()> {
+        let mut pool = compile_protobuf("syntax = \"proto3\";\npackage a;\nmessage A { string name = 1; }", "")?;
+        let other = compile_protobuf("syntax = \"proto3\";\npackage b;\nmessage B { int64 id = 1; }", "")?;
+        merge_pool(&mut pool, &other)?;
+        assert!(pool.get_message_by_name("a.A").is_some());
+        assert!(pool.get_message_by_name("b.B").is_some());
+        Ok(())
     }
 }
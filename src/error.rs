@@ -55,6 +55,9 @@ pub enum Error {
 
     #[snafu(display("Prost reflect decode error: {source}"))]
     ProstReflectDecode { source: prost_reflect::prost::DecodeError },
+
+    #[snafu(display("Avro error: {source}"))]
+    Avro { source: Box<apache_avro::Error> },
 }
 
 impl From<redis::RedisError> for Error {
@@ -152,3 +155,11 @@ impl From<prost_reflect::prost::DecodeError> for Error {
         Error::ProstReflectDecode { source }
     }
 }
+
+impl From<apache_avro::Error> for Error {
+    fn from(source: apache_avro::Error) -> Self {
+        Error::Avro {
+            source: Box::new(source),
+        }
+    }
+}
@@ -15,18 +15,28 @@
 use std::env;
 
 mod action;
+mod command;
 mod common;
 mod font;
 mod fs;
+mod java;
+mod php;
+mod protobuf_raw;
+mod redis_bytes;
 mod string;
 mod time;
 mod ttl_cache;
 mod validate;
 
 pub use action::*;
+pub use command::run_command;
 pub use common::*;
 pub use font::get_font_family;
 pub use fs::*;
+pub use java::java_unserialize;
+pub use php::*;
+pub use protobuf_raw::decode_raw_protobuf;
+pub use redis_bytes::*;
 pub use string::*;
 pub use time::{parse_duration, unix_ts};
 pub use ttl_cache::*;
@@ -0,0 +1,158 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! External command execution for value formatters.
+//!
+//! The command is run by the system shell, so pipes and arguments work as in a terminal:
+//! - The input bytes are written to its stdin
+//! - Its stdout is returned, capped at `MAX_COMMAND_OUTPUT` bytes
+//! - It is killed when it does not exit within `COMMAND_TIMEOUT`
+
+use crate::error::Error;
+use std::io::{Read, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Maximum run time of a formatter command
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+/// Maximum size of the output of a formatter command
+const MAX_COMMAND_OUTPUT: u64 = 16 * 1024 * 1024;
+/// Interval of checking whether the command has exited
+const COMMAND_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+fn shell_command(command: &str) -> Command {
+    if cfg!(target_os = "windows") {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+/// Pipes the input to the command and returns its stdout.
+///
+/// Fails if the command exits with a non-zero status, times out
+/// or writes more than `MAX_COMMAND_OUTPUT` bytes.
+pub fn run_command(command: &str, input: &[u8]) -> Result<Vec<u8>> {
+    if command.trim().is_empty() {
+        return Err(Error::Invalid {
+            message: "command is empty".to_string(),
+        });
+    }
+    let mut child = shell_command(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // stdin and stdout are handled in threads, so a command that does not read
+    // its input or fills the pipe can not block the timeout check
+    let mut stdin = child.stdin.take();
+    let input = input.to_vec();
+    // the writer is not joined, it ends once the pipe is closed
+    thread::spawn(move || {
+        if let Some(stdin) = stdin.as_mut() {
+            // a command may exit without reading all the input
+            let _ = stdin.write_all(&input);
+        }
+    });
+    // the output is received with a timeout instead of joining the threads, a process started
+    // in the background by the command may keep the pipes open after the command exits
+    let stdout = child.stdout.take();
+    let (output_tx, output_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        let result = match stdout {
+            Some(stdout) => stdout.take(MAX_COMMAND_OUTPUT + 1).read_to_end(&mut output),
+            None => Ok(0),
+        };
+        let _ = output_tx.send(result.map(|_| output));
+    });
+    let stderr = child.stderr.take();
+    let (error_tx, error_rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut output = Vec::new();
+        if let Some(stderr) = stderr {
+            let _ = stderr.take(4096).read_to_end(&mut output);
+        }
+        let _ = error_tx.send(output);
+    });
+
+    let started_at = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started_at.elapsed() >= COMMAND_TIMEOUT {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err(Error::Invalid {
+                message: format!("command timed out after {}s", COMMAND_TIMEOUT.as_secs()),
+            });
+        }
+        thread::sleep(COMMAND_POLL_INTERVAL);
+    };
+    let deadline = started_at + COMMAND_TIMEOUT;
+    let output = output_rx
+        .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        .map_err(|_| Error::Invalid {
+            message: "command output is not closed after the command exits".to_string(),
+        })??;
+    let error_output = error_rx
+        .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+        .unwrap_or_default();
+
+    // checked before the status, the command fails on the closed pipe once the cap is reached
+    if output.len() as u64 > MAX_COMMAND_OUTPUT {
+        return Err(Error::Invalid {
+            message: format!("command output exceeds {MAX_COMMAND_OUTPUT} bytes"),
+        });
+    }
+    if !status.success() {
+        return Err(Error::Invalid {
+            message: format!(
+                "command exited with {status}: {}",
+                String::from_utf8_lossy(&error_output).trim()
+            ),
+        });
+    }
+    Ok(output)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_command() -> Result<()> {
+        assert_eq!(run_command("tr a-z A-Z", b"zedis")?, b"ZEDIS");
+        assert!(run_command("exit 1", b"").is_err());
+        assert!(run_command(" ", b"").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_background_process_keeps_output_open() {
+        let started_at = Instant::now();
+        assert!(run_command("sleep 30 & echo zedis", b"").is_err());
+        assert!(started_at.elapsed() < COMMAND_TIMEOUT * 2);
+    }
+}
@@ -17,6 +17,7 @@ use crate::constants::KEY_TREE_MIN_WIDTH;
 use crate::error::Error;
 use gpui::{Pixels, px};
 use ruzstd::decoding::StreamingDecoder;
+use ruzstd::encoding::{CompressionLevel, compress_to_vec};
 use std::io::Read;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -35,3 +36,7 @@ pub fn decompress_zstd(bytes: &[u8]) -> Result<Vec<u8>> {
         .map_err(|e| Error::Invalid { message: e.to_string() })?;
     Ok(decompressed_vec)
 }
+
+pub fn compress_zstd(bytes: &[u8]) -> Vec<u8> {
+    compress_to_vec(bytes, CompressionLevel::Fastest)
+}
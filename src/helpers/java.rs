@@ -0,0 +1,655 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Java object serialization stream decoding, read only.
+//!
+//! Without the classes the stream is mapped to JSON by its class descriptors:
+//! - Objects become JSON objects of their fields, with the class name in the `__class` field
+//! - Data written by `writeObject` is kept in the `__data` field, block data as hex
+//! - Boxed primitives, strings, enums, arrays, lists and maps of `java.lang` and `java.util`
+//!   are shown as their JSON counterparts
+
+use crate::error::Error;
+use serde_json::{Map, Number, Value};
+use std::fmt::Write;
+use std::rc::Rc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Field holding the class name of an object
+const JAVA_CLASS_FIELD: &str = "__class";
+/// Field holding the data written by `writeObject` or `writeExternal`
+const JAVA_DATA_FIELD: &str = "__data";
+/// Field holding the constant name of an enum
+const JAVA_ENUM_FIELD: &str = "__enum";
+/// Maximum nesting depth of objects
+const MAX_DEPTH: usize = 64;
+/// Maximum number of JSON values copied for back references, shared objects are copied per reference
+const MAX_COPIED_VALUES: usize = 1_000_000;
+
+const STREAM_MAGIC: u16 = 0xaced;
+const STREAM_VERSION: u16 = 5;
+const BASE_WIRE_HANDLE: u32 = 0x7e0000;
+
+const TC_NULL: u8 = 0x70;
+const TC_REFERENCE: u8 = 0x71;
+const TC_CLASSDESC: u8 = 0x72;
+const TC_OBJECT: u8 = 0x73;
+const TC_STRING: u8 = 0x74;
+const TC_ARRAY: u8 = 0x75;
+const TC_CLASS: u8 = 0x76;
+const TC_BLOCKDATA: u8 = 0x77;
+const TC_ENDBLOCKDATA: u8 = 0x78;
+const TC_RESET: u8 = 0x79;
+const TC_BLOCKDATALONG: u8 = 0x7a;
+const TC_LONGSTRING: u8 = 0x7c;
+const TC_PROXYCLASSDESC: u8 = 0x7d;
+const TC_ENUM: u8 = 0x7e;
+
+const SC_WRITE_METHOD: u8 = 0x01;
+const SC_SERIALIZABLE: u8 = 0x02;
+const SC_EXTERNALIZABLE: u8 = 0x04;
+const SC_BLOCK_DATA: u8 = 0x08;
+
+/// Collections whose `writeObject` data is the list of their elements
+const LIST_CLASSES: [&str; 6] = [
+    "java.util.ArrayList",
+    "java.util.LinkedList",
+    "java.util.HashSet",
+    "java.util.LinkedHashSet",
+    "java.util.TreeSet",
+    "java.util.ArrayDeque",
+];
+/// Maps whose `writeObject` data is the list of their keys and values
+const MAP_CLASSES: [&str; 5] = [
+    "java.util.HashMap",
+    "java.util.LinkedHashMap",
+    "java.util.TreeMap",
+    "java.util.Hashtable",
+    "java.util.concurrent.ConcurrentHashMap",
+];
+/// Boxed primitives, shown as their `value` field
+const BOXED_CLASSES: [&str; 8] = [
+    "java.lang.Boolean",
+    "java.lang.Byte",
+    "java.lang.Character",
+    "java.lang.Short",
+    "java.lang.Integer",
+    "java.lang.Long",
+    "java.lang.Float",
+    "java.lang.Double",
+];
+
+struct FieldDesc {
+    type_code: u8,
+    name: String,
+}
+
+struct ClassDesc {
+    name: String,
+    flags: u8,
+    fields: Vec<FieldDesc>,
+    super_class: Option<Rc<ClassDesc>>,
+}
+
+/// Object of the handle table, referenced by TC_REFERENCE
+enum Handle {
+    ClassDesc(Rc<ClassDesc>),
+    /// Value of a string, an array or an object, `None` while the object is being read
+    Value(Option<Value>),
+}
+
+/// Item of the contents of a stream, or of the data written by `writeObject`
+enum Content {
+    Value(Value),
+    BlockData(Vec<u8>),
+}
+
+struct JavaParser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    handles: Vec<Handle>,
+    /// Number of values copied for back references
+    copied: usize,
+}
+
+/// Counts the values of the JSON tree.
+fn count_values(value: &Value) -> usize {
+    match value {
+        Value::Array(items) => 1 + items.iter().map(count_values).sum::<usize>(),
+        Value::Object(map) => 1 + map.values().map(count_values).sum::<usize>(),
+        _ => 1,
+    }
+}
+
+/// Converts Java modified UTF-8 (CESU-8 with a two-byte NUL) to a string.
+fn decode_modified_utf8(bytes: &[u8]) -> String {
+    let mut units: Vec<u16> = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let (unit, len) = match byte {
+            0x00..=0x7f => (u16::from(byte), 1),
+            0xc0..=0xdf if index + 1 < bytes.len() => {
+                let unit = (u16::from(byte & 0x1f) << 6) | u16::from(bytes[index + 1] & 0x3f);
+                (unit, 2)
+            }
+            0xe0..=0xef if index + 2 < bytes.len() => {
+                let unit = (u16::from(byte & 0x0f) << 12)
+                    | (u16::from(bytes[index + 1] & 0x3f) << 6)
+                    | u16::from(bytes[index + 2] & 0x3f);
+                (unit, 3)
+            }
+            _ => (0xfffd, 1),
+        };
+        units.push(unit);
+        index += len;
+    }
+    String::from_utf16_lossy(&units)
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{byte:02x}");
+    }
+    hex
+}
+
+fn key_to_string(key: Value) -> String {
+    match key {
+        Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+impl<'a> JavaParser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error::Invalid {
+            message: format!("invalid java serialized data at {}: {message}", self.pos),
+        }
+    }
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let Some(bytes) = self.pos.checked_add(len).and_then(|end| self.data.get(self.pos..end)) else {
+            return Err(self.error("unexpected end"));
+        };
+        self.pos += len;
+        Ok(bytes)
+    }
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self.read_bytes(N)?;
+        bytes.try_into().map_err(|_| self.error("unexpected end"))
+    }
+    fn read_u8(&mut self) -> Result<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+    fn peek_u8(&self) -> Result<u8> {
+        self.data
+            .get(self.pos)
+            .copied()
+            .ok_or_else(|| self.error("unexpected end"))
+    }
+    fn read_u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+    fn read_i32(&mut self) -> Result<i32> {
+        Ok(i32::from_be_bytes(self.read_array()?))
+    }
+    fn read_i64(&mut self) -> Result<i64> {
+        Ok(i64::from_be_bytes(self.read_array()?))
+    }
+    /// Reads a string with a two-byte length.
+    fn read_utf(&mut self) -> Result<String> {
+        let len = self.read_u16()?;
+        Ok(decode_modified_utf8(self.read_bytes(usize::from(len))?))
+    }
+    /// Reads a string with an eight-byte length.
+    fn read_long_utf(&mut self) -> Result<String> {
+        let len = self.read_i64()?;
+        let len = usize::try_from(len).map_err(|_| self.error("invalid string length"))?;
+        Ok(decode_modified_utf8(self.read_bytes(len)?))
+    }
+    fn new_handle(&mut self, handle: Handle) -> usize {
+        self.handles.push(handle);
+        self.handles.len() - 1
+    }
+    /// Reads a handle and returns its index in the handle table.
+    fn read_handle(&mut self) -> Result<usize> {
+        let handle = self.read_i32()? as u32;
+        match handle.checked_sub(BASE_WIRE_HANDLE) {
+            Some(index) if (index as usize) < self.handles.len() => Ok(index as usize),
+            _ => Err(self.error("unknown handle")),
+        }
+    }
+    /// Reads a class descriptor, `None` for TC_NULL.
+    fn read_class_desc(&mut self, depth: usize) -> Result<Option<Rc<ClassDesc>>> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        match self.read_u8()? {
+            TC_NULL => Ok(None),
+            TC_REFERENCE => {
+                let index = self.read_handle()?;
+                match &self.handles[index] {
+                    Handle::ClassDesc(desc) => Ok(Some(desc.clone())),
+                    Handle::Value(_) => Err(self.error("reference is not a class descriptor")),
+                }
+            }
+            TC_CLASSDESC => {
+                let name = self.read_utf()?;
+                // serialVersionUID
+                self.read_i64()?;
+                let index = self.new_handle(Handle::Value(None));
+                let flags = self.read_u8()?;
+                let count = self.read_u16()?;
+                let mut fields = Vec::new();
+                for _ in 0..count {
+                    let type_code = self.read_u8()?;
+                    let name = self.read_utf()?;
+                    if matches!(type_code, b'L' | b'[') {
+                        // class name of the field type
+                        self.read_object(depth + 1)?;
+                    }
+                    fields.push(FieldDesc { type_code, name });
+                }
+                self.read_annotation(depth + 1)?;
+                let super_class = self.read_class_desc(depth + 1)?;
+                let desc = Rc::new(ClassDesc {
+                    name,
+                    flags,
+                    fields,
+                    super_class,
+                });
+                self.handles[index] = Handle::ClassDesc(desc.clone());
+                Ok(Some(desc))
+            }
+            TC_PROXYCLASSDESC => {
+                let index = self.new_handle(Handle::Value(None));
+                let count = self.read_i32()?;
+                let mut interfaces = Vec::new();
+                for _ in 0..count {
+                    interfaces.push(self.read_utf()?);
+                }
+                self.read_annotation(depth + 1)?;
+                let super_class = self.read_class_desc(depth + 1)?;
+                let desc = Rc::new(ClassDesc {
+                    name: format!("Proxy[{}]", interfaces.join(", ")),
+                    flags: SC_SERIALIZABLE,
+                    fields: vec![],
+                    super_class,
+                });
+                self.handles[index] = Handle::ClassDesc(desc.clone());
+                Ok(Some(desc))
+            }
+            tc => Err(self.error(&format!("unexpected type code 0x{tc:02x} of class descriptor"))),
+        }
+    }
+    /// Reads the contents until TC_ENDBLOCKDATA.
+    fn read_annotation(&mut self, depth: usize) -> Result<Vec<Content>> {
+        let mut contents = Vec::new();
+        while self.peek_u8()? != TC_ENDBLOCKDATA {
+            contents.push(self.read_content(depth)?);
+        }
+        self.pos += 1;
+        Ok(contents)
+    }
+    /// Reads block data or an object.
+    fn read_content(&mut self, depth: usize) -> Result<Content> {
+        match self.peek_u8()? {
+            TC_BLOCKDATA => {
+                self.pos += 1;
+                let len = self.read_u8()?;
+                Ok(Content::BlockData(self.read_bytes(usize::from(len))?.to_vec()))
+            }
+            TC_BLOCKDATALONG => {
+                self.pos += 1;
+                let len = self.read_i32()?;
+                let len = usize::try_from(len).map_err(|_| self.error("invalid block data length"))?;
+                Ok(Content::BlockData(self.read_bytes(len)?.to_vec()))
+            }
+            _ => Ok(Content::Value(self.read_object(depth)?)),
+        }
+    }
+    /// Reads the value of a primitive or object field.
+    fn read_field_value(&mut self, type_code: u8, depth: usize) -> Result<Value> {
+        let value = match type_code {
+            b'B' => Value::from(self.read_u8()? as i8),
+            b'C' => {
+                let unit = self.read_u16()?;
+                Value::String(String::from_utf16_lossy(&[unit]))
+            }
+            b'D' => {
+                let value = f64::from_be_bytes(self.read_array()?);
+                Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null)
+            }
+            b'F' => {
+                let value = f32::from_be_bytes(self.read_array()?);
+                Number::from_f64(f64::from(value))
+                    .map(Value::Number)
+                    .unwrap_or(Value::Null)
+            }
+            b'I' => Value::from(self.read_i32()?),
+            b'J' => Value::from(self.read_i64()?),
+            b'S' => Value::from(i16::from_be_bytes(self.read_array()?)),
+            b'Z' => Value::Bool(self.read_u8()? != 0),
+            b'L' | b'[' => self.read_object(depth)?,
+            _ => return Err(self.error(&format!("invalid field type '{}'", type_code as char))),
+        };
+        Ok(value)
+    }
+    /// Copies the value of a back reference, objects still being read are shown by their handle.
+    fn read_reference(&mut self) -> Result<Value> {
+        let index = self.read_handle()?;
+        let value = match &self.handles[index] {
+            Handle::Value(Some(value)) => value.clone(),
+            Handle::Value(None) => Value::String("<cyclic reference>".to_string()),
+            Handle::ClassDesc(desc) => Value::String(desc.name.clone()),
+        };
+        self.copied += count_values(&value);
+        if self.copied > MAX_COPIED_VALUES {
+            return Err(self.error("too many references"));
+        }
+        Ok(value)
+    }
+    fn read_object(&mut self, depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        while self.peek_u8()? == TC_RESET {
+            self.pos += 1;
+            self.handles.clear();
+        }
+        match self.read_u8()? {
+            TC_NULL => Ok(Value::Null),
+            TC_REFERENCE => self.read_reference(),
+            TC_STRING => {
+                let value = Value::String(self.read_utf()?);
+                self.new_handle(Handle::Value(Some(value.clone())));
+                Ok(value)
+            }
+            TC_LONGSTRING => {
+                let value = Value::String(self.read_long_utf()?);
+                self.new_handle(Handle::Value(Some(value.clone())));
+                Ok(value)
+            }
+            TC_CLASS => {
+                let desc = self.read_class_desc(depth + 1)?;
+                let value = Value::String(desc.map(|desc| desc.name.clone()).unwrap_or_default());
+                self.new_handle(Handle::Value(Some(value.clone())));
+                Ok(value)
+            }
+            TC_ENUM => {
+                let desc = self.read_class_desc(depth + 1)?;
+                let index = self.new_handle(Handle::Value(None));
+                let constant = self.read_object(depth + 1)?;
+                let mut map = Map::new();
+                map.insert(
+                    JAVA_CLASS_FIELD.to_string(),
+                    Value::String(desc.map(|desc| desc.name.clone()).unwrap_or_default()),
+                );
+                map.insert(JAVA_ENUM_FIELD.to_string(), constant);
+                let value = Value::Object(map);
+                self.handles[index] = Handle::Value(Some(value.clone()));
+                Ok(value)
+            }
+            TC_ARRAY => {
+                let desc = self
+                    .read_class_desc(depth + 1)?
+                    .ok_or_else(|| self.error("array without class"))?;
+                let index = self.new_handle(Handle::Value(None));
+                let len = self.read_i32()?;
+                let len = usize::try_from(len).map_err(|_| self.error("invalid array length"))?;
+                // the element type is the second char of the class name, e.g. `[I` or `[Ljava.lang.String;`
+                let type_code = desc.name.as_bytes().get(1).copied().unwrap_or(b'L');
+                let mut items = Vec::new();
+                for _ in 0..len {
+                    items.push(self.read_field_value(type_code, depth + 1)?);
+                }
+                let value = Value::Array(items);
+                self.handles[index] = Handle::Value(Some(value.clone()));
+                Ok(value)
+            }
+            TC_OBJECT => {
+                let desc = self
+                    .read_class_desc(depth + 1)?
+                    .ok_or_else(|| self.error("object without class"))?;
+                let index = self.new_handle(Handle::Value(None));
+                let value = self.read_class_data(&desc, depth + 1)?;
+                self.handles[index] = Handle::Value(Some(value.clone()));
+                Ok(value)
+            }
+            tc => Err(self.error(&format!("unsupported type code 0x{tc:02x}"))),
+        }
+    }
+    /// Reads the fields of the object from the top superclass down to its class.
+    fn read_class_data(&mut self, desc: &Rc<ClassDesc>, depth: usize) -> Result<Value> {
+        let mut hierarchy = vec![desc.clone()];
+        while let Some(super_class) = hierarchy.last().and_then(|desc| desc.super_class.clone()) {
+            if hierarchy.len() > MAX_DEPTH {
+                return Err(self.error("class hierarchy too deep"));
+            }
+            hierarchy.push(super_class);
+        }
+        let mut map = Map::new();
+        map.insert(JAVA_CLASS_FIELD.to_string(), Value::String(desc.name.clone()));
+        let mut annotations = Vec::new();
+        for class in hierarchy.iter().rev() {
+            if class.flags & SC_EXTERNALIZABLE != 0 {
+                if class.flags & SC_BLOCK_DATA == 0 {
+                    return Err(self.error("externalizable data of protocol version 1 is not supported"));
+                }
+                annotations.extend(self.read_annotation(depth)?);
+                continue;
+            }
+            if class.flags & SC_SERIALIZABLE == 0 {
+                continue;
+            }
+            for field in &class.fields {
+                let value = self.read_field_value(field.type_code, depth)?;
+                map.insert(field.name.clone(), value);
+            }
+            if class.flags & SC_WRITE_METHOD != 0 {
+                annotations.extend(self.read_annotation(depth)?);
+            }
+        }
+
+        let name = desc.name.as_str();
+        if BOXED_CLASSES.contains(&name)
+            && let Some(value) = map.remove("value")
+        {
+            return Ok(value);
+        }
+        let items = || {
+            annotations.iter().filter_map(|content| match content {
+                Content::Value(value) => Some(value.clone()),
+                Content::BlockData(_) => None,
+            })
+        };
+        if LIST_CLASSES.contains(&name) {
+            return Ok(Value::Array(items().collect()));
+        }
+        if MAP_CLASSES.contains(&name) {
+            let values: Vec<Value> = items().collect();
+            let map = values
+                .chunks_exact(2)
+                .map(|pair| (key_to_string(pair[0].clone()), pair[1].clone()))
+                .collect::<Map<_, _>>();
+            return Ok(Value::Object(map));
+        }
+        if !annotations.is_empty() {
+            let data = annotations
+                .into_iter()
+                .map(|content| match content {
+                    Content::Value(value) => value,
+                    Content::BlockData(bytes) => Value::String(to_hex(&bytes)),
+                })
+                .collect();
+            map.insert(JAVA_DATA_FIELD.to_string(), Value::Array(data));
+        }
+        Ok(Value::Object(map))
+    }
+}
+
+/// Converts a Java object serialization stream to JSON, a stream of several objects becomes an array.
+pub fn java_unserialize(bytes: &[u8]) -> Result<Value> {
+    let mut parser = JavaParser {
+        data: bytes,
+        pos: 0,
+        handles: Vec::new(),
+        copied: 0,
+    };
+    if parser.read_u16()? != STREAM_MAGIC || parser.read_u16()? != STREAM_VERSION {
+        return Err(parser.error("invalid stream header"));
+    }
+    let mut values = Vec::new();
+    while parser.pos < bytes.len() {
+        match parser.read_content(0)? {
+            Content::Value(value) => values.push(value),
+            Content::BlockData(bytes) => values.push(Value::String(to_hex(&bytes))),
+        }
+    }
+    if values.len() == 1 {
+        return Ok(values.remove(0));
+    }
+    Ok(Value::Array(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const HEADER: [u8; 4] = [0xac, 0xed, 0x00, 0x05];
+
+    fn utf(value: &str) -> Vec<u8> {
+        let mut bytes = (value.len() as u16).to_be_bytes().to_vec();
+        bytes.extend_from_slice(value.as_bytes());
+        bytes
+    }
+
+    /// Class descriptor without annotations, `fields` are the type code and name of each field.
+    fn class_desc(name: &str, flags: u8, fields: &[(u8, &str, Option<&str>)], super_class: &[u8]) -> Vec<u8> {
+        let mut bytes = vec![TC_CLASSDESC];
+        bytes.extend(utf(name));
+        bytes.extend([0; 8]);
+        bytes.push(flags);
+        bytes.extend((fields.len() as u16).to_be_bytes());
+        for (type_code, name, class_name) in fields {
+            bytes.push(*type_code);
+            bytes.extend(utf(name));
+            if let Some(class_name) = class_name {
+                bytes.push(TC_STRING);
+                bytes.extend(utf(class_name));
+            }
+        }
+        bytes.push(TC_ENDBLOCKDATA);
+        bytes.extend_from_slice(super_class);
+        bytes
+    }
+
+    fn stream(contents: &[&[u8]]) -> Vec<u8> {
+        let mut bytes = HEADER.to_vec();
+        for content in contents {
+            bytes.extend_from_slice(content);
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_string() -> Result<()> {
+        assert_eq!(java_unserialize(&stream(&[&[TC_STRING], &utf("hi")]))?, json!("hi"));
+        // modified UTF-8 encodes NUL in two bytes and supplementary chars as surrogate pairs
+        let data = stream(&[
+            &[TC_STRING, 0x00, 0x08, 0xc0, 0x80],
+            &[0xed, 0xa0, 0xbd, 0xed, 0xb8, 0x80],
+        ]);
+        assert_eq!(java_unserialize(&data)?, json!("\u{0}\u{1f600}"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_boxed_integer() -> Result<()> {
+        let number = class_desc("java.lang.Number", SC_SERIALIZABLE, &[], &[TC_NULL]);
+        let integer = class_desc("java.lang.Integer", SC_SERIALIZABLE, &[(b'I', "value", None)], &number);
+        let data = stream(&[&[TC_OBJECT], &integer, &5i32.to_be_bytes()]);
+        assert_eq!(java_unserialize(&data)?, json!(5));
+        Ok(())
+    }
+
+    #[test]
+    fn test_object() -> Result<()> {
+        let fields = [
+            (b'I', "count", None),
+            (b'Z', "enabled", None),
+            (b'L', "name", Some("Ljava/lang/String;")),
+        ];
+        let desc = class_desc("com.example.Foo", SC_SERIALIZABLE, &fields, &[TC_NULL]);
+        let data = stream(&[&[TC_OBJECT], &desc, &7i32.to_be_bytes(), &[1, TC_STRING], &utf("zedis")]);
+        assert_eq!(
+            java_unserialize(&data)?,
+            json!({"__class": "com.example.Foo", "count": 7, "enabled": true, "name": "zedis"})
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_array_list() -> Result<()> {
+        let desc = class_desc(
+            "java.util.ArrayList",
+            SC_SERIALIZABLE | SC_WRITE_METHOD,
+            &[(b'I', "size", None)],
+            &[TC_NULL],
+        );
+        // handles: 0 the class, 1 the list, 2 the string
+        let data = stream(&[
+            &[TC_OBJECT],
+            &desc,
+            &2i32.to_be_bytes(),
+            &[TC_BLOCKDATA, 4, 0, 0, 0, 2, TC_STRING],
+            &utf("a"),
+            &[TC_REFERENCE, 0x00, 0x7e, 0x00, 0x02, TC_ENDBLOCKDATA],
+        ]);
+        assert_eq!(java_unserialize(&data)?, json!(["a", "a"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(java_unserialize(&[]).is_err());
+        assert!(java_unserialize(&[0xac, 0xed, 0x00, 0x04, TC_NULL]).is_err());
+        assert!(java_unserialize(&stream(&[&[TC_STRING, 0x00, 0x05, b'h']])).is_err());
+        assert!(java_unserialize(&stream(&[&[TC_REFERENCE, 0x00, 0x7e, 0x00, 0x09]])).is_err());
+        assert!(java_unserialize(&stream(&[&[TC_RESET; 100_000]])).is_err());
+        assert!(java_unserialize(&stream(&[&[0x00]])).is_err());
+    }
+
+    #[test]
+    fn test_huge_array() {
+        let desc = class_desc("[I", SC_SERIALIZABLE, &[], &[TC_NULL]);
+        let data = stream(&[&[TC_ARRAY], &desc, &i32::MAX.to_be_bytes(), &1i32.to_be_bytes()]);
+        assert!(java_unserialize(&data).is_err());
+    }
+
+    #[test]
+    fn test_nested_array() {
+        let desc = class_desc("[Ljava.lang.Object;", SC_SERIALIZABLE, &[], &[TC_NULL]);
+        let mut data = stream(&[&[TC_ARRAY], &desc, &1i32.to_be_bytes()]);
+        for _ in 0..100 {
+            data.extend([TC_ARRAY, TC_REFERENCE, 0x00, 0x7e, 0x00, 0x00]);
+            data.extend(1i32.to_be_bytes());
+        }
+        data.push(TC_NULL);
+        assert!(java_unserialize(&data).is_err());
+    }
+}
@@ -0,0 +1,345 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! PHP `serialize()` format conversion.
+//!
+//! Values are mapped to JSON as follows:
+//! - Arrays with the keys `0..n` become JSON arrays, other arrays become objects
+//! - Objects become JSON objects with the class name in the `__class` field
+//! - Custom serialized objects (`C:`) keep their raw data in the `__data` field
+//! - Strings that are not valid UTF-8 are converted lossily, such values can't be serialized back
+
+use crate::error::Error;
+use serde_json::{Map, Number, Value};
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Field holding the class name of an object
+const PHP_CLASS_FIELD: &str = "__class";
+/// Field holding the raw data of a custom serialized object
+const PHP_DATA_FIELD: &str = "__data";
+/// Maximum nesting depth of arrays and objects
+const MAX_DEPTH: usize = 64;
+
+struct PhpParser<'a> {
+    data: &'a [u8],
+    pos: usize,
+    /// Set when a string is not valid UTF-8 and was converted lossily
+    lossy: bool,
+}
+
+impl<'a> PhpParser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error::Invalid {
+            message: format!("invalid php serialized data at {}: {message}", self.pos),
+        }
+    }
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        if self.data.get(self.pos) != Some(&expected) {
+            return Err(self.error(&format!("expected '{}'", expected as char)));
+        }
+        self.pos += 1;
+        Ok(())
+    }
+    /// Reads the text up to the end byte, the end byte is consumed.
+    fn read_until(&mut self, end: u8) -> Result<&'a str> {
+        let start = self.pos;
+        let Some(offset) = self.data[start..].iter().position(|b| *b == end) else {
+            return Err(self.error(&format!("missing '{}'", end as char)));
+        };
+        self.pos = start + offset + 1;
+        std::str::from_utf8(&self.data[start..start + offset]).map_err(|e| self.error(&e.to_string()))
+    }
+    fn read_usize(&mut self, end: u8) -> Result<usize> {
+        let value = self.read_until(end)?;
+        value.parse::<usize>().map_err(|e| self.error(&e.to_string()))
+    }
+    /// Reads `len` bytes as text, bytes that are not valid UTF-8 are replaced.
+    fn read_text(&mut self, len: usize) -> Result<String> {
+        let Some(bytes) = self.pos.checked_add(len).and_then(|end| self.data.get(self.pos..end)) else {
+            return Err(self.error("string out of range"));
+        };
+        self.pos += len;
+        match std::str::from_utf8(bytes) {
+            Ok(text) => Ok(text.to_string()),
+            Err(_) => {
+                self.lossy = true;
+                Ok(String::from_utf8_lossy(bytes).to_string())
+            }
+        }
+    }
+    /// Reads a `LEN:"bytes"` string, the length is in bytes.
+    fn read_string(&mut self) -> Result<String> {
+        let len = self.read_usize(b':')?;
+        self.expect(b'"')?;
+        let value = self.read_text(len)?;
+        self.expect(b'"')?;
+        Ok(value)
+    }
+    /// Reads the `{key;value...}` entries of an array or object.
+    fn read_entries(&mut self, count: usize, depth: usize) -> Result<Vec<(Value, Value)>> {
+        self.expect(b'{')?;
+        // the count is untrusted, the entries are not pre-allocated
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let key = self.parse_value(depth + 1)?;
+            if !key.is_string() && !key.is_i64() {
+                return Err(self.error("invalid array key"));
+            }
+            let value = self.parse_value(depth + 1)?;
+            entries.push((key, value));
+        }
+        self.expect(b'}')?;
+        Ok(entries)
+    }
+    fn parse_value(&mut self, depth: usize) -> Result<Value> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        let Some(kind) = self.data.get(self.pos).copied() else {
+            return Err(self.error("unexpected end"));
+        };
+        self.pos += 1;
+        if kind == b'N' {
+            self.expect(b';')?;
+            return Ok(Value::Null);
+        }
+        self.expect(b':')?;
+        match kind {
+            b'b' => {
+                let value = self.read_until(b';')?;
+                Ok(Value::Bool(value == "1"))
+            }
+            b'i' => {
+                let value = self.read_until(b';')?;
+                let value = value.parse::<i64>().map_err(|e| self.error(&e.to_string()))?;
+                Ok(Value::from(value))
+            }
+            b'd' => {
+                let value = self.read_until(b';')?;
+                let value = value.parse::<f64>().map_err(|e| self.error(&e.to_string()))?;
+                // INF and NAN have no JSON form
+                Ok(Number::from_f64(value).map(Value::Number).unwrap_or(Value::Null))
+            }
+            b's' => {
+                let value = self.read_string()?;
+                self.expect(b';')?;
+                Ok(Value::String(value))
+            }
+            b'a' => {
+                let count = self.read_usize(b':')?;
+                let entries = self.read_entries(count, depth)?;
+                let is_list = entries
+                    .iter()
+                    .enumerate()
+                    .all(|(index, (key, _))| key.as_i64() == Some(index as i64));
+                if is_list {
+                    return Ok(Value::Array(entries.into_iter().map(|(_, value)| value).collect()));
+                }
+                let map = entries
+                    .into_iter()
+                    .map(|(key, value)| (key_to_string(key), value))
+                    .collect::<Map<_, _>>();
+                Ok(Value::Object(map))
+            }
+            b'O' => {
+                let class = self.read_string()?;
+                self.expect(b':')?;
+                let count = self.read_usize(b':')?;
+                let mut map = Map::new();
+                map.insert(PHP_CLASS_FIELD.to_string(), Value::String(class));
+                for (key, value) in self.read_entries(count, depth)? {
+                    map.insert(key_to_string(key), value);
+                }
+                Ok(Value::Object(map))
+            }
+            b'C' => {
+                let class = self.read_string()?;
+                self.expect(b':')?;
+                let len = self.read_usize(b':')?;
+                self.expect(b'{')?;
+                let data = self.read_text(len)?;
+                self.expect(b'}')?;
+                let mut map = Map::new();
+                map.insert(PHP_CLASS_FIELD.to_string(), Value::String(class));
+                map.insert(PHP_DATA_FIELD.to_string(), Value::String(data));
+                Ok(Value::Object(map))
+            }
+            _ => Err(self.error(&format!("unsupported type '{}'", kind as char))),
+        }
+    }
+}
+
+fn key_to_string(key: Value) -> String {
+    match key {
+        Value::String(value) => value,
+        value => value.to_string(),
+    }
+}
+
+/// Returns true if the bytes look like the output of PHP `serialize()`.
+pub fn is_php_serialized(bytes: &[u8]) -> bool {
+    let is_php_prefix = bytes == b"N;"
+        || (matches!(bytes.first(), Some(b'a' | b'O' | b'C' | b's' | b'i' | b'b' | b'd'))
+            && bytes.get(1) == Some(&b':'));
+    is_php_prefix && php_unserialize(bytes).is_ok()
+}
+
+/// Converts PHP serialized data to JSON, all the data must be consumed.
+///
+/// Returns the value and `true` if a string was not valid UTF-8 and was converted lossily,
+/// in which case `php_serialize` would not restore the original data.
+pub fn php_unserialize(bytes: &[u8]) -> Result<(Value, bool)> {
+    let mut parser = PhpParser {
+        data: bytes,
+        pos: 0,
+        lossy: false,
+    };
+    let value = parser.parse_value(0)?;
+    if parser.pos != bytes.len() {
+        return Err(parser.error("trailing data"));
+    }
+    Ok((value, parser.lossy))
+}
+
+fn write_php_string(buf: &mut String, value: &str) {
+    buf.push_str(&format!("s:{}:\"{value}\";", value.len()));
+}
+
+fn write_php_key(buf: &mut String, key: &str) {
+    match key.parse::<i64>() {
+        // PHP casts canonical integer strings to integer keys
+        Ok(index) if index.to_string() == key => buf.push_str(&format!("i:{index};")),
+        _ => write_php_string(buf, key),
+    }
+}
+
+fn write_php_value(buf: &mut String, value: &Value) {
+    match value {
+        Value::Null => buf.push_str("N;"),
+        Value::Bool(value) => buf.push_str(&format!("b:{};", u8::from(*value))),
+        Value::Number(value) => {
+            if let Some(value) = value.as_i64() {
+                buf.push_str(&format!("i:{value};"));
+            } else {
+                buf.push_str(&format!("d:{};", value.as_f64().unwrap_or_default()));
+            }
+        }
+        Value::String(value) => write_php_string(buf, value),
+        Value::Array(items) => {
+            buf.push_str(&format!("a:{}:{{", items.len()));
+            for (index, item) in items.iter().enumerate() {
+                buf.push_str(&format!("i:{index};"));
+                write_php_value(buf, item);
+            }
+            buf.push('}');
+        }
+        Value::Object(map) => {
+            let class = map.get(PHP_CLASS_FIELD).and_then(|class| class.as_str());
+            match class {
+                Some(class) => {
+                    if let Some(data) = map.get(PHP_DATA_FIELD).and_then(|data| data.as_str()) {
+                        buf.push_str(&format!("C:{}:\"{class}\":{}:{{{data}}}", class.len(), data.len()));
+                        return;
+                    }
+                    buf.push_str(&format!("O:{}:\"{class}\":{}:{{", class.len(), map.len() - 1));
+                }
+                None => buf.push_str(&format!("a:{}:{{", map.len())),
+            }
+            for (key, item) in map.iter().filter(|(key, _)| class.is_none() || *key != PHP_CLASS_FIELD) {
+                if class.is_some() {
+                    write_php_string(buf, key);
+                } else {
+                    write_php_key(buf, key);
+                }
+                write_php_value(buf, item);
+            }
+            buf.push('}');
+        }
+    }
+}
+
+/// Converts JSON back to PHP serialized data.
+pub fn php_serialize(value: &Value) -> Vec<u8> {
+    let mut buf = String::new();
+    write_php_value(&mut buf, value);
+    buf.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn unserialize(data: &str) -> Result<Value> {
+        php_unserialize(data.as_bytes()).map(|(value, _)| value)
+    }
+
+    fn round_trip(value: &Value) -> Result<Value> {
+        php_unserialize(&php_serialize(value)).map(|(value, _)| value)
+    }
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let data = r#"a:4:{i:0;N;i:1;b:1;i:2;d:1.5;i:3;a:2:{s:4:"name";s:5:"zedis";s:2:"10";i:-3;}}"#;
+        let (value, lossy) = php_unserialize(data.as_bytes())?;
+        assert!(!lossy);
+        assert_eq!(value, json!([null, true, 1.5, {"name": "zedis", "10": -3}]));
+        assert_eq!(round_trip(&value)?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_array() {
+        let data = "a:1:{i:0;".repeat(100) + "N;" + &"}".repeat(100);
+        assert!(unserialize(&data).is_err());
+        let data = "a:1:{i:0;".repeat(10) + "N;" + &"}".repeat(10);
+        assert!(unserialize(&data).is_ok());
+    }
+
+    #[test]
+    fn test_object() -> Result<()> {
+        let value = unserialize(r#"O:3:"Foo":2:{s:3:"bar";i:1;s:3:"baz";s:2:"ok";}"#)?;
+        assert_eq!(value, json!({"__class": "Foo", "bar": 1, "baz": "ok"}));
+        assert_eq!(round_trip(&value)?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_object() -> Result<()> {
+        let value = unserialize(r#"C:3:"Foo":3:{abc}"#)?;
+        assert_eq!(value, json!({"__class": "Foo", "__data": "abc"}));
+        assert_eq!(php_serialize(&value), br#"C:3:"Foo":3:{abc}"#);
+        assert!(unserialize(r#"C:3:"Foo":9:{abc}"#).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(unserialize(r#"s:10:"abc";"#).is_err());
+        assert!(unserialize("a:2:{i:0;i:1;").is_err());
+        assert!(unserialize("i:1;i:2;").is_err());
+        assert!(unserialize("x:1;").is_err());
+        assert!(unserialize("a:18446744073709551615:{}").is_err());
+        assert!(unserialize(r#"s:18446744073709551615:"abc";"#).is_err());
+    }
+
+    #[test]
+    fn test_lossy_string() -> Result<()> {
+        let (value, lossy) = php_unserialize(b"s:2:\"\xff\xfe\";")?;
+        assert!(lossy);
+        assert_eq!(value.as_str().map(|value| value.chars().count()), Some(2));
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Schema-less protobuf wire format decoding, similar to `protoc --decode_raw`.
+//!
+//! Without the .proto files the type of a length-delimited field is guessed:
+//! - Printable UTF-8 is shown as a string
+//! - Bytes parsing as a whole message are shown as a nested field tree
+//! - Bytes parsing as a list of varints are shown as a packed field
+//! - Anything else is shown as escaped bytes
+
+use crate::error::Error;
+use std::fmt::Write;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Maximum nesting depth of guessed messages
+const MAX_DEPTH: usize = 64;
+/// Largest valid field number
+const MAX_FIELD_NUMBER: u64 = (1 << 29) - 1;
+const INDENT: &str = "  ";
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_FIXED64: u64 = 1;
+const WIRE_LEN: u64 = 2;
+const WIRE_START_GROUP: u64 = 3;
+const WIRE_END_GROUP: u64 = 4;
+const WIRE_FIXED32: u64 = 5;
+
+enum RawValue<'a> {
+    Varint(u64),
+    Fixed64(u64),
+    Fixed32(u32),
+    Len(&'a [u8]),
+    Group(Vec<RawField<'a>>),
+}
+
+struct RawField<'a> {
+    number: u64,
+    value: RawValue<'a>,
+}
+
+struct WireReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> WireReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+    fn error(&self, message: &str) -> Error {
+        Error::Invalid {
+            message: format!("invalid protobuf data at {}: {message}", self.pos),
+        }
+    }
+    fn is_end(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+    fn read_varint(&mut self) -> Result<u64> {
+        let mut value = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let Some(byte) = self.data.get(self.pos).copied() else {
+                return Err(self.error("truncated varint"));
+            };
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(self.error("varint too long"))
+    }
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let Some(bytes) = self.pos.checked_add(len).and_then(|end| self.data.get(self.pos..end)) else {
+            return Err(self.error("truncated field"));
+        };
+        self.pos += len;
+        Ok(bytes)
+    }
+    /// Reads the fields until the end of the data, or the end group tag of `group`.
+    fn read_fields(&mut self, group: Option<u64>, depth: usize) -> Result<Vec<RawField<'a>>> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("nesting too deep"));
+        }
+        let mut fields = Vec::new();
+        while !self.is_end() {
+            let tag = self.read_varint()?;
+            let number = tag >> 3;
+            if number == 0 || number > MAX_FIELD_NUMBER {
+                return Err(self.error("invalid field number"));
+            }
+            let value = match tag & 0x07 {
+                WIRE_VARINT => RawValue::Varint(self.read_varint()?),
+                WIRE_FIXED64 => {
+                    let bytes = self.read_bytes(8)?;
+                    RawValue::Fixed64(u64::from_le_bytes(bytes.try_into().unwrap_or_default()))
+                }
+                WIRE_LEN => {
+                    let len = self.read_varint()?;
+                    let len = usize::try_from(len).map_err(|_| self.error("invalid length"))?;
+                    RawValue::Len(self.read_bytes(len)?)
+                }
+                WIRE_START_GROUP => RawValue::Group(self.read_fields(Some(number), depth + 1)?),
+                WIRE_END_GROUP => {
+                    if group == Some(number) {
+                        return Ok(fields);
+                    }
+                    return Err(self.error("unexpected end group"));
+                }
+                WIRE_FIXED32 => {
+                    let bytes = self.read_bytes(4)?;
+                    RawValue::Fixed32(u32::from_le_bytes(bytes.try_into().unwrap_or_default()))
+                }
+                _ => return Err(self.error("invalid wire type")),
+            };
+            fields.push(RawField { number, value });
+        }
+        if group.is_some() {
+            return Err(self.error("missing end group"));
+        }
+        Ok(fields)
+    }
+}
+
+fn parse_message(data: &[u8], depth: usize) -> Result<Vec<RawField<'_>>> {
+    WireReader::new(data).read_fields(None, depth)
+}
+
+fn parse_packed_varints(data: &[u8]) -> Option<Vec<u64>> {
+    let mut reader = WireReader::new(data);
+    let mut values = Vec::new();
+    while !reader.is_end() {
+        values.push(reader.read_varint().ok()?);
+    }
+    Some(values)
+}
+
+fn is_printable(value: &str) -> bool {
+    value
+        .chars()
+        .all(|c| !c.is_control() || matches!(c, '\n' | '\r' | '\t'))
+}
+
+/// Escapes the bytes like a C string literal, as protoc does.
+fn escape_c_bytes(data: &[u8]) -> String {
+    let mut escaped = String::with_capacity(data.len());
+    for byte in data {
+        match byte {
+            b'\n' => escaped.push_str("\\n"),
+            b'\r' => escaped.push_str("\\r"),
+            b'\t' => escaped.push_str("\\t"),
+            b'"' => escaped.push_str("\\\""),
+            b'\\' => escaped.push_str("\\\\"),
+            0x20..=0x7e => escaped.push(*byte as char),
+            _ => {
+                let _ = write!(escaped, "\\{byte:03o}");
+            }
+        }
+    }
+    escaped
+}
+
+fn escape_str(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '\n' => "\\n".to_string(),
+            '\r' => "\\r".to_string(),
+            '\t' => "\\t".to_string(),
+            '"' => "\\\"".to_string(),
+            '\\' => "\\\\".to_string(),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+fn write_fields(buf: &mut String, fields: &[RawField], depth: usize) {
+    let indent = INDENT.repeat(depth);
+    for field in fields {
+        let number = field.number;
+        match &field.value {
+            RawValue::Varint(value) => {
+                let _ = writeln!(buf, "{indent}{number}: {value}");
+            }
+            RawValue::Fixed64(value) => {
+                let _ = writeln!(buf, "{indent}{number}: 0x{value:016x}");
+            }
+            RawValue::Fixed32(value) => {
+                let _ = writeln!(buf, "{indent}{number}: 0x{value:08x}");
+            }
+            RawValue::Group(fields) => {
+                let _ = writeln!(buf, "{indent}{number} {{");
+                write_fields(buf, fields, depth + 1);
+                let _ = writeln!(buf, "{indent}}}");
+            }
+            RawValue::Len(data) => write_len_field(buf, number, data, depth),
+        }
+    }
+}
+
+fn write_len_field(buf: &mut String, number: u64, data: &[u8], depth: usize) {
+    let indent = INDENT.repeat(depth);
+    if let Ok(value) = std::str::from_utf8(data)
+        && is_printable(value)
+    {
+        let _ = writeln!(buf, "{indent}{number}: \"{}\"", escape_str(value));
+        return;
+    }
+    if let Ok(fields) = parse_message(data, depth + 1) {
+        let _ = writeln!(buf, "{indent}{number} {{");
+        write_fields(buf, &fields, depth + 1);
+        let _ = writeln!(buf, "{indent}}}");
+        return;
+    }
+    if let Some(values) = parse_packed_varints(data) {
+        let values = values.iter().map(|value| value.to_string()).collect::<Vec<_>>();
+        let _ = writeln!(buf, "{indent}{number}: [{}]", values.join(", "));
+        return;
+    }
+    let _ = writeln!(buf, "{indent}{number}: \"{}\"", escape_c_bytes(data));
+}
+
+/// Decodes protobuf wire format data without a schema to a field number tree.
+pub fn decode_raw_protobuf(data: &[u8]) -> Result<String> {
+    let fields = parse_message(data, 0)?;
+    let mut buf = String::new();
+    write_fields(&mut buf, &fields, 0);
+    Ok(buf)
+}
@@ -0,0 +1,183 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Binary-safe Redis strings.
+//!
+//! Redis keys and element values are arbitrary byte strings. This module provides:
+//! - `RedisBytes`, which keeps the raw bytes sent with every command
+//! - `RedisKey`, the key name alias of `RedisBytes`
+//! - An escaped display form where bytes that are not valid UTF-8 are shown as `\xNN`
+//!   and `\` as `\\`, so every display form maps back to exactly one byte string
+
+use bytes::Bytes;
+use gpui::SharedString;
+use redis::{RedisWrite, ToRedisArgs};
+use std::fmt::{self, Write};
+
+/// Escapes the bytes that are not valid UTF-8 as `\xNN` and `\` as `\\`, keeping the other text as is.
+pub fn escape_bytes(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len());
+    for chunk in bytes.utf8_chunks() {
+        text.push_str(&chunk.valid().replace('\\', "\\\\"));
+        for byte in chunk.invalid() {
+            let _ = write!(text, "\\x{byte:02x}");
+        }
+    }
+    text
+}
+
+/// Reverses `escape_bytes`, a `\` that does not start an escape sequence is kept as is.
+pub fn unescape_bytes(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'\\' {
+            if bytes.get(index + 1) == Some(&b'\\') {
+                result.push(b'\\');
+                index += 2;
+                continue;
+            }
+            if bytes.get(index + 1) == Some(&b'x')
+                && let Some(byte) = text
+                    .get(index + 2..index + 4)
+                    .filter(|hex| hex.bytes().all(|b| b.is_ascii_hexdigit()))
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                result.push(byte);
+                index += 4;
+                continue;
+            }
+        }
+        result.push(bytes[index]);
+        index += 1;
+    }
+    result
+}
+
+/// A Redis string (key name or element value) carried as raw bytes.
+///
+/// Commands always receive the original bytes (see the `ToRedisArgs` impl),
+/// the display form is only used by the UI.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RedisBytes {
+    /// Raw bytes
+    bytes: Bytes,
+    /// Display form, escaped when the bytes are not valid UTF-8 or contain `\`
+    display: SharedString,
+}
+
+/// A Redis key name carried as raw bytes.
+pub type RedisKey = RedisBytes;
+
+impl RedisBytes {
+    /// Creates a value from raw bytes.
+    pub fn new(bytes: impl Into<Bytes>) -> Self {
+        let bytes = bytes.into();
+        let display = match std::str::from_utf8(&bytes) {
+            Ok(text) if !text.contains('\\') => text.to_string(),
+            _ => escape_bytes(&bytes),
+        };
+        Self {
+            bytes,
+            display: display.into(),
+        }
+    }
+    /// Creates a value from its display form, the inverse of `display`.
+    pub fn from_display(display: &str) -> Self {
+        if display.contains('\\') {
+            return Self::new(unescape_bytes(display));
+        }
+        Self::from(display)
+    }
+    /// Returns the raw bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+    /// Returns the display form.
+    pub fn display(&self) -> SharedString {
+        self.display.clone()
+    }
+    /// Returns the display form as a string slice.
+    pub fn display_str(&self) -> &str {
+        self.display.as_str()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+}
+
+impl fmt::Display for RedisBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display)
+    }
+}
+
+impl From<Vec<u8>> for RedisBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl From<SharedString> for RedisBytes {
+    fn from(value: SharedString) -> Self {
+        if value.contains('\\') {
+            return Self::new(value.to_string().into_bytes());
+        }
+        Self {
+            bytes: Bytes::copy_from_slice(value.as_bytes()),
+            display: value,
+        }
+    }
+}
+
+impl From<&str> for RedisBytes {
+    fn from(value: &str) -> Self {
+        Self::from(SharedString::from(value.to_string()))
+    }
+}
+
+impl ToRedisArgs for RedisBytes {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(&self.bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_round_trip() {
+        for bytes in [&b"user:\xff\x00"[..], b"dir\\name", b"dir\\x41:\xfe", b"plain"] {
+            let key = RedisKey::new(bytes.to_vec());
+            // the display form typed into the exact search selects the same key
+            let searched = RedisKey::from_display(key.display_str());
+            assert_eq!(searched.as_bytes(), bytes);
+            assert_eq!(searched, key);
+        }
+        assert_eq!(RedisKey::new(b"user:\xff".to_vec()).display_str(), "user:\\xff");
+    }
+
+    #[test]
+    fn test_display_collision() {
+        let binary = RedisKey::new(b"user:\xff".to_vec());
+        let literal = RedisKey::from("user:\\xff");
+        assert_ne!(binary.display_str(), literal.display_str());
+        assert_eq!(RedisKey::from_display(literal.display_str()), literal);
+    }
+}
@@ -240,6 +240,25 @@ pub fn redis_value_to_string(v: &Value) -> String {
     }
 }
 
+/// Converts an INFO style reply (RESP2 flat array or RESP3 map) into name-value pairs.
+pub fn redis_value_to_pairs(value: Value) -> Vec<(String, Value)> {
+    match value {
+        Value::Map(items) => items
+            .into_iter()
+            .map(|(name, value)| (redis_value_to_string(&name), value))
+            .collect(),
+        Value::Array(items) => {
+            let mut pairs = Vec::with_capacity(items.len() / 2);
+            let mut iter = items.into_iter();
+            while let (Some(name), Some(value)) = (iter.next(), iter.next()) {
+                pairs.push((redis_value_to_string(&name), value));
+            }
+            pairs
+        }
+        _ => vec![],
+    }
+}
+
 pub fn starts_with_ignore_ascii_case(haystack: &str, needle: &str) -> bool {
     if haystack.len() < needle.len() {
         return false;
@@ -1,7 +1,7 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 use crate::connection::{clear_expired_cache, get_servers};
 use crate::constants::SIDEBAR_WIDTH;
-use crate::db::{ProtoManager, init_database};
+use crate::db::{AvroManager, DecoderRuleManager, ProtoManager, init_database};
 use crate::helpers::{MemuAction, get_or_create_config_dir, is_app_store_build, is_development, new_hot_keys};
 use crate::states::{
     FontSize, FontSizeAction, GlobalEvent, LocaleAction, NotificationCategory, Route, SettingsAction, ThemeAction,
@@ -234,6 +234,10 @@ impl Render for Zedis {
                     route = Some(Route::Settings);
                 } else if action == SettingsAction::Protos {
                     route = Some(Route::Protos);
+                } else if action == SettingsAction::Avros {
+                    route = Some(Route::Avros);
+                } else if action == SettingsAction::DecoderRules {
+                    route = Some(Route::DecoderRules);
                 }
                 if let Some(route) = route {
                     cx.update_global::<ZedisGlobalStore, ()>(|store, cx| {
@@ -375,6 +379,12 @@ fn main() {
                 if let Err(e) = ProtoManager::init() {
                     error!(error = %e, "init protos fail",);
                 }
+                if let Err(e) = AvroManager::init() {
+                    error!(error = %e, "init avro schemas fail",);
+                }
+                if let Err(e) = DecoderRuleManager::init() {
+                    error!(error = %e, "init decoder rules fail",);
+                }
             })
             .await;
         })
@@ -17,21 +17,41 @@ mod i18n;
 mod server;
 
 pub use app::*;
+pub use i18n::i18n_avro_editor;
+pub use i18n::i18n_bitmap_editor;
 pub use i18n::i18n_common;
+pub use i18n::i18n_decoder_rule_editor;
 pub use i18n::i18n_editor;
 pub use i18n::i18n_hash_editor;
+pub use i18n::i18n_hyperloglog_editor;
+pub use i18n::i18n_json_editor;
+pub use i18n::i18n_json_table;
 pub use i18n::i18n_key_tree;
 pub use i18n::i18n_kv_table;
 pub use i18n::i18n_list_editor;
+pub use i18n::i18n_probabilistic_editor;
 pub use i18n::i18n_proto_editor;
 pub use i18n::i18n_servers;
 pub use i18n::i18n_set_editor;
 pub use i18n::i18n_settings;
 pub use i18n::i18n_sidebar;
 pub use i18n::i18n_status_bar;
+pub use i18n::i18n_stream_editor;
+pub use i18n::i18n_timeseries_editor;
+pub use i18n::i18n_tree_editor;
+pub use i18n::i18n_vectorset_editor;
 pub use i18n::i18n_zset_editor;
 pub use server::ErrorMessage;
 pub use server::ZedisServerState;
+pub use server::bitmap::{BITFIELD_ENCODINGS, BitfieldOperation};
+pub use server::decoder::{decode_element, decoder_names};
 pub use server::event::ServerEvent;
 pub use server::event::ServerTask;
+pub use server::geo::{GEO_UNITS, GeoSearchQuery};
+pub use server::hash::HashFieldTtlOperation;
+pub use server::json::JsonPathOperation;
+pub use server::stream::StreamTrimStrategy;
+pub use server::string::{BytesEdit, CompressionFormat, STRING_PAGE_SIZE};
+pub use server::timeseries::{TIMESERIES_AGGREGATIONS, TimeseriesQuery};
 pub use server::value::*;
+pub use server::vectorset::{VectorsetQuery, parse_vector};
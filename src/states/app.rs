@@ -40,6 +40,8 @@ pub enum Route {
     Editor,
     Settings,
     Protos,
+    Avros,
+    DecoderRules,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
@@ -89,6 +91,8 @@ pub enum FontSizeAction {
 pub enum SettingsAction {
     Editor,
     Protos,
+    Avros,
+    DecoderRules,
 }
 
 const LIGHT_THEME_MODE: &str = "light";
@@ -187,6 +191,8 @@ pub struct ZedisAppState {
     key_separator: Option<String>,
     key_scan_count: Option<usize>,
     max_truncate_length: Option<usize>,
+    /// String values larger than this (in KB) are loaded by chunks
+    large_value_threshold: Option<usize>,
     redis_connection_timeout: Option<Duration>,
     redis_response_timeout: Option<Duration>,
     selected_server: Option<(String, usize)>,
@@ -352,6 +358,13 @@ impl ZedisAppState {
     pub fn set_max_truncate_length(&mut self, max_truncate_length: usize) {
         self.max_truncate_length = Some(max_truncate_length);
     }
+    /// Returns the large value threshold in KB
+    pub fn large_value_threshold(&self) -> usize {
+        self.large_value_threshold.unwrap_or(4096)
+    }
+    pub fn set_large_value_threshold(&mut self, large_value_threshold: usize) {
+        self.large_value_threshold = Some(large_value_threshold);
+    }
     pub fn redis_connection_timeout(&self) -> String {
         self.redis_connection_timeout
             .map(|timeout| timeout.as_secs().to_string())
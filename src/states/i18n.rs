@@ -17,6 +17,11 @@ use gpui::App;
 use gpui::SharedString;
 use rust_i18n::t;
 
+pub fn i18n_bitmap_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("bitmap_editor.{key}"), locale = locale).into()
+}
+
 pub fn i18n_common<'a>(cx: &'a App, key: &'a str) -> SharedString {
     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     t!(format!("common.{key}"), locale = locale).into()
@@ -72,6 +77,46 @@ pub fn i18n_hash_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
     t!(format!("hash_editor.{key}"), locale = locale).into()
 }
 
+pub fn i18n_stream_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("stream_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_vectorset_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("vectorset_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_hyperloglog_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("hyperloglog_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_tree_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("tree_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_json_table<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("json_table.{key}"), locale = locale).into()
+}
+
+pub fn i18n_json_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("json_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_timeseries_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("timeseries_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_probabilistic_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("probabilistic_editor.{key}"), locale = locale).into()
+}
+
 pub fn i18n_settings<'a>(cx: &'a App, key: &'a str) -> SharedString {
     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     t!(format!("settings.{key}"), locale = locale).into()
@@ -81,3 +126,13 @@ pub fn i18n_proto_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
     let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
     t!(format!("proto_editor.{key}"), locale = locale).into()
 }
+
+pub fn i18n_avro_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("avro_editor.{key}"), locale = locale).into()
+}
+
+pub fn i18n_decoder_rule_editor<'a>(cx: &'a App, key: &'a str) -> SharedString {
+    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+    t!(format!("decoder_rule_editor.{key}"), locale = locale).into()
+}
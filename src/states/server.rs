@@ -15,6 +15,8 @@
 use crate::connection::{AccessMode, QueryMode, RedisClientDescription, get_connection_manager, get_server};
 use crate::db::HistoryManager;
 use crate::error::Error;
+use crate::helpers::RedisKey;
+use crate::states::i18n_common;
 use crate::states::server::event::{ServerEvent, ServerTask};
 use crate::states::server::stat::RedisInfo;
 use ahash::AHashMap;
@@ -24,19 +26,29 @@ use gpui::prelude::*;
 use parking_lot::RwLock;
 use std::str::FromStr;
 use std::sync::Arc;
+use string::StringChunkLoad;
 use tracing::debug;
 use tracing::error;
 use uuid::Uuid;
 use value::{KeyType, RedisValue, RedisValueData};
 
+pub mod bitmap;
+pub mod decoder;
 pub mod event;
+pub mod geo;
 pub mod hash;
+pub mod hyperloglog;
+pub mod json;
 pub mod key;
 pub mod list;
+pub mod probabilistic;
 pub mod set;
 pub mod stat;
+pub mod stream;
 pub mod string;
+pub mod timeseries;
 pub mod value;
+pub mod vectorset;
 pub mod zset;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -117,12 +129,15 @@ pub struct ZedisServerState {
     /// List of all configured servers
     // servers: Option<Vec<RedisServer>>,
 
-    /// Currently selected key name
-    key: Option<SharedString>,
+    /// Currently selected key name (raw bytes)
+    key: Option<RedisKey>,
 
     /// Value data for the currently selected key
     value: Option<RedisValue>,
 
+    /// Chunked loading of a large string value
+    string_load: Option<StringChunkLoad>,
+
     // ===== Key scanning state =====
     /// Search keyword for filtering keys
     keyword: SharedString,
@@ -145,8 +160,8 @@ pub struct ZedisServerState {
     /// Set of prefixes that have been scanned (for lazy loading folders)
     loaded_prefixes: AHashSet<SharedString>,
 
-    /// Map of all loaded keys and their types
-    keys: AHashMap<SharedString, KeyType>,
+    /// Map of all loaded keys (raw bytes) and their types
+    keys: AHashMap<RedisKey, KeyType>,
 
     // ===== Error tracking =====
     /// Recent error messages (limited to MAX_ERROR_MESSAGES)
@@ -185,6 +200,7 @@ impl ZedisServerState {
         self.key = None;
         self.redis_info = None;
         self.value = None;
+        self.string_load = None;
         self.reset_scan();
         self.terminal = false;
     }
@@ -192,7 +208,7 @@ impl ZedisServerState {
     /// Add new keys to the key map (deduplicating automatically)
     ///
     /// If any new keys were added, generates a new tree ID to trigger UI refresh
-    fn extend_keys(&mut self, keys: Vec<SharedString>) {
+    fn extend_keys(&mut self, keys: Vec<RedisKey>) {
         self.keys.reserve(keys.len());
         let mut insert_count = 0;
 
@@ -280,7 +296,7 @@ impl ZedisServerState {
         .detach();
     }
 
-    fn try_get_mut_key_value(&mut self) -> Option<(SharedString, &mut RedisValue)> {
+    fn try_get_mut_key_value(&mut self) -> Option<(RedisKey, &mut RedisValue)> {
         let key = self.key.as_ref().filter(|k| !k.is_empty())?.clone();
         let value = self.value.as_mut()?;
         if value.is_busy() {
@@ -289,6 +305,15 @@ impl ZedisServerState {
         Some((key, value))
     }
 
+    /// Returns `true` if write commands are allowed, otherwise shows a warning.
+    fn ensure_writable(&self, cx: &mut Context<Self>) -> bool {
+        if self.readonly() {
+            self.emit_warning_notification(i18n_common(cx, "disable_in_readonly"), cx);
+            return false;
+        }
+        true
+    }
+
     // ===== Public accessor methods =====
 
     pub fn is_terminal(&self) -> bool {
@@ -413,13 +438,20 @@ impl ZedisServerState {
     }
 
     /// Get the currently selected key name
-    pub fn key(&self) -> Option<SharedString> {
+    pub fn key(&self) -> Option<RedisKey> {
         self.key.clone()
     }
     /// Get the map of all loaded keys and their types
-    pub fn keys(&self) -> &AHashMap<SharedString, KeyType> {
+    pub fn keys(&self) -> &AHashMap<RedisKey, KeyType> {
         &self.keys
     }
+    /// Returns the key shown with the display form (e.g. a key tree item).
+    ///
+    /// Keys that are not valid UTF-8 or contain `\` are displayed escaped,
+    /// the display form is unescaped back to the original bytes.
+    pub fn find_key(&self, display: &str) -> RedisKey {
+        RedisKey::from_display(display)
+    }
 
     /// Get the value data for the currently selected key
     pub fn value(&self) -> Option<&RedisValue> {
@@ -0,0 +1,334 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bitmap operations of string values.
+//!
+//! Strings used as bitmaps are inspected and edited bit by bit:
+//! - Summaries of the set bits (BITCOUNT, BITPOS)
+//! - Toggling a bit of the grid (SETBIT)
+//! - Reading and writing typed integer fields (BITFIELD GET/SET)
+
+use super::{
+    RedisValueData, ServerEvent, ServerTask, ZedisServerState,
+    value::{RedisBitmapSummary, RedisBytesValue, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::RedisKey,
+};
+use bytes::Bytes;
+use gpui::{SharedString, prelude::*};
+use redis::{cmd, pipe};
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Integer encodings of the BITFIELD fields
+pub const BITFIELD_ENCODINGS: [&str; 7] = ["u8", "i8", "u16", "i16", "u32", "i32", "i64"];
+/// Number of BITFIELD results kept in the summary
+const MAX_BITFIELD_RESULTS: usize = 20;
+
+/// BITFIELD operation of the bitmap view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BitfieldOperation {
+    Get,
+    Set,
+}
+
+/// Returns the width in bits of a BITFIELD encoding such as `u8` or `i16`.
+fn encoding_width(encoding: &str) -> Option<usize> {
+    if !BITFIELD_ENCODINGS.contains(&encoding) {
+        return None;
+    }
+    encoding.get(1..)?.parse().ok()
+}
+
+/// Parses a BITFIELD offset, `#n` is the n-th field of the encoding width.
+fn parse_bitfield_offset(offset: &str, width: usize) -> Result<usize> {
+    let offset = offset.trim();
+    let value = match offset.strip_prefix('#') {
+        Some(index) => index.parse::<usize>().map(|index| index * width),
+        None => offset.parse::<usize>(),
+    };
+    value.map_err(|_| Error::Invalid {
+        message: format!("Invalid bitfield offset: {offset}"),
+    })
+}
+
+/// Copies `data` written at `offset` over the `target` bytes starting at `target_offset`,
+/// growing them when the data is written past their end and `grow` is set.
+fn overlay(target: &Bytes, target_offset: usize, offset: usize, data: &[u8], grow: bool) -> Bytes {
+    let mut buf = target.to_vec();
+    let end = offset + data.len();
+    if grow && end > target_offset + buf.len() {
+        buf.resize(end - target_offset, 0);
+    }
+    let start = offset.max(target_offset);
+    let stop = end.min(target_offset + buf.len());
+    if start < stop {
+        buf[start - target_offset..stop - target_offset].copy_from_slice(&data[start - offset..stop - offset]);
+    }
+    Bytes::from(buf)
+}
+
+async fn get_bitmap_summary(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisBitmapSummary> {
+    let (count, first_set, first_clear): (u64, i64, i64) = pipe()
+        .cmd("BITCOUNT")
+        .arg(key)
+        .cmd("BITPOS")
+        .arg(key)
+        .arg(1)
+        .cmd("BITPOS")
+        .arg(key)
+        .arg(0)
+        .query_async(conn)
+        .await?;
+    Ok(RedisBitmapSummary {
+        count,
+        first_set,
+        first_clear,
+        ..Default::default()
+    })
+}
+
+impl RedisBytesValue {
+    /// Returns the bit at `offset` of the loaded bytes, `None` if it is not loaded.
+    ///
+    /// Bit 0 is the most significant bit of the first byte, as in GETBIT.
+    pub fn bit(&self, offset: usize) -> Option<bool> {
+        let (page_offset, bytes) = self.hex_page();
+        let byte = bytes.get((offset / 8).checked_sub(page_offset)?)?;
+        Some(byte & (0x80 >> (offset % 8)) != 0)
+    }
+    /// Copies the bytes written at `offset` over the loaded bytes and page,
+    /// a complete value grows when they are written past its end.
+    fn patch_range(&mut self, offset: usize, data: &[u8]) {
+        let partial = self.is_partial();
+        self.bytes = overlay(&self.bytes, 0, offset, data, !partial);
+        self.length = self.length.max(offset + data.len());
+        if let Some(page) = self.page.as_mut() {
+            page.bytes = overlay(&page.bytes, page.offset, offset, data, false);
+        }
+        if self.text.is_some() {
+            self.text = Some(String::from_utf8_lossy(&self.bytes).to_string().into());
+        }
+    }
+    /// Keeps the BITFIELD results of the current summary in the new summary.
+    fn update_bitmap(&mut self, mut summary: RedisBitmapSummary) {
+        if let Some(bitmap) = self.bitmap.take() {
+            summary.fields = bitmap.fields;
+        }
+        self.bitmap = Some(summary);
+    }
+}
+
+impl ZedisServerState {
+    /// Loads the bitmap summary of the string value (BITCOUNT, BITPOS 1, BITPOS 0).
+    pub fn load_bitmap_summary(&mut self, cx: &mut Context<Self>) {
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        if self.value.as_ref().and_then(|v| v.bytes_value()).is_none() {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::QueryBitmapValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_bitmap_summary(&mut conn, &key).await
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Ok(summary) = result
+                    && let Some(RedisValueData::Bytes(bytes_value)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    Arc::make_mut(bytes_value).update_bitmap(summary);
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Toggles the bit at `offset` with SETBIT, then reloads the bitmap summary.
+    pub fn toggle_bit(&mut self, offset: usize, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(bit) = value.bytes_value().and_then(|v| v.bit(offset)) else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::UpdateBitmapValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let _: i64 = cmd("SETBIT")
+                    .arg(&key)
+                    .arg(offset)
+                    .arg(u8::from(!bit))
+                    .query_async(&mut conn)
+                    .await?;
+                // the byte is read back, so the grid shows the stored bits
+                let byte: Vec<u8> = cmd("GETRANGE")
+                    .arg(&key)
+                    .arg(offset / 8)
+                    .arg(offset / 8)
+                    .query_async(&mut conn)
+                    .await?;
+                let summary = get_bitmap_summary(&mut conn, &key).await?;
+                Ok((byte, summary))
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((byte, summary)) = result
+                        && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                    {
+                        let bytes_value = Arc::make_mut(bytes_value);
+                        bytes_value.patch_range(offset / 8, &byte);
+                        bytes_value.update_bitmap(summary);
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Reads or writes a typed integer field with BITFIELD, the result is added to the summary.
+    ///
+    /// # Arguments
+    /// * `operation` - GET or SET
+    /// * `encoding` - Integer encoding such as `u8` or `i16`
+    /// * `offset` - Bit offset, or `#n` for the n-th field of the encoding width
+    /// * `field_value` - Value written by SET
+    pub fn run_bitfield(
+        &mut self,
+        operation: BitfieldOperation,
+        encoding: SharedString,
+        offset: SharedString,
+        field_value: SharedString,
+        cx: &mut Context<Self>,
+    ) {
+        if operation == BitfieldOperation::Set && !self.ensure_writable(cx) {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::UpdateBitmapValue,
+            move || async move {
+                let Some(width) = encoding_width(&encoding) else {
+                    return Err(Error::Invalid {
+                        message: format!("Invalid bitfield encoding: {encoding}"),
+                    });
+                };
+                let bit_offset = parse_bitfield_offset(&offset, width)?;
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut command = cmd("BITFIELD");
+                command.arg(&key);
+                let field_value = field_value.trim();
+                let operation_name = if operation == BitfieldOperation::Set {
+                    let Ok(field_value) = field_value.parse::<i64>() else {
+                        return Err(Error::Invalid {
+                            message: format!("Invalid bitfield value: {field_value}"),
+                        });
+                    };
+                    command
+                        .arg("SET")
+                        .arg(encoding.as_str())
+                        .arg(bit_offset)
+                        .arg(field_value);
+                    format!("SET {encoding} {offset} {field_value}")
+                } else {
+                    command.arg("GET").arg(encoding.as_str()).arg(bit_offset);
+                    format!("GET {encoding} {offset}")
+                };
+                let results: Vec<Option<i64>> = command.query_async(&mut conn).await?;
+                let result = results
+                    .first()
+                    .copied()
+                    .flatten()
+                    .map(|result| result.to_string())
+                    .unwrap_or_else(|| "nil".to_string());
+
+                // the bytes of the written field are read back for the grid
+                let written = if operation == BitfieldOperation::Set {
+                    let start = bit_offset / 8;
+                    let end = (bit_offset + width - 1) / 8;
+                    let bytes: Vec<u8> = cmd("GETRANGE")
+                        .arg(&key)
+                        .arg(start)
+                        .arg(end)
+                        .query_async(&mut conn)
+                        .await?;
+                    Some((start, bytes))
+                } else {
+                    None
+                };
+                let summary = get_bitmap_summary(&mut conn, &key).await?;
+                Ok((operation_name, result, written, summary))
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((operation_name, result, written, summary)) = result
+                        && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                    {
+                        let bytes_value = Arc::make_mut(bytes_value);
+                        if let Some((start, bytes)) = written {
+                            bytes_value.patch_range(start, &bytes);
+                        }
+                        bytes_value.update_bitmap(summary);
+                        if let Some(bitmap) = bytes_value.bitmap.as_mut() {
+                            bitmap.fields.insert(0, (operation_name.into(), result.into()));
+                            bitmap.fields.truncate(MAX_BITFIELD_RESULTS);
+                        }
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
@@ -0,0 +1,714 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Value decoder registry for string values.
+//!
+//! Every supported format is a `ValueDecoder`, which can:
+//! - Detect whether the bytes look like its format
+//! - Decode the bytes to text for display and editing
+//! - Encode the edited text back to bytes on save
+//!
+//! Decoders are tried in registration order during auto detection,
+//! a decoder rule of the key can force a decoder by name.
+//!
+//! Decoding is chained: hex or base64 text is decoded first, then the payload
+//! is decompressed, then the structured format (JSON, MessagePack, Protobuf, CBOR,
+//! BSON, Avro, PHP serialize or pickle) is decoded.
+//! Saving reverses the same chain.
+//!
+//! Proprietary formats can be decoded by an external command set in the decoder rule of the key.
+//!
+//! The elements of lists, sets, zsets and hashes are decoded with the same chain,
+//! the values of hash fields are also matched as `key#field`.
+//!
+//! The tree view decodes the same chain to a document, without truncating long strings.
+
+use super::string::{CompressionFormat, TextEncoding};
+use super::value::{DataFormat, detect_format, format_chain};
+use crate::db::{AvroManager, DecoderRuleManager, ProtoManager};
+use crate::error::Error;
+use crate::helpers::{java_unserialize, php_serialize, php_unserialize, run_command};
+use gpui::SharedString;
+use serde_json::Value;
+use std::sync::LazyLock;
+use tracing::error;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Context of the value being decoded
+pub struct DecodeContext<'a> {
+    pub server_id: &'a str,
+    pub key: &'a str,
+    /// Format detected from the magic bytes of the value
+    pub format: DataFormat,
+    pub max_truncate_length: usize,
+    /// Name of the decoder forced by a decoder rule
+    pub forced: Option<&'a str>,
+}
+
+impl<'a> DecodeContext<'a> {
+    /// Returns the context of a payload unwrapped from the value.
+    fn with_payload(&self, data: &[u8]) -> DecodeContext<'a> {
+        DecodeContext {
+            format: detect_format(data).0,
+            ..*self
+        }
+    }
+}
+
+/// Result of a successful decode
+#[derive(Debug, Clone)]
+pub struct DecodedValue {
+    /// Name of the decoder of the structured format
+    pub decoder: &'static str,
+    pub format: DataFormat,
+    /// Text for display, `None` for formats shown without text (e.g. images)
+    pub text: Option<SharedString>,
+    /// Codec the text was decompressed with
+    pub compression: Option<CompressionFormat>,
+    /// Text encoding the value was unwrapped from
+    pub encoding: Option<TextEncoding>,
+    /// False if the text can't be encoded back to the value, e.g. binary strings decoded lossily
+    pub encodable: bool,
+}
+
+impl DecodedValue {
+    fn new(decoder: &'static str, format: DataFormat, text: Option<SharedString>) -> Self {
+        Self {
+            decoder,
+            format,
+            text,
+            compression: None,
+            encoding: None,
+            encodable: true,
+        }
+    }
+    /// Returns true if the value is plain text, which is shown as is.
+    pub fn is_plain_text(&self) -> bool {
+        self.format == DataFormat::Text && self.compression.is_none() && self.encoding.is_none()
+    }
+    /// Returns the decode chain from the outermost layer, e.g. `base64 › zstd › protobuf`
+    pub fn format_chain(&self) -> String {
+        format_chain(self.encoding, self.compression, self.format)
+    }
+}
+
+pub trait ValueDecoder: Send + Sync {
+    /// Unique name of the decoder, referenced by decoder rules
+    fn name(&self) -> &'static str;
+    /// Returns true if the bytes look like the format of the decoder
+    fn detect(&self, ctx: &DecodeContext, data: &[u8]) -> bool;
+    /// Decodes the bytes, `None` if they are not of the format of the decoder
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue>;
+    /// Encodes the edited text back to bytes, `None` if the text is written as is
+    fn encode(&self, _server_id: &str, _key: &str, _text: &str) -> Result<Option<Vec<u8>>> {
+        Ok(None)
+    }
+    /// Returns true if the decoded text can be encoded back to the format
+    fn is_encodable(&self) -> bool {
+        false
+    }
+}
+
+/// Converts a serde value of a structured format to pretty-printed JSON.
+fn to_pretty_json<T: serde::Serialize>(value: &T) -> Option<SharedString> {
+    serde_json::to_string_pretty(value).ok().map(SharedString::from)
+}
+
+fn truncate_long_strings(max_truncate_length: usize, v: &mut Value, truncated: &mut bool) {
+    match v {
+        Value::String(s) => {
+            if s.len() > max_truncate_length {
+                let char_count = s.chars().count();
+                if char_count > max_truncate_length {
+                    let mut new_s: String = s.chars().take(max_truncate_length).collect();
+                    new_s.push_str(&format!("...(Total {} chars, content hidden)", char_count));
+                    *s = new_s;
+                    *truncated = true;
+                }
+            }
+        }
+        Value::Array(arr) => {
+            for item in arr {
+                truncate_long_strings(max_truncate_length, item, truncated);
+            }
+        }
+        Value::Object(map) => {
+            for val in map.values_mut() {
+                truncate_long_strings(max_truncate_length, val, truncated);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn looks_like_json(value: &str) -> bool {
+    let trimmed = value.trim();
+    (trimmed.starts_with('{') && trimmed.ends_with('}')) || (trimmed.starts_with('[') && trimmed.ends_with(']'))
+}
+
+/// Attempts to format a string as pretty-printed JSON.
+/// Returns None if the string is not valid JSON or doesn't look like JSON.
+fn pretty_json(value: &str, max_truncate_length: usize) -> Option<(SharedString, bool)> {
+    if !looks_like_json(value) {
+        return None;
+    }
+    let mut json_value = serde_json::from_str::<Value>(value).ok()?;
+    let mut truncated = false;
+    truncate_long_strings(max_truncate_length, &mut json_value, &mut truncated);
+    let pretty_str = serde_json::to_string_pretty(&json_value).ok()?;
+
+    Some((pretty_str.into(), truncated))
+}
+
+/// Pretty-printed JSON, a preview when long strings are truncated
+struct JsonDecoder;
+
+impl ValueDecoder for JsonDecoder {
+    fn name(&self) -> &'static str {
+        "json"
+    }
+    fn detect(&self, _ctx: &DecodeContext, data: &[u8]) -> bool {
+        std::str::from_utf8(data).is_ok_and(looks_like_json)
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let value = std::str::from_utf8(data).ok()?;
+        let (text, truncated) = pretty_json(value, ctx.max_truncate_length)?;
+        let format = if truncated {
+            DataFormat::Preview
+        } else {
+            DataFormat::Json
+        };
+        Some(DecodedValue::new(self.name(), format, Some(text)))
+    }
+}
+
+/// Plain UTF-8 text
+struct TextDecoder;
+
+impl ValueDecoder for TextDecoder {
+    fn name(&self) -> &'static str {
+        "text"
+    }
+    fn detect(&self, _ctx: &DecodeContext, data: &[u8]) -> bool {
+        std::str::from_utf8(data).is_ok()
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let value = std::str::from_utf8(data).ok()?;
+        Some(DecodedValue::new(
+            self.name(),
+            DataFormat::Text,
+            Some(value.to_string().into()),
+        ))
+    }
+}
+
+/// MessagePack shown as pretty-printed JSON
+struct MessagePackDecoder;
+
+impl ValueDecoder for MessagePackDecoder {
+    fn name(&self) -> &'static str {
+        "messagepack"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::MessagePack
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let value = rmp_serde::from_slice::<Value>(data).ok()?;
+        let text = serde_json::to_string_pretty(&value).ok()?;
+        Some(DecodedValue::new(
+            self.name(),
+            DataFormat::MessagePack,
+            Some(text.into()),
+        ))
+    }
+    fn encode(&self, _server_id: &str, _key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let value: Value = serde_json::from_str(text)?;
+        let bytes = rmp_serde::to_vec(&value).map_err(|e| Error::Invalid { message: e.to_string() })?;
+        Ok(Some(bytes))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// Protobuf shown as pretty-printed JSON, using the proto config matched by the key
+struct ProtobufDecoder;
+
+impl ValueDecoder for ProtobufDecoder {
+    fn name(&self) -> &'static str {
+        "protobuf"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ProtoManager::match_key_to_name(ctx.server_id, ctx.key).is_some()
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let id = ProtoManager::match_key_to_name(ctx.server_id, ctx.key)?;
+        let text = ProtoManager::decode_data(&id, data).ok()?;
+        Some(DecodedValue::new(self.name(), DataFormat::Protobuf, Some(text.into())))
+    }
+    fn encode(&self, server_id: &str, key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let Some(id) = ProtoManager::match_key_to_name(server_id, key) else {
+            return Err(Error::Invalid {
+                message: "proto not found".to_string(),
+            });
+        };
+        Ok(Some(ProtoManager::encode_data(&id, text)?))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// CBOR shown as pretty-printed JSON, map keys are converted to strings
+struct CborDecoder;
+
+impl ValueDecoder for CborDecoder {
+    fn name(&self) -> &'static str {
+        "cbor"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::Cbor
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let value = ciborium::from_reader::<ciborium::Value, _>(data).ok()?;
+        Some(DecodedValue::new(self.name(), DataFormat::Cbor, to_pretty_json(&value)))
+    }
+    fn encode(&self, _server_id: &str, _key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let value: Value = serde_json::from_str(text)?;
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&value, &mut bytes).map_err(|e| Error::Invalid { message: e.to_string() })?;
+        Ok(Some(bytes))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// BSON document shown as relaxed extended JSON
+struct BsonDecoder;
+
+impl ValueDecoder for BsonDecoder {
+    fn name(&self) -> &'static str {
+        "bson"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::Bson
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let document = bson::Document::from_reader(data).ok()?;
+        let value = bson::Bson::Document(document).into_relaxed_extjson();
+        Some(DecodedValue::new(self.name(), DataFormat::Bson, to_pretty_json(&value)))
+    }
+    fn encode(&self, _server_id: &str, _key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let value: Value = serde_json::from_str(text)?;
+        // Extended JSON, e.g. {"$oid": "..."}, is converted back to the BSON types
+        let bson = bson::Bson::try_from(value).map_err(|e| Error::Invalid { message: e.to_string() })?;
+        let bson::Bson::Document(document) = bson else {
+            return Err(Error::Invalid {
+                message: "bson document must be an object".to_string(),
+            });
+        };
+        let mut bytes = Vec::new();
+        document
+            .to_writer(&mut bytes)
+            .map_err(|e| Error::Invalid { message: e.to_string() })?;
+        Ok(Some(bytes))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// Avro datum decoded with the schema matched by the key
+struct AvroDecoder;
+
+impl ValueDecoder for AvroDecoder {
+    fn name(&self) -> &'static str {
+        "avro"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        AvroManager::match_key_to_name(ctx.server_id, ctx.key).is_some()
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let id = AvroManager::match_key_to_name(ctx.server_id, ctx.key)?;
+        let text = AvroManager::decode_data(&id, data).ok()?;
+        Some(DecodedValue::new(self.name(), DataFormat::Avro, Some(text.into())))
+    }
+    fn encode(&self, server_id: &str, key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let Some(id) = AvroManager::match_key_to_name(server_id, key) else {
+            return Err(Error::Invalid {
+                message: "avro schema not found".to_string(),
+            });
+        };
+        Ok(Some(AvroManager::encode_data(&id, text)?))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// Separator of the key and the field in the match target of hash field values, e.g. `user:1#avatar`
+pub const FIELD_SEPARATOR: char = '#';
+
+/// Name of the command decoder
+pub const COMMAND_DECODER: &str = "command";
+
+/// Output of the external command of the decoder rule matched by the key.
+///
+/// The command gets the raw bytes of the value, so it is applied by `decode_value`
+/// before the decode chain instead of by auto detection.
+struct CommandDecoder;
+
+impl CommandDecoder {
+    fn get_command(server_id: &str, key: &str, reverse: bool) -> Option<String> {
+        let rule = DecoderRuleManager::match_key(server_id, key)?;
+        let command = if reverse { rule.reverse_command } else { rule.command };
+        command.filter(|command| !command.trim().is_empty())
+    }
+}
+
+impl ValueDecoder for CommandDecoder {
+    fn name(&self) -> &'static str {
+        COMMAND_DECODER
+    }
+    fn detect(&self, _ctx: &DecodeContext, _data: &[u8]) -> bool {
+        false
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let command = Self::get_command(ctx.server_id, ctx.key, false)?;
+        let output = match run_command(&command, data) {
+            Ok(output) => output,
+            Err(e) => {
+                error!(error = %e, command, "run decoder command fail");
+                return None;
+            }
+        };
+        let text = String::from_utf8_lossy(&output).to_string();
+        let mut decoded = DecodedValue::new(self.name(), DataFormat::Command, Some(text.into()));
+        // without the reverse command, or with lossy text, the edited text can't be converted back
+        decoded.encodable =
+            std::str::from_utf8(&output).is_ok() && Self::get_command(ctx.server_id, ctx.key, true).is_some();
+        Some(decoded)
+    }
+    fn encode(&self, server_id: &str, key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let Some(command) = Self::get_command(server_id, key, true) else {
+            return Err(Error::Invalid {
+                message: "reverse command of the decoder rule is not set".to_string(),
+            });
+        };
+        Ok(Some(run_command(&command, text.as_bytes())?))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// Avro object container file with the embedded schema, read only
+struct AvroContainerDecoder;
+
+impl ValueDecoder for AvroContainerDecoder {
+    fn name(&self) -> &'static str {
+        "avro-container"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::Avro
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let text = AvroManager::decode_container(data).ok()?;
+        Some(DecodedValue::new(self.name(), DataFormat::Avro, Some(text.into())))
+    }
+}
+
+/// PHP `serialize()` data, objects keep their class in the `__class` field
+struct PhpDecoder;
+
+impl ValueDecoder for PhpDecoder {
+    fn name(&self) -> &'static str {
+        "php"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::Php
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let (value, lossy) = php_unserialize(data).ok()?;
+        let mut decoded = DecodedValue::new(self.name(), DataFormat::Php, to_pretty_json(&value));
+        decoded.encodable = !lossy;
+        Some(decoded)
+    }
+    fn encode(&self, _server_id: &str, _key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let value: Value = serde_json::from_str(text)?;
+        Ok(Some(php_serialize(&value)))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// Java object serialization stream, read only as the classes are not available
+struct JavaDecoder;
+
+impl ValueDecoder for JavaDecoder {
+    fn name(&self) -> &'static str {
+        "java"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::Java
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let value = java_unserialize(data).ok()?;
+        Some(DecodedValue::new(self.name(), DataFormat::Java, to_pretty_json(&value)))
+    }
+}
+
+/// Python pickle, unresolved globals are kept as their names
+struct PickleDecoder;
+
+impl ValueDecoder for PickleDecoder {
+    fn name(&self) -> &'static str {
+        "pickle"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::Pickle
+    }
+    fn decode(&self, _ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let options = serde_pickle::DeOptions::new().replace_unresolved_globals();
+        let value = serde_pickle::from_slice::<Value>(data, options).ok()?;
+        Some(DecodedValue::new(
+            self.name(),
+            DataFormat::Pickle,
+            to_pretty_json(&value),
+        ))
+    }
+    fn encode(&self, _server_id: &str, _key: &str, text: &str) -> Result<Option<Vec<u8>>> {
+        let value: Value = serde_json::from_str(text)?;
+        let bytes = serde_pickle::to_vec(&value, serde_pickle::SerOptions::new())
+            .map_err(|e| Error::Invalid { message: e.to_string() })?;
+        Ok(Some(bytes))
+    }
+    fn is_encodable(&self) -> bool {
+        true
+    }
+}
+
+/// Compressed payload, the codec is re-applied on save
+struct CompressionDecoder(CompressionFormat);
+
+impl ValueDecoder for CompressionDecoder {
+    fn name(&self) -> &'static str {
+        self.0.as_str()
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        match self.0 {
+            CompressionFormat::Gzip => ctx.format == DataFormat::Gzip,
+            CompressionFormat::Zstd => ctx.format == DataFormat::Zstd,
+            CompressionFormat::Snappy => ctx.format == DataFormat::Snappy,
+            // LZ4 blocks have no magic bytes, they are only decoded when forced by a decoder rule
+            CompressionFormat::Lz4 => false,
+        }
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        let decompressed = self.0.decompress(data).ok()?;
+        // The decompressed payload is decoded by the structured decoders,
+        // it stays editable and is re-compressed on save
+        let mut decoded = decode_payload(&ctx.with_payload(&decompressed), &decompressed)?;
+        if decoded.compression.is_some() || decoded.text.is_none() {
+            return None;
+        }
+        decoded.compression = Some(self.0);
+        Some(decoded)
+    }
+}
+
+/// Images, shown by the image viewer without text
+struct ImageDecoder;
+
+impl ValueDecoder for ImageDecoder {
+    fn name(&self) -> &'static str {
+        "image"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        matches!(
+            ctx.format,
+            DataFormat::Svg | DataFormat::Jpeg | DataFormat::Png | DataFormat::Webp | DataFormat::Gif
+        )
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        if !self.detect(ctx, data) {
+            return None;
+        }
+        Some(DecodedValue::new(self.name(), ctx.format, None))
+    }
+}
+
+/// HyperLogLog, shown by the HyperLogLog view without text
+struct HyperLogLogDecoder;
+
+impl ValueDecoder for HyperLogLogDecoder {
+    fn name(&self) -> &'static str {
+        "hyperloglog"
+    }
+    fn detect(&self, ctx: &DecodeContext, _data: &[u8]) -> bool {
+        ctx.format == DataFormat::HyperLogLog
+    }
+    fn decode(&self, ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+        if !self.detect(ctx, data) {
+            return None;
+        }
+        Some(DecodedValue::new(self.name(), ctx.format, None))
+    }
+}
+
+/// Registered decoders, in the order of auto detection
+static DECODERS: LazyLock<Vec<Box<dyn ValueDecoder>>> = LazyLock::new(|| {
+    vec![
+        Box::new(CommandDecoder),
+        Box::new(MessagePackDecoder),
+        Box::new(CborDecoder),
+        Box::new(BsonDecoder),
+        Box::new(AvroContainerDecoder),
+        Box::new(PickleDecoder),
+        Box::new(PhpDecoder),
+        Box::new(JavaDecoder),
+        Box::new(HyperLogLogDecoder),
+        Box::new(CompressionDecoder(CompressionFormat::Gzip)),
+        Box::new(CompressionDecoder(CompressionFormat::Zstd)),
+        Box::new(CompressionDecoder(CompressionFormat::Snappy)),
+        Box::new(ImageDecoder),
+        Box::new(ProtobufDecoder),
+        Box::new(AvroDecoder),
+        Box::new(CompressionDecoder(CompressionFormat::Lz4)),
+        Box::new(JsonDecoder),
+        Box::new(TextDecoder),
+    ]
+});
+
+/// Returns the decoder registered with the name.
+pub fn get_decoder(name: &str) -> Option<&'static dyn ValueDecoder> {
+    DECODERS
+        .iter()
+        .find(|decoder| decoder.name() == name)
+        .map(|decoder| decoder.as_ref())
+}
+
+/// Returns the names of all registered decoders.
+pub fn decoder_names() -> Vec<&'static str> {
+    DECODERS.iter().map(|decoder| decoder.name()).collect()
+}
+
+/// Decodes the bytes with the forced decoder, falling back to auto detection
+/// when there is none or it fails.
+fn decode_payload(ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+    if let Some(decoder) = ctx.forced.and_then(get_decoder)
+        && let Some(decoded) = decoder.decode(ctx, data)
+    {
+        return Some(decoded);
+    }
+    DECODERS
+        .iter()
+        .filter(|decoder| decoder.detect(ctx, data))
+        .find_map(|decoder| decoder.decode(ctx, data))
+}
+
+/// Decodes the value through the whole chain: text encoding, compression and structured format.
+pub fn decode_value(ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+    if CommandDecoder::get_command(ctx.server_id, ctx.key, false).is_some()
+        && let Some(decoded) = CommandDecoder.decode(ctx, data)
+    {
+        return Some(decoded);
+    }
+    // The command is not run again on the unwrapped payloads when it fails
+    let ctx = &DecodeContext {
+        forced: ctx.forced.filter(|name| *name != COMMAND_DECODER),
+        ..*ctx
+    };
+    decode_chain(ctx, data)
+}
+
+/// Decodes the text encoding, compression and structured format of the bytes.
+fn decode_chain(ctx: &DecodeContext, data: &[u8]) -> Option<DecodedValue> {
+    for encoding in TextEncoding::ALL {
+        if !encoding.detect(data) {
+            continue;
+        }
+        let Ok(payload) = encoding.decode(data) else {
+            continue;
+        };
+        // Only unwrap the text encoding when the payload is a recognized format,
+        // plain text that happens to be hex or base64 is kept as is
+        if let Some(mut decoded) = decode_payload(&ctx.with_payload(&payload), &payload)
+            && decoded.text.is_some()
+            && (decoded.format != DataFormat::Text || decoded.compression.is_some())
+        {
+            decoded.encoding = Some(encoding);
+            return Some(decoded);
+        }
+    }
+    decode_payload(ctx, data)
+}
+
+/// Decodes the value to a structured document for the tree view, long strings are kept whole.
+///
+/// Only JSON objects and arrays are documents, other values are shown as text.
+pub fn decode_document(ctx: &DecodeContext, data: &[u8]) -> Option<Value> {
+    let ctx = DecodeContext {
+        max_truncate_length: usize::MAX,
+        ..*ctx
+    };
+    let text = decode_value(&ctx, data)?.text?;
+    serde_json::from_str::<Value>(&text)
+        .ok()
+        .filter(|value| value.is_object() || value.is_array())
+}
+
+/// Returns the key the rules of an element are matched on: `key#field` for the
+/// values of hash fields when a rule matches it, otherwise the key itself,
+/// so the rules of a key also apply to all its elements.
+fn element_match_key(server_id: &str, key: &str, field: Option<&str>) -> String {
+    let Some(field) = field else {
+        return key.to_string();
+    };
+    let target = format!("{key}{FIELD_SEPARATOR}{field}");
+    let is_matched = ProtoManager::match_key_to_name(server_id, &target).is_some()
+        || AvroManager::match_key_to_name(server_id, &target).is_some()
+        || DecoderRuleManager::match_key(server_id, &target).is_some();
+    if is_matched { target } else { key.to_string() }
+}
+
+/// Decodes an element of a list, set, zset or hash, `field` is the field of a hash value.
+///
+/// Elements are decoded while the table is rendered, so external commands are not run for them.
+pub fn decode_element(
+    server_id: &str,
+    key: &str,
+    field: Option<&str>,
+    data: &[u8],
+    max_truncate_length: usize,
+) -> Option<DecodedValue> {
+    if data.is_empty() {
+        return None;
+    }
+    let match_key = element_match_key(server_id, key, field);
+    let forced = DecoderRuleManager::match_key(server_id, &match_key)
+        .and_then(|rule| rule.decoder)
+        .filter(|decoder| decoder != COMMAND_DECODER);
+    let ctx = DecodeContext {
+        server_id,
+        key: &match_key,
+        format: detect_format(data).0,
+        max_truncate_length,
+        forced: forced.as_deref(),
+    };
+    decode_chain(&ctx, data)
+}
@@ -75,6 +75,64 @@ pub enum ServerTask {
 
     /// Remove a value from a hash
     RemoveHashValue,
+    /// Update the ttl of a hash field
+    UpdateHashFieldTtl,
+
+    /// Add an entry to a stream
+    AddStreamValue,
+    /// Remove an entry from a stream
+    RemoveStreamValue,
+    /// Trim a stream by length or minimum ID
+    TrimStreamValue,
+    /// Load consumer groups, consumers and pending entries of a stream
+    LoadStreamGroups,
+    /// Create, reset or destroy a stream consumer group
+    UpdateStreamGroup,
+    /// Acknowledge or claim pending entries of a stream consumer group
+    UpdateStreamPending,
+
+    /// Add an element to a vector set
+    AddVectorsetValue,
+    /// Remove an element from a vector set
+    RemoveVectorsetValue,
+    /// Run a similarity search on a vector set
+    SearchVectorsetValue,
+
+    /// Set, append or delete a value at a JSONPath of a RedisJSON document
+    UpdateJsonPath,
+
+    /// Query samples of a time series by range and aggregation
+    QueryTimeseriesValue,
+    /// Add a sample to a time series
+    AddTimeseriesValue,
+    /// Delete samples of a time series by range
+    RemoveTimeseriesValue,
+
+    /// Query membership, frequency or quantiles of a probabilistic structure
+    QueryProbabilisticValue,
+
+    /// Load a large string value by chunks
+    LoadStringValue,
+    /// Load a page of a large string value for the hex view
+    LoadStringPage,
+
+    /// Query the bit count and positions of a string used as a bitmap
+    QueryBitmapValue,
+    /// Set a bit or a BITFIELD integer of a string used as a bitmap
+    UpdateBitmapValue,
+
+    /// Decode a string value to a document for the tree view
+    DecodeDocument,
+
+    /// Add elements to a HyperLogLog
+    UpdateHyperLogLogValue,
+    /// Merge a HyperLogLog into another key
+    MergeHyperLogLogValue,
+
+    /// Query the positions of a geo sorted set or search it with GEOSEARCH
+    QueryGeoValue,
+    /// Add a member to a geo sorted set by coordinate
+    AddGeoValue,
 
     /// Save edited value back to Redis
     SaveValue,
@@ -104,6 +162,30 @@ impl ServerTask {
             ServerTask::AddZsetValue => "add_zset_value",
             ServerTask::RemoveZsetValue => "remove_zset_value",
             ServerTask::RemoveHashValue => "remove_hash_value",
+            ServerTask::UpdateHashFieldTtl => "update_hash_field_ttl",
+            ServerTask::AddStreamValue => "add_stream_value",
+            ServerTask::RemoveStreamValue => "remove_stream_value",
+            ServerTask::TrimStreamValue => "trim_stream_value",
+            ServerTask::LoadStreamGroups => "load_stream_groups",
+            ServerTask::UpdateStreamGroup => "update_stream_group",
+            ServerTask::UpdateStreamPending => "update_stream_pending",
+            ServerTask::AddVectorsetValue => "add_vectorset_value",
+            ServerTask::RemoveVectorsetValue => "remove_vectorset_value",
+            ServerTask::SearchVectorsetValue => "search_vectorset_value",
+            ServerTask::UpdateJsonPath => "update_json_path",
+            ServerTask::QueryTimeseriesValue => "query_timeseries_value",
+            ServerTask::AddTimeseriesValue => "add_timeseries_value",
+            ServerTask::RemoveTimeseriesValue => "remove_timeseries_value",
+            ServerTask::QueryProbabilisticValue => "query_probabilistic_value",
+            ServerTask::LoadStringValue => "load_string_value",
+            ServerTask::LoadStringPage => "load_string_page",
+            ServerTask::QueryBitmapValue => "query_bitmap_value",
+            ServerTask::UpdateBitmapValue => "update_bitmap_value",
+            ServerTask::DecodeDocument => "decode_document",
+            ServerTask::UpdateHyperLogLogValue => "update_hyperloglog_value",
+            ServerTask::MergeHyperLogLogValue => "merge_hyperloglog_value",
+            ServerTask::QueryGeoValue => "query_geo_value",
+            ServerTask::AddGeoValue => "add_geo_value",
         }
     }
 }
@@ -140,6 +222,10 @@ pub enum ServerEvent {
     ValuePaginationFinished,
     /// Add a value to a set、list、hash、zset
     ValueAdded,
+    /// The edited text could not be encoded back to the value format, nothing was written
+    ValueEncodeFailed(SharedString),
+    /// A chunk of a large string value has been loaded, or the loading stopped
+    ValueLoadProgressed,
 
     /// User selected a different server
     ServerSelected(SharedString),
@@ -0,0 +1,342 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Geospatial operations of sorted sets.
+//!
+//! Geo indexes are sorted sets with geohash scores, their members are decoded by position:
+//! - Decoding the loaded members to coordinates (GEOPOS, GEOHASH)
+//! - Searching by radius or box from a member or a coordinate (GEOSEARCH)
+//! - Adding members by coordinate (GEOADD)
+
+use super::{
+    RedisValueData, ServerEvent, ServerTask, ZedisServerState,
+    value::{RedisGeoMember, RedisGeoValue, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::{RedisBytes, RedisKey},
+    states::i18n_zset_editor,
+};
+use gpui::{SharedString, prelude::*};
+use redis::{cmd, pipe};
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Type alias for GEOPOS and GEOHASH results: (longitude-latitude pairs, geohashes)
+type GeoPositionValue = (Vec<Option<(f64, f64)>>, Vec<Option<String>>);
+
+/// Distance units of GEOSEARCH
+pub const GEO_UNITS: [&str; 4] = ["m", "km", "mi", "ft"];
+/// Maximum number of members returned by GEOSEARCH
+const GEO_SEARCH_LIMIT: usize = 1000;
+
+/// Search options for GEOSEARCH
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GeoSearchQuery {
+    /// Member to search from, or `longitude,latitude` of a coordinate
+    pub origin: SharedString,
+    /// Search in a box instead of a radius
+    pub by_box: bool,
+    /// Radius, or `width,height` of the box
+    pub size: SharedString,
+    /// Distance unit of the size and the distances
+    pub unit: SharedString,
+}
+
+/// Parses two comma or space separated numbers, such as `longitude,latitude`.
+fn parse_pair(value: &str) -> Option<(f64, f64)> {
+    let mut parts = value
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty());
+    let first = parts.next()?.parse::<f64>().ok()?;
+    let second = parts.next()?.parse::<f64>().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((first, second))
+}
+
+impl GeoSearchQuery {
+    /// Appends the FROMMEMBER / FROMLONLAT and BYRADIUS / BYBOX arguments to the command
+    fn append_args(&self, command: &mut redis::Cmd) -> Result<()> {
+        let invalid = |message: String| Error::Invalid { message };
+        let origin = self.origin.trim();
+        if origin.is_empty() {
+            return Err(invalid("Search origin is empty".to_string()));
+        }
+        match parse_pair(origin) {
+            Some((longitude, latitude)) => command.arg("FROMLONLAT").arg(longitude).arg(latitude),
+            None => command.arg("FROMMEMBER").arg(origin),
+        };
+        let unit = self.unit.as_str();
+        if !GEO_UNITS.contains(&unit) {
+            return Err(invalid(format!("Invalid distance unit: {unit}")));
+        }
+        let size = self.size.trim();
+        if self.by_box {
+            let Some((width, height)) = parse_pair(size) else {
+                return Err(invalid(format!("Invalid box size: {size}")));
+            };
+            command.arg("BYBOX").arg(width).arg(height).arg(unit);
+        } else {
+            let Ok(radius) = size.parse::<f64>() else {
+                return Err(invalid(format!("Invalid radius: {size}")));
+            };
+            command.arg("BYRADIUS").arg(radius).arg(unit);
+        }
+        Ok(())
+    }
+}
+
+/// Decodes the position and geohash of the members, members without a position are left out.
+async fn get_geo_members(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    members: Vec<RedisBytes>,
+) -> Result<Vec<RedisGeoMember>> {
+    if members.is_empty() {
+        return Ok(vec![]);
+    }
+    let (positions, hashes): GeoPositionValue = pipe()
+        .cmd("GEOPOS")
+        .arg(key)
+        .arg(&members)
+        .cmd("GEOHASH")
+        .arg(key)
+        .arg(&members)
+        .query_async(conn)
+        .await?;
+    let values = members
+        .into_iter()
+        .zip(positions)
+        .zip(hashes)
+        .filter_map(|((member, position), geohash)| {
+            let (longitude, latitude) = position?;
+            Some(RedisGeoMember {
+                member,
+                longitude,
+                latitude,
+                geohash: geohash.unwrap_or_default().into(),
+                distance: None,
+            })
+        })
+        .collect();
+    Ok(values)
+}
+
+impl ZedisServerState {
+    /// Decodes the loaded members of the sorted set to positions for the geo view.
+    pub fn load_geo_positions(&mut self, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(zset) = value.zset_value() else {
+            return;
+        };
+        let members: Vec<RedisBytes> = zset.values.iter().map(|(member, _)| member.clone()).collect();
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::QueryGeoValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_geo_members(&mut conn, &key, members).await
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(members) = result
+                        && let Some(RedisValueData::Zset(zset_data)) = value.data.as_mut()
+                    {
+                        let zset = Arc::make_mut(zset_data);
+                        // the last search is kept while more members are loaded
+                        let search = zset.geo.as_ref().and_then(|geo| geo.search.clone());
+                        zset.geo = Some(Arc::new(RedisGeoValue { members, search }));
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Searches the members within a radius or a box with GEOSEARCH, nearest first.
+    pub fn search_geo_value(&mut self, query: GeoSearchQuery, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        if value.zset_value().is_none() {
+            return;
+        }
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::QueryGeoValue,
+            move || async move {
+                let mut command = cmd("GEOSEARCH");
+                command.arg(&key);
+                query.append_args(&mut command)?;
+                command
+                    .arg("ASC")
+                    .arg("COUNT")
+                    .arg(GEO_SEARCH_LIMIT)
+                    .arg("WITHCOORD")
+                    .arg("WITHDIST");
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // each result is [member, distance, [longitude, latitude]]
+                let results: Vec<(Vec<u8>, f64, (f64, f64))> = command.query_async(&mut conn).await?;
+                let members: Vec<RedisBytes> = results
+                    .iter()
+                    .map(|(member, _, _)| RedisBytes::from(member.clone()))
+                    .collect();
+                let hashes: Vec<Option<String>> = if members.is_empty() {
+                    vec![]
+                } else {
+                    cmd("GEOHASH").arg(&key).arg(&members).query_async(&mut conn).await?
+                };
+                let values = results
+                    .into_iter()
+                    .zip(members)
+                    .zip(hashes)
+                    .map(
+                        |(((_, distance, (longitude, latitude)), member), geohash)| RedisGeoMember {
+                            member,
+                            longitude,
+                            latitude,
+                            geohash: geohash.unwrap_or_default().into(),
+                            distance: Some(distance),
+                        },
+                    )
+                    .collect::<Vec<_>>();
+                Ok(values)
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(values) = result
+                        && let Some(RedisValueData::Zset(zset_data)) = value.data.as_mut()
+                    {
+                        let zset = Arc::make_mut(zset_data);
+                        let geo = Arc::make_mut(zset.geo.get_or_insert_default());
+                        geo.search = Some(values);
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Clears the GEOSEARCH result, the geo view shows the loaded members again.
+    pub fn clear_geo_search(&mut self, cx: &mut Context<Self>) {
+        if let Some(RedisValueData::Zset(zset_data)) = self.value.as_mut().and_then(|v| v.data.as_mut())
+            && let Some(geo) = Arc::make_mut(zset_data).geo.as_mut()
+        {
+            Arc::make_mut(geo).search = None;
+            cx.emit(ServerEvent::ValueUpdated);
+            cx.notify();
+        }
+    }
+    /// Adds a member at the coordinate with GEOADD, or moves an existing member.
+    ///
+    /// # Arguments
+    /// * `longitude` - Longitude between -180 and 180
+    /// * `latitude` - Latitude between -85.05112878 and 85.05112878
+    /// * `member` - The member to add
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn add_geo_value(&mut self, longitude: f64, latitude: f64, member: SharedString, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let current_key = key.clone();
+        let member = RedisBytes::from(member);
+        self.spawn(
+            ServerTask::AddGeoValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let count: usize = cmd("GEOADD")
+                    .arg(&key)
+                    .arg(longitude)
+                    .arg(latitude)
+                    .arg(&member)
+                    .query_async(&mut conn)
+                    .await?;
+                let score: f64 = cmd("ZSCORE").arg(&key).arg(&member).query_async(&mut conn).await?;
+                let geo_member = get_geo_members(&mut conn, &key, vec![member.clone()]).await?;
+                Ok((count, member, score, geo_member))
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                let title = i18n_zset_editor(cx, "add_value_success");
+                let msg = i18n_zset_editor(cx, "geo_add_success_tips");
+                if let Ok((count, member, score, geo_member)) = result
+                    && let Some(RedisValueData::Zset(zset_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let zset = Arc::make_mut(zset_data);
+                    zset.size += count;
+                    let (exists, inserted) = zset.upsert(member.clone(), score);
+                    if (exists || inserted)
+                        && let Some(geo) = zset.geo.as_mut()
+                    {
+                        let geo = Arc::make_mut(geo);
+                        geo.members.retain(|item| item.member != member);
+                        // keep the order of the loaded members
+                        let index = zset
+                            .values
+                            .iter()
+                            .position(|(name, _)| *name == member)
+                            .unwrap_or_default();
+                        let index = index.min(geo.members.len());
+                        for item in geo_member {
+                            geo.members.insert(index, item);
+                        }
+                    }
+                    cx.emit(ServerEvent::ValueAdded);
+                    this.emit_success_notification(msg, title, cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
@@ -20,6 +20,7 @@
 //! - Removing fields from a HASH (HDEL)
 //! - Filtering HASH fields with pattern matching
 //! - Efficient incremental loading for large HASHes
+//! - Per-field expiration (HPTTL, HEXPIRE, HPEXPIREAT, HPERSIST) on Redis 7.4+
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
@@ -28,10 +29,13 @@ use super::{
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
-    states::{ServerEvent, i18n_hash_editor},
+    helpers::{RedisBytes, RedisKey, parse_duration},
+    states::{ServerEvent, ZedisGlobalStore, i18n_hash_editor},
 };
+use chrono::Local;
 use gpui::{SharedString, prelude::*};
 use redis::cmd;
+use rust_i18n::t;
 use std::sync::Arc;
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -39,6 +43,64 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 /// Type alias for HSCAN result: (cursor, vec of (field, value) pairs as bytes)
 type HashScanValue = (u64, Vec<(Vec<u8>, Vec<u8>)>);
 
+/// Type alias for a loaded HASH batch: (cursor, field-value pairs, field expiration timestamps)
+type HashBatchValue = (u64, Vec<(RedisBytes, RedisBytes)>, Vec<(RedisBytes, i64)>);
+
+/// Minimum Redis version supporting hash field expiration
+pub(crate) const HASH_FIELD_TTL_VERSION: &str = "7.4.0";
+
+/// Field-level expiration operations supported on a HASH
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HashFieldTtlOperation {
+    /// Set the ttl of the field in seconds (HEXPIRE)
+    Expire,
+    /// Set the expiration unix timestamp of the field in milliseconds (HPEXPIREAT)
+    ExpireAt,
+    /// Remove the ttl of the field (HPERSIST)
+    Persist,
+}
+
+impl HashFieldTtlOperation {
+    /// Returns the Redis command used for this operation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashFieldTtlOperation::Expire => "HEXPIRE",
+            HashFieldTtlOperation::ExpireAt => "HPEXPIREAT",
+            HashFieldTtlOperation::Persist => "HPERSIST",
+        }
+    }
+}
+
+/// Retrieves the expiration timestamps of HASH fields using HPTTL.
+///
+/// Fields without a ttl (-1) or missing fields (-2) are skipped.
+///
+/// # Returns
+/// The fields with a ttl and their expiration unix timestamps in milliseconds
+async fn get_redis_hash_field_expire_at(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    fields: &[RedisBytes],
+) -> Result<Vec<(RedisBytes, i64)>> {
+    if fields.is_empty() {
+        return Ok(vec![]);
+    }
+    let ttls: Vec<i64> = cmd("HPTTL")
+        .arg(key)
+        .arg("FIELDS")
+        .arg(fields.len())
+        .arg(fields)
+        .query_async(conn)
+        .await?;
+    let now = Local::now().timestamp_millis();
+    Ok(fields
+        .iter()
+        .zip(ttls)
+        .filter(|(_, ttl)| *ttl >= 0)
+        .map(|(field, ttl)| (field.clone(), now + ttl))
+        .collect())
+}
+
 /// Retrieves HASH field-value pairs using Redis HSCAN command for cursor-based pagination.
 ///
 /// # Arguments
@@ -47,16 +109,19 @@ type HashScanValue = (u64, Vec<(Vec<u8>, Vec<u8>)>);
 /// * `keyword` - Optional filter keyword for field names (will be wrapped with wildcards)
 /// * `cursor` - Current cursor position (0 to start, returned cursor to continue)
 /// * `count` - Hint for number of field-value pairs to return per iteration
+/// * `field_ttl` - Whether to load the field ttls with HPTTL (Redis 7.4+)
 ///
 /// # Returns
-/// A tuple of (next_cursor, field-value pairs) where next_cursor is 0 when scan is complete
+/// A tuple of (next_cursor, field-value pairs, field expiration timestamps)
+/// where next_cursor is 0 when scan is complete
 async fn get_redis_hash_value(
     conn: &mut RedisAsyncConn,
-    key: &str,
+    key: &RedisKey,
     keyword: Option<SharedString>,
     cursor: u64,
     count: usize,
-) -> Result<(u64, Vec<(SharedString, SharedString)>)> {
+    field_ttl: bool,
+) -> Result<HashBatchValue> {
     // Build pattern: wrap keyword with wildcards or match all fields
     let pattern = keyword
         .as_ref()
@@ -76,21 +141,24 @@ async fn get_redis_hash_value(
 
     // Early return if no values found
     if raw_values.is_empty() {
-        return Ok((next_cursor, vec![]));
+        return Ok((next_cursor, vec![], vec![]));
     }
 
-    // Convert bytes to UTF-8 strings (lossy conversion for non-UTF8 data)
-    let values = raw_values
-        .iter()
-        .map(|(field, value)| {
-            (
-                String::from_utf8_lossy(field).to_string().into(),
-                String::from_utf8_lossy(value).to_string().into(),
-            )
-        })
+    // Keep the raw field and value bytes, binary data is displayed escaped
+    let values: Vec<(RedisBytes, RedisBytes)> = raw_values
+        .into_iter()
+        .map(|(field, value)| (RedisBytes::from(field), RedisBytes::from(value)))
         .collect();
 
-    Ok((next_cursor, values))
+    // Load the ttls of the scanned fields right after HSCAN
+    let expire_at = if field_ttl {
+        let fields: Vec<RedisBytes> = values.iter().map(|(field, _)| field.clone()).collect();
+        get_redis_hash_field_expire_at(conn, key, &fields).await?
+    } else {
+        vec![]
+    };
+
+    Ok((next_cursor, values, expire_at))
 }
 
 /// Performs initial load of a Redis HASH value.
@@ -101,15 +169,20 @@ async fn get_redis_hash_value(
 /// # Arguments
 /// * `conn` - Redis async connection
 /// * `key` - The HASH key to load
+/// * `field_ttl` - Whether the server supports hash field expiration (Redis 7.4+)
 ///
 /// # Returns
 /// A `RedisValue` containing HASH metadata and initial field-value pairs
-pub(crate) async fn first_load_hash_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisValue> {
+pub(crate) async fn first_load_hash_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    field_ttl: bool,
+) -> Result<RedisValue> {
     // Get total number of fields in the HASH
     let size: usize = cmd("HLEN").arg(key).query_async(conn).await?;
 
     // Load first batch of field-value pairs (up to 100)
-    let (cursor, values) = get_redis_hash_value(conn, key, None, 0, 100).await?;
+    let (cursor, values, expire_at) = get_redis_hash_value(conn, key, None, 0, 100, field_ttl).await?;
 
     // If cursor is 0, all values have been loaded in one iteration
     let done = cursor == 0;
@@ -121,6 +194,8 @@ pub(crate) async fn first_load_hash_value(conn: &mut RedisAsyncConn, key: &str)
             size,
             values,
             done,
+            field_ttl,
+            field_expire_at: expire_at.into_iter().collect(),
             ..Default::default()
         }))),
         ..Default::default()
@@ -138,20 +213,20 @@ impl ZedisServerState {
     /// * `new_value` - The value to set for the field
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn add_hash_value(&mut self, new_field: SharedString, new_value: SharedString, cx: &mut Context<Self>) {
-        self.add_or_update_hash_value(new_field, new_value, cx);
+        self.add_or_update_hash_value(new_field.into(), new_value.into(), cx);
     }
     /// Updates a field-value pair in the Redis HASH.
     ///
     /// Uses HSET command to update the value of the specified field.
     ///
     /// # Arguments
-    /// * `new_field` - The field name to update
-    /// * `new_value` - The value to set for the field
+    /// * `field` - The original field bytes to update
+    /// * `new_value` - The value bytes to set for the field
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn update_hash_value(&mut self, new_field: SharedString, new_value: SharedString, cx: &mut Context<Self>) {
-        self.add_or_update_hash_value(new_field, new_value, cx);
+    pub fn update_hash_value(&mut self, field: RedisBytes, new_value: RedisBytes, cx: &mut Context<Self>) {
+        self.add_or_update_hash_value(field, new_value, cx);
     }
-    fn add_or_update_hash_value(&mut self, new_field: SharedString, new_value: SharedString, cx: &mut Context<Self>) {
+    fn add_or_update_hash_value(&mut self, new_field: RedisBytes, new_value: RedisBytes, cx: &mut Context<Self>) {
         // Early return if no key/value is selected
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
@@ -174,9 +249,9 @@ impl ZedisServerState {
 
                 // HSET returns 1 if new field created, 0 if existing field updated
                 let count: usize = cmd("HSET")
-                    .arg(key.as_str())
-                    .arg(new_field.as_str())
-                    .arg(new_value.as_str())
+                    .arg(&key)
+                    .arg(&new_field)
+                    .arg(&new_value)
                     .query_async(&mut conn)
                     .await?;
                 Ok(count)
@@ -249,6 +324,8 @@ impl ZedisServerState {
         let new_hash = RedisHashValue {
             keyword: Some(keyword),
             size: hash.size,
+            field_ttl: hash.field_ttl,
+            expiring_only: hash.expiring_only,
             ..Default::default()
         };
         value.data = Some(RedisValueData::Hash(Arc::new(new_hash)));
@@ -264,7 +341,7 @@ impl ZedisServerState {
     /// # Arguments
     /// * `remove_field` - The field name to remove from the HASH
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn remove_hash_value(&mut self, remove_field: SharedString, cx: &mut Context<Self>) {
+    pub fn remove_hash_value(&mut self, remove_field: RedisBytes, cx: &mut Context<Self>) {
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
@@ -284,11 +361,7 @@ impl ZedisServerState {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
 
                 // HDEL returns number of fields removed (0 if doesn't exist, 1 if removed)
-                let count: usize = cmd("HDEL")
-                    .arg(key.as_str())
-                    .arg(remove_field.as_str())
-                    .query_async(&mut conn)
-                    .await?;
+                let count: usize = cmd("HDEL").arg(&key).arg(&remove_field).query_async(&mut conn).await?;
                 Ok(count)
             },
             // UI callback: update local state to reflect removal
@@ -302,6 +375,7 @@ impl ZedisServerState {
 
                         // Remove from local field-value list
                         hash.values.retain(|(field, _)| field != &remove_field_clone);
+                        hash.field_expire_at.remove(&remove_field_clone);
 
                         // Decrease HASH size by number of removed fields
                         hash.size -= count;
@@ -335,9 +409,9 @@ impl ZedisServerState {
         value.status = RedisValueStatus::Loading;
         cx.notify();
 
-        // Extract current cursor and filter keyword from HASH state
-        let (cursor, keyword) = match value.hash_value() {
-            Some(hash) => (hash.cursor, hash.keyword.clone()),
+        // Extract current cursor, filter keyword and ttl options from HASH state
+        let (cursor, keyword, field_ttl, expiring_only) = match value.hash_value() {
+            Some(hash) => (hash.cursor, hash.keyword.clone(), hash.field_ttl, hash.expiring_only),
             None => return,
         };
 
@@ -352,18 +426,24 @@ impl ZedisServerState {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
 
                 // Use larger batch size when filtering to reduce round trips
-                let count = if keyword.is_some() { 1000 } else { 100 };
+                let count = if keyword.is_some() || expiring_only { 1000 } else { 100 };
 
-                get_redis_hash_value(&mut conn, &key, keyword, cursor, count).await
+                get_redis_hash_value(&mut conn, &key, keyword, cursor, count, field_ttl).await
             },
             // UI callback: merge results into local state
             move |this, result, cx| {
                 let mut should_load_more = false;
-                if let Ok((new_cursor, new_values)) = result
+                if let Ok((new_cursor, mut new_values, new_expire_at)) = result
                     && let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
                 {
                     let hash = Arc::make_mut(hash_data);
                     hash.cursor = new_cursor;
+                    hash.field_expire_at.extend(new_expire_at);
+
+                    // HSCAN can't filter by ttl, drop the persistent fields locally
+                    if hash.expiring_only {
+                        new_values.retain(|(field, _)| hash.field_expire_at.contains_key(field));
+                    }
 
                     // Mark as done when cursor returns to 0 (scan complete)
                     if new_cursor == 0 {
@@ -393,4 +473,140 @@ impl ZedisServerState {
             cx,
         );
     }
+    /// Shows only the HASH fields with a ttl, or all fields again.
+    ///
+    /// Resets the scan state keeping the current keyword and triggers a load.
+    ///
+    /// # Arguments
+    /// * `expiring_only` - Whether to keep only the fields with a ttl
+    /// * `cx` - GPUI context for UI updates
+    pub fn filter_hash_expiring(&mut self, expiring_only: bool, cx: &mut Context<Self>) {
+        let Some(value) = self.value.as_mut() else {
+            return;
+        };
+        let Some(hash) = value.hash_value() else {
+            return;
+        };
+
+        let new_hash = RedisHashValue {
+            keyword: hash.keyword.clone(),
+            size: hash.size,
+            field_ttl: hash.field_ttl,
+            expiring_only,
+            ..Default::default()
+        };
+        value.data = Some(RedisValueData::Hash(Arc::new(new_hash)));
+
+        self.load_more_hash_value(cx);
+    }
+    /// Updates the ttl of a HASH field (Redis 7.4+).
+    ///
+    /// # Arguments
+    /// * `field` - The field to update
+    /// * `operation` - HEXPIRE, HPEXPIREAT or HPERSIST
+    /// * `input` - Ttl (seconds or duration like `1h`) for HEXPIRE,
+    ///   unix timestamp in milliseconds for HPEXPIREAT, ignored for HPERSIST
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn update_hash_field_ttl(
+        &mut self,
+        field: RedisBytes,
+        operation: HashFieldTtlOperation,
+        input: SharedString,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let field_clone = field.clone();
+
+        self.spawn(
+            ServerTask::UpdateHashFieldTtl,
+            // Async operation: execute the field expiration command, then reload the field ttl
+            move || async move {
+                let mut command = cmd(operation.as_str());
+                command.arg(&key);
+                match operation {
+                    HashFieldTtlOperation::Expire => {
+                        let ttl = parse_duration(input.trim())?;
+                        if ttl.as_secs() == 0 {
+                            return Err(Error::Invalid {
+                                message: format!("Invalid ttl: {input}"),
+                            });
+                        }
+                        command.arg(ttl.as_secs());
+                    }
+                    HashFieldTtlOperation::ExpireAt => {
+                        let timestamp = input.trim().parse::<u64>().map_err(|_| Error::Invalid {
+                            message: format!("Invalid timestamp: {input}"),
+                        })?;
+                        command.arg(timestamp);
+                    }
+                    HashFieldTtlOperation::Persist => {}
+                }
+                command.arg("FIELDS").arg(1).arg(&field);
+
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                // Reply per field: -2 no such field, 0 condition not met, 1 updated, 2 deleted (past time),
+                // and -1 no ttl for HPERSIST
+                let codes: Vec<i64> = command.query_async(&mut conn).await?;
+                let code = codes.first().copied().unwrap_or(-2);
+                if code == -2 {
+                    return Err(Error::Invalid {
+                        message: format!("Field {field} does not exist"),
+                    });
+                }
+                let expire_at = get_redis_hash_field_expire_at(&mut conn, &key, std::slice::from_ref(&field))
+                    .await?
+                    .first()
+                    .map(|(_, expire_at)| *expire_at);
+                Ok((code == 2, expire_at))
+            },
+            // UI callback: update the local ttl of the field
+            move |this, result, cx| {
+                if let Ok((deleted, expire_at)) = result
+                    && let Some(RedisValueData::Hash(hash_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let hash = Arc::make_mut(hash_data);
+                    if deleted {
+                        // An expiration time in the past deletes the field
+                        hash.values.retain(|(field, _)| field != &field_clone);
+                        hash.field_expire_at.remove(&field_clone);
+                        hash.size = hash.size.saturating_sub(1);
+                    } else if let Some(expire_at) = expire_at {
+                        hash.field_expire_at.insert(field_clone.clone(), expire_at);
+                    } else {
+                        hash.field_expire_at.remove(&field_clone);
+                        if hash.expiring_only {
+                            hash.values.retain(|(field, _)| field != &field_clone);
+                        }
+                    }
+                    let title = i18n_hash_editor(cx, "update_field_ttl_success");
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let msg = t!(
+                        "hash_editor.update_field_ttl_success_tips",
+                        command = operation.as_str(),
+                        field = field_clone.display(),
+                        locale = locale
+                    )
+                    .to_string();
+                    this.emit_success_notification(msg.into(), title, cx);
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
 }
@@ -0,0 +1,174 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HyperLogLog operations of string values.
+//!
+//! Strings with the `HYLL` header are shown by their estimated cardinality:
+//! - Reading the register encoding and the cardinality (PFCOUNT)
+//! - Adding elements (PFADD)
+//! - Merging into another key (PFMERGE)
+
+use super::{
+    KeyType, RedisValueData, ServerEvent, ServerTask, ZedisServerState,
+    value::{HyperLogLogEncoding, RedisBytesValue, RedisHyperLogLog, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::RedisKey,
+    states::ZedisGlobalStore,
+};
+use bytes::Bytes;
+use gpui::{SharedString, prelude::*};
+use redis::cmd;
+use std::sync::Arc;
+use uuid::Uuid;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Reads the encoding of the HyperLogLog bytes and its cardinality.
+pub(crate) async fn get_hyperloglog(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    bytes: &[u8],
+) -> Result<RedisHyperLogLog> {
+    let cardinality: u64 = cmd("PFCOUNT").arg(key).query_async(conn).await?;
+    Ok(RedisHyperLogLog {
+        encoding: HyperLogLogEncoding::from_bytes(bytes),
+        cardinality,
+        merged: None,
+    })
+}
+
+impl ZedisServerState {
+    /// Adds the whitespace separated elements to the HyperLogLog with PFADD.
+    ///
+    /// The value is loaded again, as the registers may switch from the sparse to the dense encoding.
+    pub fn add_hyperloglog_elements(&mut self, input: SharedString, cx: &mut Context<Self>) {
+        let elements: Vec<String> = input.split_whitespace().map(|s| s.to_string()).collect();
+        if elements.is_empty() || !self.ensure_writable(cx) {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(bytes_value) = value.bytes_value() else {
+            return;
+        };
+        let view_mode = bytes_value.view_mode.clone();
+        let merged = bytes_value.hyperloglog.as_ref().and_then(|v| v.merged.clone());
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::UpdateHyperLogLogValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let _: i64 = cmd("PFADD").arg(&key).arg(&elements).query_async(&mut conn).await?;
+                let bytes: Vec<u8> = cmd("GET").arg(&key).query_async(&mut conn).await?;
+                let size = cmd("MEMORY")
+                    .arg("USAGE")
+                    .arg(&key)
+                    .query_async::<u64>(&mut conn)
+                    .await
+                    .ok();
+                let mut data = RedisBytesValue {
+                    length: bytes.len(),
+                    bytes: Bytes::from(bytes),
+                    ..Default::default()
+                };
+                data.detect_and_update(server_id.as_str(), key.display_str(), max_truncate_length);
+                data.view_mode = view_mode;
+                let mut hyperloglog = get_hyperloglog(&mut conn, &key, &data.bytes).await?;
+                hyperloglog.merged = merged;
+                data.hyperloglog = Some(hyperloglog);
+                Ok((size, data))
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((size, data)) = result {
+                        if let Some(size) = size {
+                            value.size = size;
+                        }
+                        value.data = Some(RedisValueData::Bytes(Arc::new(data)));
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Merges the HyperLogLog into the target key with PFMERGE, the target keeps its own elements.
+    pub fn merge_hyperloglog(&mut self, target: SharedString, cx: &mut Context<Self>) {
+        let target = target.trim().to_string();
+        if target.is_empty() || !self.ensure_writable(cx) {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let current_key = key.clone();
+        let target_key = RedisKey::from(target.as_str());
+        self.spawn(
+            ServerTask::MergeHyperLogLogValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let _: () = cmd("PFMERGE")
+                    .arg(target.as_str())
+                    .arg(&key)
+                    .query_async(&mut conn)
+                    .await?;
+                let cardinality: u64 = cmd("PFCOUNT").arg(target.as_str()).query_async(&mut conn).await?;
+                Ok((target, cardinality))
+            },
+            move |this, result, cx| {
+                // PFMERGE creates the target key when it does not exist
+                if result.is_ok() && !this.keys.contains_key(&target_key) {
+                    this.keys.insert(target_key, KeyType::String);
+                    // Force refresh of the key tree view
+                    this.key_tree_id = Uuid::now_v7().to_string().into();
+                }
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((target, cardinality)) = result
+                        && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                        && let Some(hyperloglog) = Arc::make_mut(bytes_value).hyperloglog.as_mut()
+                    {
+                        hyperloglog.merged = Some((target.into(), cardinality));
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RedisJSON (`ReJSON-RL`) data type operations module.
+//!
+//! This module provides functionality for managing RedisJSON documents including:
+//! - Loading the whole document as pretty-printed JSON (JSON.GET)
+//! - Replacing the whole document (JSON.SET at the root path)
+//! - Path-level edits without rewriting the document (JSON.SET / JSON.DEL / JSON.ARRAPPEND)
+
+use super::{
+    KeyType, RedisValueData, ServerTask, ZedisServerState,
+    value::{DataFormat, RedisBytesValue, RedisValue, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::RedisKey,
+    states::{ServerEvent, ZedisGlobalStore, i18n_json_editor},
+};
+use bytes::Bytes;
+use gpui::{SharedString, prelude::*};
+use redis::cmd;
+use rust_i18n::t;
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Root JSONPath of a document
+pub const JSON_ROOT_PATH: &str = "$";
+
+/// Path-level operations supported on a RedisJSON document
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JsonPathOperation {
+    /// Set the value at the path (JSON.SET)
+    Set,
+    /// Append the value to the array at the path (JSON.ARRAPPEND)
+    Append,
+    /// Delete the value at the path (JSON.DEL)
+    Delete,
+}
+
+impl JsonPathOperation {
+    /// Returns the Redis command used for this operation
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JsonPathOperation::Set => "JSON.SET",
+            JsonPathOperation::Append => "JSON.ARRAPPEND",
+            JsonPathOperation::Delete => "JSON.DEL",
+        }
+    }
+}
+
+/// Validates that the value is a well-formed JSON text.
+pub(crate) fn validate_json(value: &str) -> Result<()> {
+    serde_json::from_str::<serde_json::Value>(value).map_err(|e| Error::Invalid {
+        message: format!("Invalid JSON: {e}"),
+    })?;
+    Ok(())
+}
+
+/// Loads the whole RedisJSON document and pretty-prints it.
+///
+/// Long strings are never truncated here, because the text is saved back as is.
+pub(crate) async fn get_redis_json_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisBytesValue> {
+    let value: Option<String> = cmd("JSON.GET").arg(key).query_async(conn).await?;
+    let value = value.unwrap_or_default();
+    let text = serde_json::from_str::<serde_json::Value>(&value)
+        .ok()
+        .and_then(|v| serde_json::to_string_pretty(&v).ok())
+        .unwrap_or_else(|| value.clone());
+
+    Ok(RedisBytesValue {
+        format: DataFormat::Json,
+        length: value.len(),
+        bytes: Bytes::from(value.into_bytes()),
+        text: Some(text.into()),
+        ..Default::default()
+    })
+}
+
+/// Performs the initial load of a RedisJSON value.
+pub(crate) async fn first_load_json_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisValue> {
+    let data = get_redis_json_value(conn, key).await?;
+    Ok(RedisValue {
+        key_type: KeyType::Json,
+        data: Some(RedisValueData::Bytes(Arc::new(data))),
+        ..Default::default()
+    })
+}
+
+impl ZedisServerState {
+    /// Applies a path-level edit to a RedisJSON document and reloads it.
+    ///
+    /// # Arguments
+    /// * `operation` - The edit to perform (set, append or delete)
+    /// * `path` - JSONPath to edit, defaults to the root path when empty
+    /// * `new_value` - JSON value to set or append (ignored for delete)
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn update_json_path(
+        &mut self,
+        operation: JsonPathOperation,
+        path: SharedString,
+        new_value: SharedString,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let path = if path.trim().is_empty() {
+            JSON_ROOT_PATH.to_string()
+        } else {
+            path.trim().to_string()
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let task_path = path.clone();
+
+        self.spawn(
+            ServerTask::UpdateJsonPath,
+            // Async operation: run the path command then read the document back
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut command = cmd(operation.as_str());
+                command.arg(&key).arg(task_path.as_str());
+                if operation != JsonPathOperation::Delete {
+                    validate_json(new_value.as_str())?;
+                    command.arg(new_value.as_str());
+                }
+                let result: redis::Value = command.query_async(&mut conn).await?;
+                // JSON.SET returns nil when the parent of the path does not exist
+                if operation == JsonPathOperation::Set && result == redis::Value::Nil {
+                    return Err(Error::Invalid {
+                        message: format!("Path not found: {task_path}"),
+                    });
+                }
+
+                let data = get_redis_json_value(&mut conn, &key).await?;
+                let size = cmd("MEMORY")
+                    .arg("USAGE")
+                    .arg(&key)
+                    .query_async::<u64>(&mut conn)
+                    .await
+                    .ok();
+                Ok((data, size))
+            },
+            // UI callback: replace the document with the reloaded one
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((data, size)) = result {
+                        let view_mode = value.bytes_value().map(|v| v.view_mode.clone()).unwrap_or_default();
+                        value.data = Some(RedisValueData::Bytes(Arc::new(RedisBytesValue { view_mode, ..data })));
+                        if let Some(size) = size {
+                            value.size = size;
+                        }
+                        let title = i18n_json_editor(cx, "update_path_success");
+                        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                        let msg = t!(
+                            "json_editor.update_path_success_tips",
+                            command = operation.as_str(),
+                            path = path,
+                            locale = locale
+                        )
+                        .to_string();
+                        this.emit_success_notification(msg.into(), title, cx);
+                        // The document is reloaded, so the editor text has to be refreshed
+                        cx.emit(ServerEvent::ValueLoaded);
+                    }
+                }
+                cx.notify();
+                if this
+                    .value
+                    .as_ref()
+                    .and_then(|v| v.bytes_value())
+                    .is_some_and(|v| v.view_mode.is_document())
+                {
+                    this.load_document(cx);
+                }
+            },
+            cx,
+        );
+    }
+}
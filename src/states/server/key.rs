@@ -14,11 +14,17 @@
 
 use super::{
     ServerEvent, ServerTask, ZedisServerState,
-    hash::first_load_hash_value,
+    hash::{HASH_FIELD_TTL_VERSION, first_load_hash_value},
+    hyperloglog::get_hyperloglog,
+    json::{JSON_ROOT_PATH, first_load_json_value},
     list::first_load_list_value,
+    probabilistic::first_load_probabilistic_value,
     set::first_load_set_value,
+    stream::first_load_stream_value,
     string::get_redis_bytes_value,
-    value::{KeyType, RedisValue, RedisValueData, RedisValueStatus, SortOrder},
+    timeseries::first_load_timeseries_value,
+    value::{DataFormat, KeyType, RedisValue, RedisValueData, RedisValueStatus, SortOrder, ViewMode},
+    vectorset::first_load_vectorset_value,
     zset::first_load_zset_value,
 };
 use crate::db::add_normalize_history;
@@ -26,7 +32,7 @@ use crate::states::ZedisGlobalStore;
 use crate::{
     connection::{QueryMode, get_connection_manager},
     error::Error,
-    helpers::{parse_duration, unix_ts},
+    helpers::{RedisKey, parse_duration, unix_ts},
 };
 use futures::future::try_join_all;
 use futures::{StreamExt, stream};
@@ -61,12 +67,12 @@ impl ZedisServerState {
                 if prefix.is_empty() {
                     // if no prefix, only fill keys that are not in a subdirectory
                     // or if the count is less than 1000
-                    if count < 1000 || !key.contains(separator) {
+                    if count < 1000 || !key.display_str().contains(separator) {
                         return Some(key.clone());
                     }
                     return None;
                 };
-                let suffix = key.strip_prefix(prefix)?;
+                let suffix = key.display_str().strip_prefix(prefix)?;
                 // Skip if the key is in a deeper subdirectory (contains delimiter)
                 if suffix.contains(separator) {
                     return None;
@@ -74,7 +80,7 @@ impl ZedisServerState {
                 Some(key.clone())
             })
             .take(2000)
-            .collect::<Vec<SharedString>>();
+            .collect::<Vec<RedisKey>>();
         debug!(prefix, size = keys.len(), "fill key types");
         if keys.is_empty() {
             return;
@@ -88,13 +94,13 @@ impl ZedisServerState {
             move || async move {
                 let conn = get_connection_manager().get_connection(&server_id, db).await?;
                 // Use a stream to execute commands concurrently with backpressure
-                let types: Vec<(SharedString, String)> = stream::iter(keys.iter().cloned())
+                let types: Vec<(RedisKey, String)> = stream::iter(keys.iter().cloned())
                     .map(|key| {
                         let mut conn_clone = conn.clone();
                         let key = key.clone();
                         async move {
                             let t: String = cmd("TYPE")
-                                .arg(key.as_str())
+                                .arg(&key)
                                 .query_async(&mut conn_clone)
                                 .await
                                 .unwrap_or_default();
@@ -207,7 +213,8 @@ impl ZedisServerState {
         }
         match self.query_mode {
             QueryMode::Prefix => self.scan_prefix(keyword, cx),
-            QueryMode::Exact => self.select_key(keyword, cx),
+            // the keyword is the display form, so escaped binary keys can be found
+            QueryMode::Exact => self.select_key(RedisKey::from_display(&keyword), cx),
             _ => self.scan(keyword, cx),
         }
     }
@@ -323,7 +330,8 @@ impl ZedisServerState {
     }
 
     /// Selects a key and fetches its details (Type, TTL, Value).
-    pub fn select_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+    pub fn select_key(&mut self, key: RedisKey, cx: &mut Context<Self>) {
+        self.cancel_string_load(cx);
         self.key = Some(key.clone());
         if key.is_empty() {
             return;
@@ -345,7 +353,9 @@ impl ZedisServerState {
         let server_id = self.server_id.clone();
         let db = self.db;
         let current_key = key.clone();
-        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        let store = cx.global::<ZedisGlobalStore>().read(cx);
+        let max_truncate_length = store.max_truncate_length();
+        let large_value_threshold = store.large_value_threshold() * 1024;
 
         self.spawn(
             ServerTask::Selectkey,
@@ -353,9 +363,9 @@ impl ZedisServerState {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
                 let (t, ttl): (String, i64) = pipe()
                     .cmd("TYPE")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .cmd("TTL")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .query_async(&mut conn)
                     .await?;
                 // the key does not exist
@@ -375,8 +385,16 @@ impl ZedisServerState {
                 let key_type = KeyType::from(t.as_str());
                 let mut redis_value = match key_type {
                     KeyType::String => {
-                        let mut data = get_redis_bytes_value(&mut conn, &key).await?;
-                        data.detect_and_update(server_id.as_str(), key.as_str(), max_truncate_length);
+                        let mut data = get_redis_bytes_value(&mut conn, &key, large_value_threshold).await?;
+                        if data.is_partial() {
+                            data.detect_prefix(server_id.as_str(), key.display_str());
+                        } else {
+                            data.detect_and_update(server_id.as_str(), key.display_str(), max_truncate_length);
+                        }
+                        // a corrupted HyperLogLog is still shown as bytes
+                        if data.format == DataFormat::HyperLogLog {
+                            data.hyperloglog = get_hyperloglog(&mut conn, &key, &data.bytes).await.ok();
+                        }
                         Ok(RedisValue {
                             key_type: KeyType::String,
                             data: Some(RedisValueData::Bytes(Arc::new(data))),
@@ -386,17 +404,23 @@ impl ZedisServerState {
                     KeyType::List => first_load_list_value(&mut conn, &key).await,
                     KeyType::Set => first_load_set_value(&mut conn, &key).await,
                     KeyType::Zset => first_load_zset_value(&mut conn, &key, SortOrder::Asc).await,
-                    KeyType::Hash => first_load_hash_value(&mut conn, &key).await,
+                    KeyType::Hash => {
+                        let client = get_connection_manager().get_client(&server_id, db).await?;
+                        let field_ttl = client.is_at_least_version(HASH_FIELD_TTL_VERSION);
+                        first_load_hash_value(&mut conn, &key, field_ttl).await
+                    }
+                    KeyType::Stream => first_load_stream_value(&mut conn, &key).await,
+                    KeyType::Vectorset => first_load_vectorset_value(&mut conn, &key).await,
+                    KeyType::Json => first_load_json_value(&mut conn, &key).await,
+                    KeyType::Timeseries => first_load_timeseries_value(&mut conn, &key).await,
+                    KeyType::Bloom | KeyType::Cuckoo | KeyType::CountMinSketch | KeyType::TopK | KeyType::TDigest => {
+                        first_load_probabilistic_value(&mut conn, &key, key_type).await
+                    }
                     _ => Err(Error::Invalid {
                         message: "unsupported key type".to_string(),
                     }),
                 }?;
-                if let Ok(memory_usage) = cmd("MEMORY")
-                    .arg("USAGE")
-                    .arg(key.as_str())
-                    .query_async::<u64>(&mut conn)
-                    .await
-                {
+                if let Ok(memory_usage) = cmd("MEMORY").arg("USAGE").arg(&key).query_async::<u64>(&mut conn).await {
                     redis_value.size = memory_usage;
                 }
 
@@ -437,11 +461,22 @@ impl ZedisServerState {
                 };
                 cx.emit(ServerEvent::ValueLoaded);
                 cx.notify();
+                // a rule may show the value as a bitmap, a tree or a table directly
+                let view_mode = this
+                    .value
+                    .as_ref()
+                    .and_then(|v| v.bytes_value())
+                    .map(|v| v.view_mode.clone());
+                match view_mode {
+                    Some(ViewMode::Bitmap) => this.load_bitmap_summary(cx),
+                    Some(view_mode) if view_mode.is_document() => this.load_document(cx),
+                    _ => {}
+                }
             },
             cx,
         );
     }
-    pub fn delete_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+    pub fn delete_key(&mut self, key: RedisKey, cx: &mut Context<Self>) {
         let server_id = self.server_id.clone();
         let db = self.db;
         let remove_key = key.clone();
@@ -449,7 +484,7 @@ impl ZedisServerState {
             ServerTask::DeleteKey,
             move || async move {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
-                let _: () = cmd("DEL").arg(key.as_str()).query_async(&mut conn).await?;
+                let _: () = cmd("DEL").arg(&key).query_async(&mut conn).await?;
                 Ok(())
             },
             move |this, result, cx| {
@@ -491,7 +526,7 @@ impl ZedisServerState {
                     if !keys.is_empty() {
                         let mut pipe = redis::pipe();
                         for key in keys {
-                            pipe.cmd("UNLINK").arg(key.as_str());
+                            pipe.cmd("UNLINK").arg(&key);
                         }
                         let mut conn = client.connection();
                         let _: () = pipe.query_async(&mut conn).await?;
@@ -508,7 +543,8 @@ impl ZedisServerState {
             },
             move |this, result, cx| {
                 if let Ok(()) = result {
-                    this.keys.retain(|key, _| !key.starts_with(prefix.as_str()));
+                    this.keys
+                        .retain(|key, _| !key.display_str().starts_with(prefix.as_str()));
                     // Force refresh of the key tree view
                     this.key_tree_id = Uuid::now_v7().to_string().into();
                 }
@@ -517,7 +553,7 @@ impl ZedisServerState {
             cx,
         );
     }
-    pub fn unlink_key(&mut self, keys: Vec<SharedString>, cx: &mut Context<Self>) {
+    pub fn unlink_key(&mut self, keys: Vec<RedisKey>, cx: &mut Context<Self>) {
         let server_id = self.server_id.clone();
         let db = self.db;
         let remove_keys = keys.clone();
@@ -529,7 +565,7 @@ impl ZedisServerState {
                     let mut conn = client.connection();
                     let mut pipe = redis::pipe();
                     for key in keys {
-                        pipe.cmd("UNLINK").arg(key.as_str());
+                        pipe.cmd("UNLINK").arg(&key);
                     }
                     let _: () = pipe.query_async(&mut conn).await?;
                     return Ok(());
@@ -541,7 +577,7 @@ impl ZedisServerState {
                     let futures = chunk.iter().map(|key| {
                         let mut conn_clone = conn.clone();
                         async move {
-                            let _: () = cmd("UNLINK").arg(key.as_str()).query_async(&mut conn_clone).await?;
+                            let _: () = cmd("UNLINK").arg(key).query_async(&mut conn_clone).await?;
                             Ok::<(), Error>(())
                         }
                     });
@@ -561,7 +597,7 @@ impl ZedisServerState {
         );
     }
     /// Deletes a specified key.
-    pub fn delete_select_key(&mut self, key: SharedString, cx: &mut Context<Self>) {
+    pub fn delete_select_key(&mut self, key: RedisKey, cx: &mut Context<Self>) {
         let Some(value) = self.value.as_mut() else {
             return;
         };
@@ -570,7 +606,7 @@ impl ZedisServerState {
         self.delete_key(key, cx);
     }
     /// Updates the TTL (expiration) for a key.
-    pub fn update_key_ttl(&mut self, key: SharedString, ttl: SharedString, cx: &mut Context<Self>) {
+    pub fn update_key_ttl(&mut self, key: RedisKey, ttl: SharedString, cx: &mut Context<Self>) {
         if ttl.is_empty() {
             return;
         }
@@ -605,7 +641,7 @@ impl ZedisServerState {
                 }
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
                 let _: () = cmd("EXPIRE")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(new_ttl.as_secs())
                     .query_async(&mut conn)
                     .await?;
@@ -628,12 +664,14 @@ impl ZedisServerState {
         let server_id = self.server_id.clone();
         let db = self.db;
         let key_type = KeyType::from(category.to_lowercase().as_str());
+        // Keys typed by the user are sent as their UTF-8 bytes
+        let key = RedisKey::from(key);
         let key_clone = key.clone();
         self.spawn(
             ServerTask::AddKey,
             move || async move {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
-                let exists: bool = cmd("EXISTS").arg(key.as_str()).query_async(&mut conn).await?;
+                let exists: bool = cmd("EXISTS").arg(&key).query_async(&mut conn).await?;
                 let ttl_duration = if ttl.is_empty() {
                     None
                 } else if let Ok(secs) = ttl.parse::<u64>() {
@@ -650,25 +688,17 @@ impl ZedisServerState {
                 }
                 match key_type {
                     KeyType::String => {
-                        let _: () = cmd("SET").arg(key.as_str()).arg("").query_async(&mut conn).await?;
+                        let _: () = cmd("SET").arg(&key).arg("").query_async(&mut conn).await?;
                     }
                     KeyType::List => {
-                        let _: () = cmd("LPUSH")
-                            .arg(key.as_str())
-                            .arg("list item 1")
-                            .query_async(&mut conn)
-                            .await?;
+                        let _: () = cmd("LPUSH").arg(&key).arg("list item 1").query_async(&mut conn).await?;
                     }
                     KeyType::Set => {
-                        let _: () = cmd("SADD")
-                            .arg(key.as_str())
-                            .arg("set item 1")
-                            .query_async(&mut conn)
-                            .await?;
+                        let _: () = cmd("SADD").arg(&key).arg("set item 1").query_async(&mut conn).await?;
                     }
                     KeyType::Zset => {
                         let _: () = cmd("ZADD")
-                            .arg(key.as_str())
+                            .arg(&key)
                             .arg(1.0)
                             .arg("zset item 1")
                             .query_async(&mut conn)
@@ -676,12 +706,32 @@ impl ZedisServerState {
                     }
                     KeyType::Hash => {
                         let _: () = cmd("HSET")
-                            .arg(key.as_str())
+                            .arg(&key)
+                            .arg("field1")
+                            .arg("value1")
+                            .query_async(&mut conn)
+                            .await?;
+                    }
+                    KeyType::Stream => {
+                        let _: () = cmd("XADD")
+                            .arg(&key)
+                            .arg("*")
                             .arg("field1")
                             .arg("value1")
                             .query_async(&mut conn)
                             .await?;
                     }
+                    KeyType::Json => {
+                        let _: () = cmd("JSON.SET")
+                            .arg(&key)
+                            .arg(JSON_ROOT_PATH)
+                            .arg("{}")
+                            .query_async(&mut conn)
+                            .await?;
+                    }
+                    KeyType::Timeseries => {
+                        let _: () = cmd("TS.CREATE").arg(&key).query_async(&mut conn).await?;
+                    }
                     _ => {
                         return Err(Error::Invalid {
                             message: "Invalid key type".to_string(),
@@ -690,7 +740,7 @@ impl ZedisServerState {
                 };
                 if let Some(ttl_duration) = ttl_duration {
                     let _: () = cmd("EXPIRE")
-                        .arg(key.as_str())
+                        .arg(&key)
                         .arg(ttl_duration.as_secs())
                         .query_async(&mut conn)
                         .await?;
@@ -19,6 +19,7 @@ use super::{
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
+    helpers::{RedisBytes, RedisKey},
     states::ServerEvent,
 };
 use gpui::{SharedString, prelude::*};
@@ -30,27 +31,28 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// Fetch a range of elements from a Redis List.
 ///
-/// Returns a vector of strings. Binary data is lossily converted to UTF-8.
-async fn get_redis_list_value(conn: &mut RedisAsyncConn, key: &str, start: usize, stop: usize) -> Result<Vec<String>> {
+/// Returns the raw elements. Binary data is kept as is and displayed escaped.
+async fn get_redis_list_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    start: usize,
+    stop: usize,
+) -> Result<Vec<RedisBytes>> {
     // Fetch raw bytes to handle binary data safely
     let value: Vec<Vec<u8>> = cmd("LRANGE").arg(key).arg(start).arg(stop).query_async(conn).await?;
-    if value.is_empty() {
-        return Ok(vec![]);
-    }
-    let value: Vec<String> = value.iter().map(|v| String::from_utf8_lossy(v).to_string()).collect();
-    Ok(value)
+    Ok(value.into_iter().map(RedisBytes::from).collect())
 }
 
 /// Initial load for a List key.
 /// Fetches the total length (LLEN) and the first 100 items.
-pub(crate) async fn first_load_list_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisValue> {
+pub(crate) async fn first_load_list_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisValue> {
     let size: usize = cmd("LLEN").arg(key).query_async(conn).await?;
     let values = get_redis_list_value(conn, key, 0, 99).await?;
     Ok(RedisValue {
         key_type: KeyType::List,
         data: Some(RedisValueData::List(Arc::new(RedisListValue {
             size,
-            values: values.into_iter().map(|v| v.into()).collect(),
+            values,
             ..Default::default()
         }))),
         expire_at: None,
@@ -90,11 +92,11 @@ impl ZedisServerState {
                 let _: () = pipe()
                     .atomic()
                     .cmd("LSET")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(index)
                     .arg(&unique_marker)
                     .cmd("LREM")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(1)
                     .arg(&unique_marker)
                     .query_async(&mut conn)
@@ -125,15 +127,16 @@ impl ZedisServerState {
         };
         let is_lpush = mode == "1";
         let mut pushed_value = false;
+        let pushed = RedisBytes::from(new_value.clone());
         value.status = RedisValueStatus::Updating;
         if let Some(RedisValueData::List(list_data)) = value.data.as_mut() {
             // Use Arc::make_mut to get mutable access (Cow behavior)
             let list = Arc::make_mut(list_data);
             if is_lpush {
-                list.values.insert(0, new_value.clone());
+                list.values.insert(0, pushed);
                 pushed_value = true;
             } else if list.values.len() == list.size {
-                list.values.push(new_value.clone());
+                list.values.push(pushed);
                 pushed_value = true;
             }
             list.size += 1;
@@ -149,7 +152,7 @@ impl ZedisServerState {
                 let cmd_name = if is_lpush { "LPUSH" } else { "RPUSH" };
 
                 let _: () = cmd(cmd_name)
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(new_value.as_str())
                     .query_async(&mut conn)
                     .await?;
@@ -184,11 +187,12 @@ impl ZedisServerState {
     ///
     /// Performs an optimistic lock check: verifies if the current value at `index`
     /// matches `original_value` before updating.
+    /// `new_value` is sent as raw bytes, use `RedisBytes::from_display` for edited display text.
     pub fn update_list_value(
         &mut self,
         index: usize,
-        original_value: SharedString,
-        new_value: SharedString,
+        original_value: RedisBytes,
+        new_value: RedisBytes,
         cx: &mut Context<Self>,
     ) {
         let Some((key, value)) = self.try_get_mut_key_value() else {
@@ -219,26 +223,23 @@ impl ZedisServerState {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
 
                 // 1. Optimistic Lock Check: Get current value
-                let current_value: String = cmd("LINDEX")
-                    .arg(key.as_str())
-                    .arg(index)
-                    .query_async(&mut conn)
-                    .await?;
+                let current_value: Vec<u8> = cmd("LINDEX").arg(&key).arg(index).query_async(&mut conn).await?;
 
-                if current_value != original_value_clone {
+                if current_value != original_value_clone.as_bytes() {
                     return Err(Error::Invalid {
                         message: format!(
                             "Value changed (expected: '{}', actual: '{}'), update aborted.",
-                            original_value_clone, current_value
+                            original_value_clone,
+                            RedisBytes::from(current_value)
                         ),
                     });
                 }
 
                 // 2. Perform Update
                 let _: () = cmd("LSET")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(index)
-                    .arg(new_value_clone.as_str())
+                    .arg(&new_value_clone)
                     .query_async(&mut conn)
                     .await?;
 
@@ -301,7 +302,7 @@ impl ZedisServerState {
                     // Append new items to the existing list
                     if let Some(RedisValueData::List(list_data)) = this.value.as_mut().and_then(|v| v.data.as_mut()) {
                         let list = Arc::make_mut(list_data);
-                        list.values.extend(new_values.into_iter().map(|v| v.into()));
+                        list.values.extend(new_values);
                     }
                 }
                 cx.emit(ServerEvent::ValuePaginationFinished);
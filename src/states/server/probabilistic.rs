@@ -0,0 +1,207 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RedisBloom probabilistic data type operations module.
+//!
+//! This module provides read-only inspection of the RedisBloom structures:
+//! - Bloom filter: BF.INFO and membership queries (BF.MEXISTS)
+//! - Cuckoo filter: CF.INFO and membership queries (CF.EXISTS)
+//! - Count-Min sketch: CMS.INFO and frequency queries (CMS.QUERY)
+//! - Top-K: TOPK.INFO, TOPK.LIST WITHCOUNT and membership queries (TOPK.QUERY)
+//! - t-digest: TDIGEST.INFO and quantile queries (TDIGEST.QUANTILE)
+
+use super::{
+    KeyType, RedisValueData, ServerTask, ZedisServerState,
+    value::{RedisProbabilisticValue, RedisValue, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::{RedisKey, redis_value_to_pairs, redis_value_to_string},
+    states::ServerEvent,
+};
+use gpui::{SharedString, prelude::*};
+use redis::{Value, cmd, pipe};
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Returns the INFO command of a probabilistic key type.
+fn info_command(key_type: KeyType) -> Option<&'static str> {
+    match key_type {
+        KeyType::Bloom => Some("BF.INFO"),
+        KeyType::Cuckoo => Some("CF.INFO"),
+        KeyType::CountMinSketch => Some("CMS.INFO"),
+        KeyType::TopK => Some("TOPK.INFO"),
+        KeyType::TDigest => Some("TDIGEST.INFO"),
+        _ => None,
+    }
+}
+
+/// Formats a membership reply (`1` / `0`) as `true` / `false`.
+fn format_exists(value: &Value) -> SharedString {
+    let exists = match value {
+        Value::Int(v) => *v == 1,
+        Value::Boolean(v) => *v,
+        _ => redis_value_to_string(value) == "1",
+    };
+    exists.to_string().into()
+}
+
+/// Loads the INFO of a probabilistic structure, and the top items of a Top-K.
+pub(crate) async fn first_load_probabilistic_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    key_type: KeyType,
+) -> Result<RedisValue> {
+    let Some(command) = info_command(key_type) else {
+        return Err(Error::Invalid {
+            message: "unsupported key type".to_string(),
+        });
+    };
+    let value: Value = cmd(command).arg(key).query_async(conn).await?;
+    let info = redis_value_to_pairs(value)
+        .into_iter()
+        .map(|(name, value)| (name.into(), redis_value_to_string(&value).into()))
+        .collect();
+
+    let mut items = vec![];
+    if key_type == KeyType::TopK {
+        let value: Value = cmd("TOPK.LIST").arg(key).arg("WITHCOUNT").query_async(conn).await?;
+        items = redis_value_to_pairs(value)
+            .into_iter()
+            .map(|(item, count)| (item.into(), redis_value_to_string(&count).into()))
+            .collect();
+    }
+
+    Ok(RedisValue {
+        key_type,
+        data: Some(RedisValueData::Probabilistic(Arc::new(RedisProbabilisticValue {
+            info,
+            items,
+            results: None,
+        }))),
+        ..Default::default()
+    })
+}
+
+impl ZedisServerState {
+    /// Queries a probabilistic structure with whitespace separated inputs.
+    ///
+    /// The inputs are items for the membership and frequency queries,
+    /// and quantiles between 0 and 1 for t-digest.
+    ///
+    /// # Arguments
+    /// * `input` - Whitespace separated items or quantiles
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn query_probabilistic_value(&mut self, input: SharedString, cx: &mut Context<Self>) {
+        let inputs: Vec<String> = input.split_whitespace().map(|s| s.to_string()).collect();
+        if inputs.is_empty() {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let key_type = value.key_type;
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::QueryProbabilisticValue,
+            // Async operation: execute the query command of the type
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let results: Vec<SharedString> = match key_type {
+                    KeyType::Bloom | KeyType::TopK => {
+                        let command = if key_type == KeyType::Bloom {
+                            "BF.MEXISTS"
+                        } else {
+                            "TOPK.QUERY"
+                        };
+                        let values: Vec<Value> = cmd(command).arg(&key).arg(&inputs).query_async(&mut conn).await?;
+                        values.iter().map(format_exists).collect()
+                    }
+                    KeyType::Cuckoo => {
+                        // CF.EXISTS checks one item per call
+                        let mut pipeline = pipe();
+                        for item in inputs.iter() {
+                            pipeline.cmd("CF.EXISTS").arg(&key).arg(item);
+                        }
+                        let values: Vec<Value> = pipeline.query_async(&mut conn).await?;
+                        values.iter().map(format_exists).collect()
+                    }
+                    KeyType::CountMinSketch => {
+                        let values: Vec<Value> = cmd("CMS.QUERY").arg(&key).arg(&inputs).query_async(&mut conn).await?;
+                        values.iter().map(|v| redis_value_to_string(v).into()).collect()
+                    }
+                    KeyType::TDigest => {
+                        if let Some(quantile) = inputs
+                            .iter()
+                            .find(|q| !q.parse::<f64>().is_ok_and(|q| (0.0..=1.0).contains(&q)))
+                        {
+                            return Err(Error::Invalid {
+                                message: format!("Invalid quantile: {quantile}"),
+                            });
+                        }
+                        let values: Vec<Value> = cmd("TDIGEST.QUANTILE")
+                            .arg(&key)
+                            .arg(&inputs)
+                            .query_async(&mut conn)
+                            .await?;
+                        values.iter().map(|v| redis_value_to_string(v).into()).collect()
+                    }
+                    _ => {
+                        return Err(Error::Invalid {
+                            message: "unsupported key type".to_string(),
+                        });
+                    }
+                };
+                Ok(inputs
+                    .into_iter()
+                    .map(SharedString::from)
+                    .zip(results)
+                    .collect::<Vec<(SharedString, SharedString)>>())
+            },
+            // UI callback: store results in local state
+            move |this, result, cx| {
+                if let Ok(results) = result
+                    && let Some(RedisValueData::Probabilistic(probabilistic_data)) =
+                        this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    Arc::make_mut(probabilistic_data).results = Some(results);
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Clears the query results.
+    pub fn clear_probabilistic_query(&mut self, cx: &mut Context<Self>) {
+        if let Some(RedisValueData::Probabilistic(probabilistic_data)) =
+            self.value.as_mut().and_then(|v| v.data.as_mut())
+        {
+            Arc::make_mut(probabilistic_data).results = None;
+            cx.emit(ServerEvent::ValueUpdated);
+            cx.notify();
+        }
+    }
+}
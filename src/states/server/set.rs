@@ -19,6 +19,7 @@ use super::{
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
+    helpers::{RedisBytes, RedisKey},
     states::{ServerEvent, i18n_set_editor},
 };
 use gpui::{SharedString, prelude::*};
@@ -40,11 +41,11 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 /// A tuple of (next_cursor, values) where next_cursor is 0 when scan is complete
 async fn get_redis_set_value(
     conn: &mut RedisAsyncConn,
-    key: &str,
+    key: &RedisKey,
     keyword: Option<SharedString>,
     cursor: u64,
     count: usize,
-) -> Result<(u64, Vec<String>)> {
+) -> Result<(u64, Vec<RedisBytes>)> {
     // Build pattern: wrap keyword with wildcards or match all
     let pattern = keyword
         .as_ref()
@@ -62,16 +63,8 @@ async fn get_redis_set_value(
         .query_async(conn)
         .await?;
 
-    // Early return if no values found
-    if raw_values.is_empty() {
-        return Ok((next_cursor, vec![]));
-    }
-
-    // Convert bytes to UTF-8 strings (lossy conversion for non-UTF8 data)
-    let values = raw_values
-        .iter()
-        .map(|v| String::from_utf8_lossy(v).to_string())
-        .collect();
+    // Keep the raw bytes, binary members are displayed escaped
+    let values = raw_values.into_iter().map(RedisBytes::from).collect();
 
     Ok((next_cursor, values))
 }
@@ -87,7 +80,7 @@ async fn get_redis_set_value(
 ///
 /// # Returns
 /// A `RedisValue` containing SET metadata and initial member values
-pub(crate) async fn first_load_set_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisValue> {
+pub(crate) async fn first_load_set_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisValue> {
     // Get total number of members in the SET
     let size: usize = cmd("SCARD").arg(key).query_async(conn).await?;
 
@@ -102,7 +95,7 @@ pub(crate) async fn first_load_set_value(conn: &mut RedisAsyncConn, key: &str) -
         data: Some(RedisValueData::Set(Arc::new(RedisSetValue {
             cursor,
             size,
-            values: values.into_iter().map(|v| v.into()).collect(),
+            values,
             done,
             ..Default::default()
         }))),
@@ -131,7 +124,7 @@ impl ZedisServerState {
 
         let server_id = self.server_id.clone();
         let db = self.db;
-        let new_value_clone = new_value.clone();
+        let new_value_clone = RedisBytes::from(new_value.clone());
 
         self.spawn(
             ServerTask::AddSetValue,
@@ -141,7 +134,7 @@ impl ZedisServerState {
 
                 // SADD returns number of elements added (0 if already exists, 1 if new)
                 let count: usize = cmd("SADD")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(new_value.as_str())
                     .query_async(&mut conn)
                     .await?;
@@ -269,7 +262,7 @@ impl ZedisServerState {
 
                     // Append new members to existing list
                     if !new_values.is_empty() {
-                        set.values.extend(new_values.into_iter().map(|v| v.into()));
+                        set.values.extend(new_values);
                     }
 
                     // Auto-load more batches when filtering until we have enough results
@@ -303,7 +296,7 @@ impl ZedisServerState {
     /// # Arguments
     /// * `remove_value` - The member value to remove from the SET
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn remove_set_value(&mut self, remove_value: SharedString, cx: &mut Context<Self>) {
+    pub fn remove_set_value(&mut self, remove_value: RedisBytes, cx: &mut Context<Self>) {
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
@@ -323,11 +316,7 @@ impl ZedisServerState {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
 
                 // SREM returns number of members removed (0 if doesn't exist, 1 if removed)
-                let count: usize = cmd("SREM")
-                    .arg(key.as_str())
-                    .arg(remove_value.as_str())
-                    .query_async(&mut conn)
-                    .await?;
+                let count: usize = cmd("SREM").arg(&key).arg(&remove_value).query_async(&mut conn).await?;
                 Ok(count)
             },
             // UI callback: update local state to reflect removal
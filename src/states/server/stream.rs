@@ -0,0 +1,911 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis STREAM data type operations module.
+//!
+//! This module provides functionality for managing Redis STREAM operations including:
+//! - Loading stream entries by ID range with pagination (XRANGE / XREVRANGE)
+//! - Appending new entries to a stream (XADD)
+//! - Removing entries from a stream (XDEL)
+//! - Trimming a stream by length or minimum ID (XTRIM MAXLEN / MINID)
+//! - Support for ascending and descending ID order
+//! - Inspecting consumer groups, consumers and pending entries (XINFO / XPENDING)
+//! - Managing pending entries (XACK, XCLAIM, XAUTOCLAIM) and groups (XGROUP)
+
+use super::{
+    KeyType, RedisValueData, ServerTask, ZedisServerState,
+    value::{
+        RedisStreamConsumer, RedisStreamEntry, RedisStreamGroup, RedisStreamGroups, RedisStreamPendingEntry,
+        RedisStreamValue, RedisValue, RedisValueStatus, SortOrder,
+    },
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::{RedisKey, redis_value_to_string},
+    states::{ServerEvent, ZedisGlobalStore, i18n_stream_editor},
+};
+use gpui::{SharedString, prelude::*};
+use redis::{Cmd, Value, cmd};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Type alias for XRANGE result: vec of (entry id, flat field/value list as bytes)
+type StreamRangeValue = Vec<(String, Vec<Vec<u8>>)>;
+
+/// Type alias for XINFO GROUPS / XINFO CONSUMERS result: one field map per item
+type StreamInfoValue = Vec<HashMap<String, Value>>;
+
+/// Type alias for XPENDING extended result: (id, consumer, idle ms, delivery count)
+type StreamPendingValue = Vec<(String, String, u64, u64)>;
+
+/// Number of entries loaded per page
+const STREAM_PAGE_SIZE: usize = 100;
+
+/// Maximum number of pending entries listed for a consumer group
+const STREAM_PENDING_COUNT: usize = 100;
+
+/// Trim strategy used by XTRIM
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum StreamTrimStrategy {
+    /// Keep at most N entries
+    #[default]
+    MaxLen,
+    /// Evict entries with IDs lower than the threshold
+    MinId,
+}
+
+impl StreamTrimStrategy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StreamTrimStrategy::MaxLen => "MAXLEN",
+            StreamTrimStrategy::MinId => "MINID",
+        }
+    }
+}
+
+/// Splits a stream entry ID (`<ms>-<seq>`) into its numeric parts.
+///
+/// An ID without a sequence part is treated as `<ms>-0`.
+fn parse_stream_id(id: &str) -> Option<(u64, u64)> {
+    match id.split_once('-') {
+        Some((ms, seq)) => Some((ms.parse().ok()?, seq.parse().ok()?)),
+        None => Some((id.parse().ok()?, 0)),
+    }
+}
+
+/// Returns the smallest ID strictly greater than `id`.
+///
+/// Used instead of the exclusive `(` range syntax, which requires Redis 6.2+.
+fn next_stream_id(id: &str) -> Option<String> {
+    let (ms, seq) = parse_stream_id(id)?;
+    if seq == u64::MAX {
+        return Some(format!("{}-0", ms.checked_add(1)?));
+    }
+    Some(format!("{ms}-{}", seq + 1))
+}
+
+/// Returns the largest ID strictly lower than `id`.
+fn prev_stream_id(id: &str) -> Option<String> {
+    let (ms, seq) = parse_stream_id(id)?;
+    if seq == 0 {
+        return Some(format!("{}-{}", ms.checked_sub(1)?, u64::MAX));
+    }
+    Some(format!("{ms}-{}", seq - 1))
+}
+
+/// Parses the filter keyword into an ID range.
+///
+/// The keyword has the form `start [end]`, missing bounds default to `-` and `+`.
+fn parse_stream_range(keyword: Option<&SharedString>) -> (String, String) {
+    let mut parts = keyword.map(|kw| kw.split_whitespace()).into_iter().flatten();
+    let start = parts.next().unwrap_or("-").to_string();
+    let end = parts.next().unwrap_or("+").to_string();
+    (start, end)
+}
+
+/// Retrieves stream entries within an ID range using XRANGE or XREVRANGE.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The STREAM key to query
+/// * `start` - Lowest entry ID of the range (inclusive, `-` for the minimum)
+/// * `end` - Highest entry ID of the range (inclusive, `+` for the maximum)
+/// * `sort_order` - Ascending (XRANGE) or Descending (XREVRANGE)
+/// * `count` - Maximum number of entries to return
+///
+/// # Returns
+/// A vector of stream entries in the specified order
+async fn get_redis_stream_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    start: &str,
+    end: &str,
+    sort_order: SortOrder,
+    count: usize,
+) -> Result<Vec<RedisStreamEntry>> {
+    // XREVRANGE takes the range bounds in reverse order
+    let (cmd_name, first, second) = if sort_order == SortOrder::Asc {
+        ("XRANGE", start, end)
+    } else {
+        ("XREVRANGE", end, start)
+    };
+    let raw_values: StreamRangeValue = cmd(cmd_name)
+        .arg(key)
+        .arg(first)
+        .arg(second)
+        .arg("COUNT")
+        .arg(count)
+        .query_async(conn)
+        .await?;
+
+    // Convert the flat field/value list into pairs (lossy conversion for non-UTF8 data)
+    let values = raw_values
+        .into_iter()
+        .map(|(id, fields)| RedisStreamEntry {
+            id: id.into(),
+            fields: fields
+                .chunks(2)
+                .map(|chunk| {
+                    let field = String::from_utf8_lossy(&chunk[0]).to_string();
+                    let value = chunk
+                        .get(1)
+                        .map(|v| String::from_utf8_lossy(v).to_string())
+                        .unwrap_or_default();
+                    (field.into(), value.into())
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(values)
+}
+
+/// Reads a field of an XINFO reply as string.
+fn info_string(info: &HashMap<String, Value>, name: &str) -> SharedString {
+    info.get(name).map(redis_value_to_string).unwrap_or_default().into()
+}
+
+/// Reads a field of an XINFO reply as number, `None` if missing or nil.
+fn info_u64(info: &HashMap<String, Value>, name: &str) -> Option<u64> {
+    info.get(name).and_then(|v| redis_value_to_string(v).parse().ok())
+}
+
+/// Retrieves the consumer groups of a stream, along with the consumers and
+/// pending entries of the selected group.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The STREAM key to inspect
+/// * `selected` - Group to load details for; falls back to the first group if missing
+async fn get_redis_stream_groups(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    selected: Option<SharedString>,
+) -> Result<RedisStreamGroups> {
+    let raw_groups: StreamInfoValue = cmd("XINFO").arg("GROUPS").arg(key).query_async(conn).await?;
+    let groups: Vec<RedisStreamGroup> = raw_groups
+        .iter()
+        .map(|info| RedisStreamGroup {
+            name: info_string(info, "name"),
+            consumers: info_u64(info, "consumers").unwrap_or_default(),
+            pending: info_u64(info, "pending").unwrap_or_default(),
+            last_delivered_id: info_string(info, "last-delivered-id"),
+            lag: info_u64(info, "lag"),
+        })
+        .collect();
+
+    // Keep the current selection if the group still exists
+    let selected = selected
+        .filter(|name| groups.iter().any(|group| &group.name == name))
+        .or_else(|| groups.first().map(|group| group.name.clone()));
+    let Some(group) = selected.clone() else {
+        return Ok(RedisStreamGroups {
+            groups,
+            ..Default::default()
+        });
+    };
+
+    let raw_consumers: StreamInfoValue = cmd("XINFO")
+        .arg("CONSUMERS")
+        .arg(key)
+        .arg(group.as_str())
+        .query_async(conn)
+        .await?;
+    let consumers = raw_consumers
+        .iter()
+        .map(|info| RedisStreamConsumer {
+            name: info_string(info, "name"),
+            pending: info_u64(info, "pending").unwrap_or_default(),
+            idle: info_u64(info, "idle").unwrap_or_default(),
+        })
+        .collect();
+
+    let raw_pending: StreamPendingValue = cmd("XPENDING")
+        .arg(key)
+        .arg(group.as_str())
+        .arg("-")
+        .arg("+")
+        .arg(STREAM_PENDING_COUNT)
+        .query_async(conn)
+        .await?;
+    let pending = raw_pending
+        .into_iter()
+        .map(|(id, consumer, idle, delivery_count)| RedisStreamPendingEntry {
+            id: id.into(),
+            consumer: consumer.into(),
+            idle,
+            delivery_count,
+        })
+        .collect();
+
+    Ok(RedisStreamGroups {
+        groups,
+        selected,
+        consumers,
+        pending,
+    })
+}
+
+/// Performs initial load of a Redis STREAM value.
+///
+/// Fetches the stream length (XLEN) and loads the first page of entries
+/// in ascending ID order.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The STREAM key to load
+///
+/// # Returns
+/// A `RedisValue` containing STREAM metadata and the initial entries
+pub(crate) async fn first_load_stream_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisValue> {
+    let size: usize = cmd("XLEN").arg(key).query_async(conn).await?;
+
+    let values = get_redis_stream_value(conn, key, "-", "+", SortOrder::Asc, STREAM_PAGE_SIZE).await?;
+    let done = values.len() < STREAM_PAGE_SIZE;
+
+    Ok(RedisValue {
+        key_type: KeyType::Stream,
+        data: Some(RedisValueData::Stream(Arc::new(RedisStreamValue {
+            size,
+            values,
+            done,
+            ..Default::default()
+        }))),
+        ..Default::default()
+    })
+}
+
+impl ZedisServerState {
+    /// Loads the next page of stream entries.
+    ///
+    /// Continues from the last loaded entry ID, honoring the current sort order
+    /// and ID range filter.
+    ///
+    /// # Arguments
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn load_more_stream_value(&mut self, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(stream) = value.stream_value() else {
+            return;
+        };
+        if stream.done {
+            return;
+        }
+
+        let (mut start, mut end) = parse_stream_range(stream.keyword.as_ref());
+        let sort_order = stream.sort_order;
+
+        // Continue right after the last loaded entry
+        if let Some(last) = stream.values.last() {
+            let bound = if sort_order == SortOrder::Asc {
+                next_stream_id(&last.id).map(|id| start = id)
+            } else {
+                prev_stream_id(&last.id).map(|id| end = id)
+            };
+            // The last entry is already at the boundary of the ID space
+            if bound.is_none() {
+                return;
+            }
+        }
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        cx.emit(ServerEvent::ValuePaginationStarted);
+
+        self.spawn(
+            ServerTask::LoadMoreValue,
+            // Async operation: fetch next page using XRANGE / XREVRANGE
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_redis_stream_value(&mut conn, &key, &start, &end, sort_order, STREAM_PAGE_SIZE).await
+            },
+            // UI callback: append results into local state
+            move |this, result, cx| {
+                if let Ok(new_values) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let stream = Arc::make_mut(stream_data);
+                    stream.done = new_values.len() < STREAM_PAGE_SIZE;
+                    stream.values.extend(new_values);
+                }
+
+                cx.emit(ServerEvent::ValuePaginationFinished);
+
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Resets loaded entries and reloads from the beginning of the current range.
+    fn reload_stream_value(&mut self, keyword: Option<SharedString>, sort_order: SortOrder, cx: &mut Context<Self>) {
+        let Some((_, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(stream) = value.stream_value() else {
+            return;
+        };
+
+        let new_stream = RedisStreamValue {
+            keyword,
+            sort_order,
+            size: stream.size,
+            groups: stream.groups.clone(),
+            ..Default::default()
+        };
+        value.data = Some(RedisValueData::Stream(Arc::new(new_stream)));
+
+        self.load_more_stream_value(cx);
+    }
+    /// Applies an ID range filter to the stream entries.
+    ///
+    /// # Arguments
+    /// * `keyword` - ID range in the form `start [end]` (empty to clear the filter)
+    /// * `cx` - GPUI context for UI updates
+    pub fn filter_stream_value(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        let Some(stream) = self.value.as_ref().and_then(|v| v.stream_value()) else {
+            return;
+        };
+        let sort_order = stream.sort_order;
+        let keyword = if keyword.trim().is_empty() { None } else { Some(keyword) };
+        self.reload_stream_value(keyword, sort_order, cx);
+    }
+    /// Switches the order in which stream entries are listed and reloads them.
+    ///
+    /// # Arguments
+    /// * `sort_order` - Ascending (oldest first) or Descending (newest first)
+    /// * `cx` - GPUI context for UI updates
+    pub fn set_stream_sort_order(&mut self, sort_order: SortOrder, cx: &mut Context<Self>) {
+        let Some(stream) = self.value.as_ref().and_then(|v| v.stream_value()) else {
+            return;
+        };
+        if stream.sort_order == sort_order {
+            return;
+        }
+        let keyword = stream.keyword.clone();
+        self.reload_stream_value(keyword, sort_order, cx);
+    }
+    /// Appends a new entry to the Redis STREAM.
+    ///
+    /// Uses XADD; the ID may be `*` to let Redis generate it.
+    ///
+    /// # Arguments
+    /// * `id` - Entry ID or `*`
+    /// * `fields` - Field-value pairs of the new entry
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn add_stream_value(
+        &mut self,
+        id: SharedString,
+        fields: Vec<(SharedString, SharedString)>,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let fields_clone = fields.clone();
+
+        self.spawn(
+            ServerTask::AddStreamValue,
+            // Async operation: execute XADD on Redis
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut command = cmd("XADD");
+                command.arg(&key).arg(id.as_str());
+                for (field, value) in fields.iter() {
+                    command.arg(field.as_str()).arg(value.as_str());
+                }
+                // XADD returns the ID of the added entry
+                let new_id: String = command.query_async(&mut conn).await?;
+                Ok(new_id)
+            },
+            // UI callback: insert the new entry into local state
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                let title = i18n_stream_editor(cx, "add_value_success");
+                let msg = i18n_stream_editor(cx, "add_value_success_tips");
+
+                if let Ok(new_id) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let stream = Arc::make_mut(stream_data);
+                    stream.size += 1;
+                    let entry = RedisStreamEntry {
+                        id: new_id.into(),
+                        fields: fields_clone,
+                    };
+
+                    // New entries always have the greatest ID; only show them if
+                    // they belong to the visible window and no range filter is active
+                    if stream.keyword.is_none() {
+                        if stream.sort_order == SortOrder::Desc {
+                            stream.values.insert(0, entry);
+                        } else if stream.done {
+                            stream.values.push(entry);
+                        }
+                    }
+
+                    cx.emit(ServerEvent::ValueAdded);
+                    this.emit_success_notification(msg, title, cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Removes an entry from the Redis STREAM.
+    ///
+    /// # Arguments
+    /// * `id` - The entry ID to remove (XDEL)
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn remove_stream_value(&mut self, id: SharedString, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let id_clone = id.clone();
+
+        self.spawn(
+            ServerTask::RemoveStreamValue,
+            // Async operation: execute XDEL on Redis
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let count: usize = cmd("XDEL").arg(&key).arg(id.as_str()).query_async(&mut conn).await?;
+                Ok(count)
+            },
+            // UI callback: update local state to reflect removal
+            move |this, result, cx| {
+                if let Ok(count) = result {
+                    if count != 0
+                        && let Some(RedisValueData::Stream(stream_data)) =
+                            this.value.as_mut().and_then(|v| v.data.as_mut())
+                    {
+                        let stream = Arc::make_mut(stream_data);
+                        stream.values.retain(|entry| entry.id != id_clone);
+                        stream.size = stream.size.saturating_sub(count);
+                    }
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Trims the Redis STREAM with XTRIM and reloads the entries.
+    ///
+    /// # Arguments
+    /// * `strategy` - MAXLEN (keep the newest N entries) or MINID (evict IDs below threshold)
+    /// * `threshold` - Entry count for MAXLEN or entry ID for MINID
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn trim_stream_value(&mut self, strategy: StreamTrimStrategy, threshold: SharedString, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::TrimStreamValue,
+            // Async operation: execute XTRIM then read the new length
+            move || async move {
+                if strategy == StreamTrimStrategy::MaxLen && threshold.parse::<u64>().is_err() {
+                    return Err(Error::Invalid {
+                        message: format!("Invalid MAXLEN threshold: {threshold}"),
+                    });
+                }
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let removed: usize = cmd("XTRIM")
+                    .arg(&key)
+                    .arg(strategy.as_str())
+                    .arg(threshold.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                let size: usize = cmd("XLEN").arg(&key).query_async(&mut conn).await?;
+                Ok((removed, size))
+            },
+            // UI callback: reset local entries and reload
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if let Ok((removed, size)) = result {
+                    let (keyword, sort_order) = match this.value.as_ref().and_then(|v| v.stream_value()) {
+                        Some(stream) => (stream.keyword.clone(), stream.sort_order),
+                        None => return,
+                    };
+                    if let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                    {
+                        Arc::make_mut(stream_data).size = size;
+                    }
+                    let title = i18n_stream_editor(cx, "trim_success");
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let msg = t!("stream_editor.trim_success_tips", count = removed, locale = locale).to_string();
+                    this.emit_success_notification(msg.into(), title, cx);
+                    this.reload_stream_value(keyword, sort_order, cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Loads the consumer groups of the stream (XINFO GROUPS), with the consumers
+    /// (XINFO CONSUMERS) and pending entries (XPENDING) of the selected group.
+    ///
+    /// # Arguments
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn load_stream_groups(&mut self, cx: &mut Context<Self>) {
+        let selected = self
+            .value
+            .as_ref()
+            .and_then(|v| v.stream_value())
+            .and_then(|stream| stream.groups.as_ref())
+            .and_then(|groups| groups.selected.clone());
+        self.load_stream_group_details(selected, cx);
+    }
+    /// Selects a consumer group and loads its consumers and pending entries.
+    ///
+    /// # Arguments
+    /// * `group` - Name of the consumer group
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn select_stream_group(&mut self, group: SharedString, cx: &mut Context<Self>) {
+        self.load_stream_group_details(Some(group), cx);
+    }
+    fn load_stream_group_details(&mut self, selected: Option<SharedString>, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        if value.stream_value().is_none() {
+            return;
+        }
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::LoadStreamGroups,
+            // Async operation: execute XINFO / XPENDING on Redis
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_redis_stream_groups(&mut conn, &key, selected).await
+            },
+            // UI callback: replace the consumer groups in local state
+            move |this, result, cx| {
+                if let Ok(groups) = result
+                    && let Some(RedisValueData::Stream(stream_data)) = this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    Arc::make_mut(stream_data).groups = Some(Arc::new(groups));
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Executes a consumer group write command, then reloads the consumer groups.
+    ///
+    /// # Arguments
+    /// * `task` - Task type used for logging and error reporting
+    /// * `build_cmd` - Builds the command from the stream key
+    /// * `success_tips` - i18n key of the success notification
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    fn run_stream_group_cmd(
+        &mut self,
+        task: ServerTask,
+        build_cmd: impl FnOnce(&RedisKey) -> Cmd + Send + 'static,
+        success_tips: &'static str,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            task,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let _: Value = build_cmd(&key).query_async(&mut conn).await?;
+                Ok(())
+            },
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if result.is_ok() {
+                    let title = i18n_stream_editor(cx, "group_update_success");
+                    let msg = i18n_stream_editor(cx, success_tips);
+                    this.emit_success_notification(msg, title, cx);
+                    this.load_stream_groups(cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Creates a consumer group (XGROUP CREATE).
+    ///
+    /// # Arguments
+    /// * `group` - Name of the new consumer group
+    /// * `id` - Last delivered ID to start from (`$` for new entries only, `0` for all)
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn create_stream_group(&mut self, group: SharedString, id: SharedString, cx: &mut Context<Self>) {
+        self.run_stream_group_cmd(
+            ServerTask::UpdateStreamGroup,
+            move |key| {
+                let mut command = cmd("XGROUP");
+                command.arg("CREATE").arg(key).arg(group.as_str()).arg(id.as_str());
+                command
+            },
+            "create_group_success_tips",
+            cx,
+        );
+    }
+    /// Sets the last delivered ID of a consumer group (XGROUP SETID).
+    ///
+    /// # Arguments
+    /// * `group` - Name of the consumer group
+    /// * `id` - New last delivered ID
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn set_stream_group_id(&mut self, group: SharedString, id: SharedString, cx: &mut Context<Self>) {
+        self.run_stream_group_cmd(
+            ServerTask::UpdateStreamGroup,
+            move |key| {
+                let mut command = cmd("XGROUP");
+                command.arg("SETID").arg(key).arg(group.as_str()).arg(id.as_str());
+                command
+            },
+            "set_group_id_success_tips",
+            cx,
+        );
+    }
+    /// Destroys a consumer group and all of its pending entries (XGROUP DESTROY).
+    ///
+    /// # Arguments
+    /// * `group` - Name of the consumer group
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn destroy_stream_group(&mut self, group: SharedString, cx: &mut Context<Self>) {
+        self.run_stream_group_cmd(
+            ServerTask::UpdateStreamGroup,
+            move |key| {
+                let mut command = cmd("XGROUP");
+                command.arg("DESTROY").arg(key).arg(group.as_str());
+                command
+            },
+            "destroy_group_success_tips",
+            cx,
+        );
+    }
+    /// Acknowledges pending entries of a consumer group (XACK).
+    ///
+    /// # Arguments
+    /// * `group` - Name of the consumer group
+    /// * `ids` - Pending entry IDs to acknowledge
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn ack_stream_pending(&mut self, group: SharedString, ids: Vec<SharedString>, cx: &mut Context<Self>) {
+        if ids.is_empty() {
+            return;
+        }
+        self.run_stream_group_cmd(
+            ServerTask::UpdateStreamPending,
+            move |key| {
+                let mut command = cmd("XACK");
+                command.arg(key).arg(group.as_str());
+                for id in ids.iter() {
+                    command.arg(id.as_str());
+                }
+                command
+            },
+            "ack_success_tips",
+            cx,
+        );
+    }
+    /// Transfers pending entries to another consumer (XCLAIM).
+    ///
+    /// # Arguments
+    /// * `group` - Name of the consumer group
+    /// * `consumer` - Consumer that takes ownership of the entries
+    /// * `min_idle` - Only claim entries idle for at least this many milliseconds
+    /// * `ids` - Pending entry IDs to claim
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn claim_stream_pending(
+        &mut self,
+        group: SharedString,
+        consumer: SharedString,
+        min_idle: u64,
+        ids: Vec<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        if ids.is_empty() {
+            return;
+        }
+        self.run_stream_group_cmd(
+            ServerTask::UpdateStreamPending,
+            move |key| {
+                let mut command = cmd("XCLAIM");
+                command
+                    .arg(key)
+                    .arg(group.as_str())
+                    .arg(consumer.as_str())
+                    .arg(min_idle);
+                for id in ids.iter() {
+                    command.arg(id.as_str());
+                }
+                // Only transfer ownership, the entries themselves are not needed
+                command.arg("JUSTID");
+                command
+            },
+            "claim_success_tips",
+            cx,
+        );
+    }
+    /// Transfers all pending entries idle for at least `min_idle` milliseconds
+    /// to another consumer (XAUTOCLAIM, Redis 6.2+).
+    ///
+    /// # Arguments
+    /// * `group` - Name of the consumer group
+    /// * `consumer` - Consumer that takes ownership of the entries
+    /// * `min_idle` - Minimum idle time in milliseconds
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn auto_claim_stream_pending(
+        &mut self,
+        group: SharedString,
+        consumer: SharedString,
+        min_idle: u64,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::UpdateStreamPending,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                if !client.is_at_least_version("6.2.0") {
+                    return Err(Error::Invalid {
+                        message: "XAUTOCLAIM requires Redis 6.2 or later".to_string(),
+                    });
+                }
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut start = "0-0".to_string();
+                let mut claimed = 0;
+                // Iterate until the cursor returned by XAUTOCLAIM wraps around to 0-0
+                loop {
+                    // Reply is [next cursor, claimed ids] (plus deleted ids since Redis 7.0)
+                    let reply: Vec<Value> = cmd("XAUTOCLAIM")
+                        .arg(&key)
+                        .arg(group.as_str())
+                        .arg(consumer.as_str())
+                        .arg(min_idle)
+                        .arg(start.as_str())
+                        .arg("COUNT")
+                        .arg(STREAM_PENDING_COUNT)
+                        .arg("JUSTID")
+                        .query_async(&mut conn)
+                        .await?;
+                    let next = reply.first().map(redis_value_to_string).unwrap_or_default();
+                    if let Some(Value::Array(ids)) = reply.get(1) {
+                        claimed += ids.len();
+                    }
+                    if next.is_empty() || next == "0-0" {
+                        break;
+                    }
+                    start = next;
+                }
+                Ok(claimed)
+            },
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if let Ok(claimed) = result {
+                    let title = i18n_stream_editor(cx, "group_update_success");
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let msg = t!(
+                        "stream_editor.auto_claim_success_tips",
+                        count = claimed,
+                        locale = locale
+                    )
+                    .to_string();
+                    this.emit_success_notification(msg.into(), title, cx);
+                    this.load_stream_groups(cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
@@ -12,78 +12,210 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::value::{DataFormat, RedisBytesValue, detect_format};
-use crate::db::ProtoManager;
-use crate::helpers::decompress_zstd;
+use super::decoder::{DecodeContext, decode_document, decode_value, get_decoder};
+use super::hyperloglog::get_hyperloglog;
+use super::value::{BytesPage, DataFormat, RedisBytesValue, RedisValueData, RedisValueStatus, ViewMode, detect_format};
+use super::{ServerEvent, ServerTask, ZedisServerState};
+use crate::connection::get_connection_manager;
+use crate::db::DecoderRuleManager;
+use crate::helpers::{RedisKey, compress_zstd, decompress_zstd};
+use crate::states::ZedisGlobalStore;
 use crate::{connection::RedisAsyncConn, error::Error};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
 use bytes::Bytes;
-use flate2::read::GzDecoder;
-use gpui::SharedString;
-use lz4_flex::block::decompress_size_prepended;
-use redis::cmd;
-use serde_json::Value;
-use snap::read::FrameDecoder;
-use std::io::Read;
+use flate2::{Compression, read::GzDecoder, write::GzEncoder};
+use gpui::prelude::*;
+use lz4_flex::block::{compress_prepend_size, decompress_size_prepended};
+use redis::{cmd, pipe};
+use snap::{read::FrameDecoder, write::FrameEncoder};
+use std::io::{Read, Write};
+use std::ops::Range;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 type Result<T, E = Error> = std::result::Result<T, E>;
 
-fn truncate_long_strings(max_truncate_length: usize, v: &mut Value, truncated: &mut bool) {
-    match v {
-        Value::String(s) => {
-            if s.len() > max_truncate_length {
-                let char_count = s.chars().count();
-                if char_count > max_truncate_length {
-                    let mut new_s: String = s.chars().take(max_truncate_length).collect();
-                    new_s.push_str(&format!("...(Total {} chars, content hidden)", char_count));
-                    *s = new_s;
-                    *truncated = true;
+/// Shorter values are not treated as hex or base64, as short words are often valid encodings
+const MIN_ENCODED_LENGTH: usize = 16;
+/// Size of the prefix loaded for large values, also the page size of their hex view
+pub const STRING_PAGE_SIZE: usize = 256 * 1024;
+/// Size of each GETRANGE when a large value is loaded completely
+const STRING_CHUNK_SIZE: usize = 1024 * 1024;
+/// Changed ranges closer than this are written by one SETRANGE
+const MIN_RANGE_GAP: usize = 16;
+/// LZ4 expands each compressed byte to at most 255 bytes
+const LZ4_MAX_RATIO: usize = 255;
+/// Maximum decompressed size of an LZ4 block, the size prefix is untrusted and allocated up front
+const LZ4_MAX_SIZE: usize = 64 * 1024 * 1024;
+
+/// Progress of loading a large string value by chunks
+#[derive(Debug, Clone, Default)]
+pub struct StringChunkLoad {
+    loaded: usize,
+    total: usize,
+    cancelled: Arc<AtomicBool>,
+}
+
+/// Bytes edited in the hex view, starting at `offset` of the value
+#[derive(Debug, Clone, Default)]
+pub struct BytesEdit {
+    pub offset: usize,
+    pub original: Bytes,
+    pub data: Vec<u8>,
+}
+
+/// Result of loading one chunk of a large string value
+enum StringChunk {
+    /// The bytes loaded so far
+    Partial(Vec<u8>),
+    /// The whole value, decoded
+    Done(Box<RedisBytesValue>),
+}
+
+/// Compression codecs of string values, decompressed for display and re-applied on save
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum CompressionFormat {
+    Gzip,
+    Zstd,
+    /// Snappy framing format
+    Snappy,
+    /// LZ4 block with the uncompressed size prepended (little-endian u32)
+    Lz4,
+}
+
+impl CompressionFormat {
+    /// All supported codecs, in the order shown in the editor
+    pub const ALL: [CompressionFormat; 4] = [
+        CompressionFormat::Gzip,
+        CompressionFormat::Zstd,
+        CompressionFormat::Snappy,
+        CompressionFormat::Lz4,
+    ];
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CompressionFormat::Gzip => "gzip",
+            CompressionFormat::Zstd => "zstd",
+            CompressionFormat::Snappy => "snappy",
+            CompressionFormat::Lz4 => "lz4",
+        }
+    }
+    /// Returns the codec by name, `None` for uncompressed
+    pub fn from_str(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|format| format.as_str() == s)
+    }
+    /// Decompresses the data with this codec.
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            CompressionFormat::Gzip => {
+                let mut decoder = GzDecoder::new(data);
+                let mut vec = Vec::with_capacity(data.len() * 2);
+                decoder.read_to_end(&mut vec)?;
+                Ok(vec)
+            }
+            CompressionFormat::Zstd => decompress_zstd(data),
+            CompressionFormat::Snappy => {
+                let mut decoder = FrameDecoder::new(data);
+                let mut vec = Vec::with_capacity(data.len() * 2);
+                decoder.read_to_end(&mut vec)?;
+                Ok(vec)
+            }
+            CompressionFormat::Lz4 => {
+                let Some((prefix, block)) = data.split_first_chunk::<4>() else {
+                    return Err(Error::Invalid {
+                        message: "lz4 block is too short".to_string(),
+                    });
+                };
+                let size = u32::from_le_bytes(*prefix) as usize;
+                if size > block.len().saturating_mul(LZ4_MAX_RATIO).min(LZ4_MAX_SIZE) {
+                    return Err(Error::Invalid {
+                        message: format!("lz4 uncompressed size {size} is invalid"),
+                    });
                 }
+                decompress_size_prepended(data).map_err(|e| Error::Invalid { message: e.to_string() })
             }
         }
-        Value::Array(arr) => {
-            for item in arr {
-                truncate_long_strings(max_truncate_length, item, truncated);
+    }
+    /// Compresses the data with this codec, using the same framing as `decompress`.
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            CompressionFormat::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::with_capacity(data.len() / 2), Compression::default());
+                encoder.write_all(data)?;
+                Ok(encoder.finish()?)
             }
-        }
-        Value::Object(map) => {
-            for val in map.values_mut() {
-                truncate_long_strings(max_truncate_length, val, truncated);
+            CompressionFormat::Zstd => Ok(compress_zstd(data)),
+            CompressionFormat::Snappy => {
+                let mut encoder = FrameEncoder::new(Vec::with_capacity(data.len() / 2));
+                encoder.write_all(data)?;
+                encoder
+                    .into_inner()
+                    .map_err(|e| Error::Invalid { message: e.to_string() })
             }
+            CompressionFormat::Lz4 => Ok(compress_prepend_size(data)),
         }
-        _ => {}
     }
 }
 
-/// Attempts to format a string as pretty-printed JSON.
-/// Returns None if the string is not valid JSON or doesn't look like JSON.
-fn pretty_json(value: &str, max_truncate_length: usize) -> Option<(SharedString, bool)> {
-    let trimmed = value.trim();
-    if !((trimmed.starts_with('{') && trimmed.ends_with('}')) || (trimmed.starts_with('[') && trimmed.ends_with(']'))) {
-        return None;
-    }
-    let mut json_value = serde_json::from_str::<Value>(value).ok()?;
-    let mut truncated = false;
-    truncate_long_strings(max_truncate_length, &mut json_value, &mut truncated);
-    let pretty_str = serde_json::to_string_pretty(&json_value).ok()?;
-
-    Some((pretty_str.into(), truncated))
+/// Text encodings wrapping binary values, decoded before decompression and re-applied on save
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TextEncoding {
+    Hex,
+    Base64,
 }
 
-fn format_text(data: &[u8], max_truncate_length: usize) -> Option<(DataFormat, SharedString)> {
-    match std::str::from_utf8(data) {
-        Ok(s) => {
-            if let Some((pretty, truncated)) = pretty_json(s, max_truncate_length) {
-                let format = if truncated {
-                    DataFormat::Preview
-                } else {
-                    DataFormat::Json
-                };
-                Some((format, pretty))
-            } else {
-                Some((DataFormat::Text, s.to_string().into()))
+impl TextEncoding {
+    /// All supported encodings, hex first as hex digits are also valid base64
+    pub const ALL: [TextEncoding; 2] = [TextEncoding::Hex, TextEncoding::Base64];
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TextEncoding::Hex => "hex",
+            TextEncoding::Base64 => "base64",
+        }
+    }
+    /// Returns true if the data only contains characters of this encoding.
+    pub fn detect(&self, data: &[u8]) -> bool {
+        if data.len() < MIN_ENCODED_LENGTH {
+            return false;
+        }
+        match self {
+            TextEncoding::Hex => data.len().is_multiple_of(2) && data.iter().all(u8::is_ascii_hexdigit),
+            TextEncoding::Base64 => {
+                let padding = data.iter().rev().take_while(|b| **b == b'=').count();
+                data.len().is_multiple_of(4)
+                    && padding <= 2
+                    && data[..data.len() - padding]
+                        .iter()
+                        .all(|b| b.is_ascii_alphanumeric() || *b == b'+' || *b == b'/')
             }
         }
-        Err(_) => None,
+    }
+    /// Decodes the encoded text to bytes.
+    pub fn decode(&self, data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            TextEncoding::Hex => {
+                let text = std::str::from_utf8(data).map_err(|e| Error::Invalid { message: e.to_string() })?;
+                (0..text.len())
+                    .step_by(2)
+                    .map(|i| {
+                        text.get(i..i + 2)
+                            .and_then(|pair| u8::from_str_radix(pair, 16).ok())
+                            .ok_or_else(|| Error::Invalid {
+                                message: "invalid hex string".to_string(),
+                            })
+                    })
+                    .collect()
+            }
+            TextEncoding::Base64 => BASE64
+                .decode(data)
+                .map_err(|e| Error::Invalid { message: e.to_string() }),
+        }
+    }
+    /// Encodes the bytes as text.
+    pub fn encode(&self, data: &[u8]) -> Vec<u8> {
+        match self {
+            TextEncoding::Hex => data.iter().map(|b| format!("{b:02x}")).collect::<String>().into_bytes(),
+            TextEncoding::Base64 => BASE64.encode(data).into_bytes(),
+        }
     }
 }
 
@@ -97,64 +229,448 @@ impl RedisBytesValue {
         let (initial_format, mime) = detect_format(data);
         self.mime = mime;
 
-        let process_decompressed = |decompressed: Option<Vec<u8>>| {
-            decompressed
-                .and_then(|vec| format_text(&vec, max_truncate_length).map(|(_, text)| (DataFormat::Preview, text)))
+        // A decoder rule of the key forces the decoder and the view mode
+        let rule = DecoderRuleManager::match_key(server_id, key);
+        if let Some(rule) = &rule {
+            self.view_mode = ViewMode::from_str(&rule.view_mode);
+        }
+        let forced = rule.and_then(|rule| rule.decoder);
+        let ctx = DecodeContext {
+            server_id,
+            key,
+            format: initial_format,
+            max_truncate_length,
+            forced: forced.as_deref(),
         };
 
-        let result = match initial_format {
-            DataFormat::MessagePack => rmp_serde::from_slice::<serde_json::Value>(data)
-                .ok()
-                .and_then(|v| serde_json::to_string_pretty(&v).ok())
-                .map(|s| (DataFormat::Preview, SharedString::from(s))),
-
-            DataFormat::Gzip => process_decompressed({
-                let mut decoder = GzDecoder::new(data);
-                let mut vec = Vec::with_capacity(data.len() * 2);
-                decoder.read_to_end(&mut vec).ok().map(|_| vec)
-            }),
-
-            DataFormat::Zstd => process_decompressed(decompress_zstd(data).ok()),
-
-            DataFormat::Snappy => process_decompressed({
-                let mut decoder = FrameDecoder::new(data);
-                let mut vec = Vec::with_capacity(data.len() * 2);
-                decoder.read_to_end(&mut vec).ok().map(|_| vec)
-            }),
-
-            DataFormat::Svg | DataFormat::Jpeg | DataFormat::Png | DataFormat::Webp | DataFormat::Gif => None,
-
-            _ => {
-                if let Some(id) = ProtoManager::match_key_to_name(server_id, key)
-                    && let Ok(data) = ProtoManager::decode_data(&id, data)
-                {
-                    Some((DataFormat::Protobuf, SharedString::from(data)))
-                } else if let Ok(decompressed) = decompress_size_prepended(data) {
-                    process_decompressed(Some(decompressed))
-                } else {
-                    format_text(data, max_truncate_length)
-                }
+        if let Some(decoded) = decode_value(&ctx, data) {
+            self.format = decoded.format;
+            self.text = decoded.text;
+            self.compression = decoded.compression;
+            self.encoding = decoded.encoding;
+            self.decoder = Some(decoded.decoder).filter(|_| decoded.encodable);
+        } else {
+            self.format = initial_format;
+        }
+    }
+    /// Decodes the value to a document for the tree view with the decoder rule of the key.
+    pub fn decode_document(&self, server_id: &str, key: &str) -> Option<serde_json::Value> {
+        let forced = DecoderRuleManager::match_key(server_id, key).and_then(|rule| rule.decoder);
+        let ctx = DecodeContext {
+            server_id,
+            key,
+            format: detect_format(&self.bytes).0,
+            max_truncate_length: usize::MAX,
+            forced: forced.as_deref(),
+        };
+        decode_document(&ctx, &self.bytes)
+    }
+    /// Detects the format of a partial value from its prefix.
+    ///
+    /// Decoders need the whole value, so the prefix is only shown as text or hex.
+    pub fn detect_prefix(&mut self, server_id: &str, key: &str) {
+        if let Some(rule) = DecoderRuleManager::match_key(server_id, key) {
+            self.view_mode = ViewMode::from_str(&rule.view_mode);
+        }
+        // the prefix may end in the middle of a multi-byte char
+        let is_text = match std::str::from_utf8(&self.bytes) {
+            Ok(_) => true,
+            Err(e) => e.error_len().is_none(),
+        };
+        if is_text {
+            self.format = DataFormat::Text;
+            self.text = Some(String::from_utf8_lossy(&self.bytes).to_string().into());
+            return;
+        }
+        let (format, mime) = detect_format(&self.bytes);
+        self.format = format;
+        self.mime = mime;
+    }
+    /// Copies the same-length edit over the loaded prefix and page of a partial value.
+    fn patch(&mut self, edit: BytesEdit) {
+        let mut prefix = self.bytes.to_vec();
+        if edit.offset < prefix.len() {
+            let end = (edit.offset + edit.data.len()).min(prefix.len());
+            prefix[edit.offset..end].copy_from_slice(&edit.data[..end - edit.offset]);
+            if self.text.is_some() {
+                self.text = Some(String::from_utf8_lossy(&prefix).to_string().into());
             }
+            self.bytes = Bytes::from(prefix);
+        }
+        self.page = Some(BytesPage {
+            offset: edit.offset,
+            bytes: Bytes::from(edit.data),
+        });
+    }
+    /// Encodes the edited text back to the format of the value with its decoder.
+    ///
+    /// Returns `None` for text formats, which are written as is.
+    pub fn encode_text(&self, server_id: &str, key: &str, text: &str) -> Result<Option<Bytes>> {
+        let Some(decoder) = self.decoder.and_then(get_decoder) else {
+            return Ok(None);
         };
+        Ok(decoder.encode(server_id, key, text)?.map(Bytes::from))
+    }
+}
 
-        if let Some((new_format, text)) = result {
-            self.format = new_format;
-            self.text = Some(text);
-        } else {
-            self.format = initial_format;
+/// Returns the ranges of the bytes that differ, ranges closer than `MIN_RANGE_GAP` are merged.
+fn changed_ranges(original: &[u8], data: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (index, _) in original.iter().zip(data).enumerate().filter(|(_, (a, b))| a != b) {
+        match ranges.last_mut() {
+            Some(range) if index - range.end < MIN_RANGE_GAP => range.end = index + 1,
+            _ => ranges.push(index..index + 1),
         }
     }
+    ranges
 }
 
-pub(crate) async fn get_redis_bytes_value(conn: &mut RedisAsyncConn, key: &str) -> Result<RedisBytesValue> {
+async fn get_string_range(conn: &mut RedisAsyncConn, key: &RedisKey, start: usize, len: usize) -> Result<Vec<u8>> {
+    if len == 0 {
+        return Ok(Vec::new());
+    }
+    // the end offset of GETRANGE is inclusive
+    let value_bytes: Vec<u8> = cmd("GETRANGE")
+        .arg(key)
+        .arg(start)
+        .arg(start + len - 1)
+        .query_async(conn)
+        .await?;
+    Ok(value_bytes)
+}
+
+/// Gets a string value, only a prefix of `STRING_PAGE_SIZE` bytes is loaded
+/// when it is longer than `threshold`, so a huge value can not block the connection.
+pub(crate) async fn get_redis_bytes_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    threshold: usize,
+) -> Result<RedisBytesValue> {
+    let length: usize = cmd("STRLEN").arg(key).query_async(conn).await?;
+    if length > threshold {
+        let value_bytes = get_string_range(conn, key, 0, STRING_PAGE_SIZE.min(length)).await?;
+        return Ok(RedisBytesValue {
+            format: DataFormat::Text,
+            bytes: Bytes::from(value_bytes),
+            length,
+            ..Default::default()
+        });
+    }
     let value_bytes: Vec<u8> = cmd("GET").arg(key).query_async(conn).await?;
     Ok(RedisBytesValue {
         format: DataFormat::Text,
+        length: value_bytes.len(),
         bytes: Bytes::from(value_bytes),
         ..Default::default()
     })
 }
 
+impl ZedisServerState {
+    /// Returns the loaded and total bytes of the large string value being loaded
+    pub fn string_load_progress(&self) -> Option<(usize, usize)> {
+        self.string_load.as_ref().map(|load| (load.loaded, load.total))
+    }
+    /// Cancels loading the large string value, the loaded prefix is kept.
+    pub fn cancel_string_load(&mut self, cx: &mut Context<Self>) {
+        let Some(load) = self.string_load.take() else {
+            return;
+        };
+        load.cancelled.store(true, Ordering::Relaxed);
+        cx.emit(ServerEvent::ValueLoadProgressed);
+        cx.notify();
+    }
+    /// Loads the whole large string value by GETRANGE chunks, reporting the progress.
+    pub fn load_full_string_value(&mut self, cx: &mut Context<Self>) {
+        if self.string_load.is_some() {
+            return;
+        }
+        let Some(key) = self.key.clone() else {
+            return;
+        };
+        let Some(value) = self.value.as_ref().and_then(|value| value.bytes_value()) else {
+            return;
+        };
+        if !value.is_partial() {
+            return;
+        }
+        let load = StringChunkLoad {
+            loaded: 0,
+            total: value.length,
+            cancelled: Arc::default(),
+        };
+        let cancelled = load.cancelled.clone();
+        self.string_load = Some(load);
+        cx.emit(ServerEvent::ValueLoadProgressed);
+        cx.notify();
+        // the buffer grows with each chunk in the task, the length may be stale and is not allocated up front
+        self.load_string_chunk(key, Vec::new(), value.length, cancelled, cx);
+    }
+    /// Loads the next chunk into the buffer, then schedules the following one until the value is complete.
+    fn load_string_chunk(
+        &mut self,
+        key: RedisKey,
+        mut buffer: Vec<u8>,
+        total: usize,
+        cancelled: Arc<AtomicBool>,
+        cx: &mut Context<Self>,
+    ) {
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let current_key = key.clone();
+        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        self.spawn(
+            ServerTask::LoadStringValue,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let start = buffer.len();
+                let len = STRING_CHUNK_SIZE.min(total.saturating_sub(start));
+                let chunk = get_string_range(&mut conn, &key, start, len).await?;
+                // a short chunk means the value was shortened while loading
+                let done = chunk.len() < len || start + chunk.len() >= total;
+                buffer.extend_from_slice(&chunk);
+                if !done {
+                    return Ok(StringChunk::Partial(buffer));
+                }
+                let mut data = RedisBytesValue {
+                    length: buffer.len(),
+                    bytes: Bytes::from(buffer),
+                    ..Default::default()
+                };
+                data.detect_and_update(server_id.as_str(), key.display_str(), max_truncate_length);
+                Ok(StringChunk::Done(Box::new(data)))
+            },
+            move |this, result, cx| {
+                if cancelled.load(Ordering::Relaxed) || this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                match result {
+                    Ok(StringChunk::Partial(buffer)) => {
+                        if let Some(load) = this.string_load.as_mut() {
+                            load.loaded = buffer.len();
+                        }
+                        cx.emit(ServerEvent::ValueLoadProgressed);
+                        cx.notify();
+                        this.load_string_chunk(current_key, buffer, total, cancelled, cx);
+                    }
+                    Ok(StringChunk::Done(mut data)) => {
+                        this.string_load = None;
+                        if let Some(value) = this.value.as_mut() {
+                            // keep the view mode of the prefix, it may have been changed by the user
+                            if let Some(prefix) = value.bytes_value() {
+                                data.view_mode = prefix.view_mode.clone();
+                                data.bitmap = prefix.bitmap.clone();
+                            }
+                            value.data = Some(RedisValueData::Bytes(Arc::new(*data)));
+                        }
+                        cx.emit(ServerEvent::ValueLoaded);
+                        cx.notify();
+                    }
+                    Err(_) => {
+                        this.string_load = None;
+                        cx.emit(ServerEvent::ValueLoadProgressed);
+                        cx.notify();
+                    }
+                }
+            },
+            cx,
+        );
+    }
+    /// Decodes the document of the value for the tree and table views, partial values can not be decoded.
+    pub fn load_document(&mut self, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(bytes_value) = value.bytes_value().filter(|v| !v.is_partial()) else {
+            return;
+        };
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::DecodeDocument,
+            move || async move {
+                bytes_value
+                    .decode_document(server_id.as_str(), key.display_str())
+                    .ok_or_else(|| Error::Invalid {
+                        message: "The value is not a structured document (JSON object or array)".to_string(),
+                    })
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(document) = result
+                        && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                    {
+                        Arc::make_mut(bytes_value).document = Some(Arc::new(document));
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Loads the page of a partial string value starting at `offset` for the hex view.
+    pub fn load_string_page(&mut self, offset: usize, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(bytes_value) = value.bytes_value() else {
+            return;
+        };
+        if !bytes_value.is_partial() || offset >= bytes_value.length {
+            return;
+        }
+        let len = STRING_PAGE_SIZE.min(bytes_value.length - offset);
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let current_key = key.clone();
+        self.spawn(
+            ServerTask::LoadStringPage,
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_string_range(&mut conn, &key, offset, len).await
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(bytes) = result
+                        && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                    {
+                        Arc::make_mut(bytes_value).page = Some(BytesPage {
+                            offset,
+                            bytes: Bytes::from(bytes),
+                        });
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Writes the bytes edited in the hex view.
+    ///
+    /// Same-length edits only write the changed ranges with SETRANGE,
+    /// edits inserting or deleting bytes replace the whole value with SET.
+    pub fn save_bytes_edit(&mut self, key: RedisKey, edit: BytesEdit, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let max_truncate_length = cx.global::<ZedisGlobalStore>().read(cx).max_truncate_length();
+        let Some(value) = self.value.as_mut() else {
+            return;
+        };
+        let Some(bytes_value) = value.bytes_value() else {
+            return;
+        };
+        let same_length = edit.data.len() == edit.original.len();
+        // SET would truncate a partial value, its pages can only be overwritten
+        if !same_length && bytes_value.is_partial() {
+            return;
+        }
+        let view_mode = bytes_value.view_mode.clone();
+        let ttl = value.ttl().map(|ttl| ttl.num_milliseconds()).unwrap_or_default();
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let current_key = key.clone();
+        let page = edit.clone();
+        let partial = bytes_value.is_partial();
+        self.spawn(
+            ServerTask::SaveValue,
+            move || async move {
+                let client = get_connection_manager().get_client(&server_id, db).await?;
+                let mut conn = client.connection();
+                if same_length {
+                    // MULTI/EXEC, so the ranges of the page are written all or none
+                    let mut pipeline = pipe();
+                    pipeline.atomic();
+                    for range in changed_ranges(&edit.original, &edit.data) {
+                        pipeline
+                            .cmd("SETRANGE")
+                            .arg(&key)
+                            .arg(edit.offset + range.start)
+                            .arg(&edit.data[range])
+                            .ignore();
+                    }
+                    let _: () = pipeline.query_async(&mut conn).await?;
+                } else {
+                    let mut binding = cmd("SET");
+                    let mut new_cmd = binding.arg(&key).arg(&edit.data);
+                    // keep ttl if the version is at least 6.0.0
+                    new_cmd = if client.is_at_least_version("6.0.0") {
+                        new_cmd.arg("KEEPTTL")
+                    } else if ttl > 0 {
+                        new_cmd.arg("PX").arg(ttl)
+                    } else {
+                        new_cmd
+                    };
+                    let _: () = new_cmd.query_async(&mut conn).await?;
+                }
+                let size = cmd("MEMORY")
+                    .arg("USAGE")
+                    .arg(&key)
+                    .query_async::<u64>(&mut conn)
+                    .await
+                    .ok();
+
+                // The whole value is decoded again, a partial value only patches its loaded bytes
+                let data = if partial {
+                    None
+                } else {
+                    let mut data = RedisBytesValue {
+                        length: edit.data.len(),
+                        bytes: Bytes::from(edit.data),
+                        ..Default::default()
+                    };
+                    data.detect_and_update(server_id.as_str(), key.display_str(), max_truncate_length);
+                    data.view_mode = view_mode;
+                    if data.format == DataFormat::HyperLogLog {
+                        data.hyperloglog = get_hyperloglog(&mut conn, &key, &data.bytes).await.ok();
+                    }
+                    Some(data)
+                };
+                Ok((size, data))
+            },
+            move |this, result, cx| {
+                if this.key.as_ref() != Some(&current_key) {
+                    return;
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok((size, data)) = result {
+                        if let Some(size) = size {
+                            value.size = size;
+                        }
+                        if let Some(data) = data {
+                            value.data = Some(RedisValueData::Bytes(Arc::new(data)));
+                        } else if let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut() {
+                            Arc::make_mut(bytes_value).patch(page);
+                        }
+                    }
+                }
+                cx.emit(ServerEvent::ValueUpdated);
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
+
 // pub(crate) async fn get_redis_value(
 //     mut data: RedisBytesValue,
 //     key: &str,
@@ -167,3 +683,20 @@ pub(crate) async fn get_redis_bytes_value(conn: &mut RedisAsyncConn, key: &str)
 //         ..Default::default()
 //     })
 // }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lz4_size_prefix() -> Result<()> {
+        let data = b"zedis zedis zedis zedis";
+        let compressed = CompressionFormat::Lz4.compress(data)?;
+        assert_eq!(CompressionFormat::Lz4.decompress(&compressed)?, data);
+        // an md5 hex digest decoded as a block, its prefix asks for about 3.6 GB
+        let digest = b"\xd4\x1d\x8c\xd9\x8f\x00\xb2\x04\xe9\x80\x09\x98\xec\xf8\x42\x7e";
+        assert!(CompressionFormat::Lz4.decompress(digest).is_err());
+        assert!(CompressionFormat::Lz4.decompress(b"\x01").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,480 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RedisTimeSeries (`TSDB-TYPE`) data type operations module.
+//!
+//! This module provides functionality for managing RedisTimeSeries operations including:
+//! - Loading retention, labels and compaction rules (TS.INFO)
+//! - Querying samples by timestamp range with pagination (TS.RANGE / TS.REVRANGE)
+//! - Aggregating samples into buckets (AGGREGATION avg/sum/min/max/...)
+//! - Adding samples (TS.ADD) and deleting samples by range (TS.DEL)
+
+use super::{
+    KeyType, RedisValueData, ServerTask, ZedisServerState,
+    value::{RedisTimeseriesRule, RedisTimeseriesValue, RedisValue, RedisValueStatus},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::{RedisKey, redis_value_to_pairs, redis_value_to_string},
+    states::{ServerEvent, ZedisGlobalStore, i18n_timeseries_editor},
+};
+use gpui::{SharedString, prelude::*};
+use redis::{Value, cmd};
+use rust_i18n::t;
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Number of samples loaded per page
+const TIMESERIES_PAGE_SIZE: usize = 1_000;
+
+/// Aggregation types supported by TS.RANGE AGGREGATION
+pub const TIMESERIES_AGGREGATIONS: [&str; 13] = [
+    "avg", "sum", "min", "max", "range", "count", "first", "last", "std.p", "std.s", "var.p", "var.s", "twa",
+];
+
+/// Range query options for TS.RANGE / TS.REVRANGE
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TimeseriesQuery {
+    /// Start timestamp in milliseconds, `-` (or empty) for the earliest sample
+    pub from: SharedString,
+    /// End timestamp in milliseconds, `+` (or empty) for the latest sample
+    pub to: SharedString,
+    /// Query the newest samples first (TS.REVRANGE)
+    pub reverse: bool,
+    /// Aggregation type, `None` for raw samples
+    pub aggregation: Option<SharedString>,
+    /// Bucket duration of the aggregation in milliseconds
+    pub bucket_duration: u64,
+}
+
+impl TimeseriesQuery {
+    /// Returns the start of the range as a TS.RANGE argument
+    fn start_arg(&self) -> String {
+        let from = self.from.trim();
+        if from.is_empty() {
+            "-".to_string()
+        } else {
+            from.to_string()
+        }
+    }
+    /// Returns the end of the range as a TS.RANGE argument
+    fn end_arg(&self) -> String {
+        let to = self.to.trim();
+        if to.is_empty() { "+".to_string() } else { to.to_string() }
+    }
+    /// Returns the query with the range parsed from `from [to]`
+    pub fn with_range(&self, keyword: &str) -> Self {
+        let mut parts = keyword.split_whitespace();
+        Self {
+            from: parts.next().unwrap_or_default().to_string().into(),
+            to: parts.next().unwrap_or_default().to_string().into(),
+            ..self.clone()
+        }
+    }
+}
+
+/// Converts the labels of TS.INFO, `[[name, value], ...]` or a map, into name-value pairs.
+fn labels_from_value(value: Value) -> Vec<(SharedString, SharedString)> {
+    let items = match value {
+        Value::Map(items) => items,
+        Value::Array(items) => items
+            .into_iter()
+            .filter_map(|item| match item {
+                Value::Array(mut pair) if pair.len() == 2 => {
+                    let value = pair.pop()?;
+                    let name = pair.pop()?;
+                    Some((name, value))
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    };
+    items
+        .iter()
+        .map(|(name, value)| (redis_value_to_string(name).into(), redis_value_to_string(value).into()))
+        .collect()
+}
+
+/// Converts the compaction rules of TS.INFO into rules.
+///
+/// RESP2 replies `[[dest, bucket, aggregation, align], ...]`,
+/// RESP3 replies a map of `dest => [bucket, aggregation, align]`.
+fn rules_from_value(value: Value) -> Vec<RedisTimeseriesRule> {
+    let new_rule = |dest_key: &Value, fields: &[Value]| RedisTimeseriesRule {
+        dest_key: redis_value_to_string(dest_key).into(),
+        bucket_duration: fields
+            .first()
+            .and_then(|v| redis_value_to_string(v).parse::<u64>().ok())
+            .unwrap_or_default(),
+        aggregation: fields.get(1).map(redis_value_to_string).unwrap_or_default().into(),
+    };
+    match value {
+        Value::Map(items) => items
+            .iter()
+            .filter_map(|(dest_key, fields)| match fields {
+                Value::Array(fields) => Some(new_rule(dest_key, fields)),
+                _ => None,
+            })
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| match item {
+                Value::Array(fields) if !fields.is_empty() => Some(new_rule(&fields[0], &fields[1..])),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Loads TS.INFO into a time series value without samples.
+async fn get_redis_timeseries_info(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisTimeseriesValue> {
+    let value: Value = cmd("TS.INFO").arg(key).query_async(conn).await?;
+    let mut timeseries = RedisTimeseriesValue::default();
+    for (name, value) in redis_value_to_pairs(value) {
+        match name.as_str() {
+            "labels" => timeseries.labels = labels_from_value(value),
+            "rules" => timeseries.rules = rules_from_value(value),
+            _ => {
+                if value == Value::Nil {
+                    continue;
+                }
+                let value = redis_value_to_string(&value);
+                if name == "totalSamples" {
+                    timeseries.size = value.parse::<usize>().unwrap_or_default();
+                }
+                timeseries.info.push((name.into(), value.into()));
+            }
+        }
+    }
+    Ok(timeseries)
+}
+
+/// Loads a page of samples with TS.RANGE / TS.REVRANGE.
+///
+/// # Arguments
+/// * `from` - Start of the range, overrides the start of the query when paginating
+/// * `to` - End of the range, overrides the end of the query when paginating
+async fn get_redis_timeseries_samples(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    query: &TimeseriesQuery,
+    from: &str,
+    to: &str,
+) -> Result<Vec<(i64, f64)>> {
+    let mut command = cmd(if query.reverse { "TS.REVRANGE" } else { "TS.RANGE" });
+    command
+        .arg(key)
+        .arg(from)
+        .arg(to)
+        .arg("COUNT")
+        .arg(TIMESERIES_PAGE_SIZE);
+    if let Some(aggregation) = query.aggregation.as_ref() {
+        if query.bucket_duration == 0 {
+            return Err(Error::Invalid {
+                message: "Bucket duration must be greater than 0".to_string(),
+            });
+        }
+        command
+            .arg("AGGREGATION")
+            .arg(aggregation.as_str())
+            .arg(query.bucket_duration);
+    }
+    // The sample value is a simple string in RESP2 and a double in RESP3
+    let samples: Vec<(i64, Value)> = command.query_async(conn).await?;
+    Ok(samples
+        .into_iter()
+        .filter_map(|(timestamp, value)| {
+            let value = redis_value_to_string(&value).parse::<f64>().ok()?;
+            Some((timestamp, value))
+        })
+        .collect())
+}
+
+/// Loads TS.INFO and the first page of samples for the query.
+async fn get_redis_timeseries_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    query: TimeseriesQuery,
+) -> Result<RedisTimeseriesValue> {
+    let mut timeseries = get_redis_timeseries_info(conn, key).await?;
+    let values = get_redis_timeseries_samples(conn, key, &query, &query.start_arg(), &query.end_arg()).await?;
+    timeseries.done = values.len() < TIMESERIES_PAGE_SIZE;
+    timeseries.values = values;
+    timeseries.query = query;
+    Ok(timeseries)
+}
+
+/// Performs the initial load of a RedisTimeSeries value.
+pub(crate) async fn first_load_timeseries_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisValue> {
+    let timeseries = get_redis_timeseries_value(conn, key, TimeseriesQuery::default()).await?;
+    Ok(RedisValue {
+        key_type: KeyType::Timeseries,
+        data: Some(RedisValueData::Timeseries(Arc::new(timeseries))),
+        ..Default::default()
+    })
+}
+
+impl ZedisServerState {
+    /// Loads the next page of samples after the last loaded one.
+    ///
+    /// # Arguments
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn load_more_timeseries_value(&mut self, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(timeseries) = value.timeseries_value() else {
+            return;
+        };
+        if timeseries.done {
+            return;
+        }
+        let query = timeseries.query.clone();
+        let (mut from, mut to) = (query.start_arg(), query.end_arg());
+        // Continue right after the last loaded sample (or bucket)
+        if let Some((last, _)) = timeseries.values.last() {
+            let step = if query.aggregation.is_some() {
+                query.bucket_duration.max(1) as i64
+            } else {
+                1
+            };
+            if query.reverse {
+                to = (last - 1).to_string();
+            } else {
+                from = (last + step).to_string();
+            }
+        }
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        cx.emit(ServerEvent::ValuePaginationStarted);
+
+        self.spawn(
+            ServerTask::LoadMoreValue,
+            // Async operation: fetch next page using TS.RANGE / TS.REVRANGE
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_redis_timeseries_samples(&mut conn, &key, &query, &from, &to).await
+            },
+            // UI callback: append results into local state
+            move |this, result, cx| {
+                if let Ok(new_values) = result
+                    && let Some(RedisValueData::Timeseries(timeseries_data)) =
+                        this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let timeseries = Arc::make_mut(timeseries_data);
+                    timeseries.done = new_values.len() < TIMESERIES_PAGE_SIZE;
+                    timeseries.values.extend(new_values);
+                }
+
+                cx.emit(ServerEvent::ValuePaginationFinished);
+
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+
+    /// Reloads TS.INFO and the samples with a new range query.
+    ///
+    /// # Arguments
+    /// * `query` - Range, order and aggregation options
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn query_timeseries_value(&mut self, query: TimeseriesQuery, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::QueryTimeseriesValue,
+            // Async operation: execute TS.INFO and TS.RANGE / TS.REVRANGE
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_redis_timeseries_value(&mut conn, &key, query).await
+            },
+            // UI callback: replace the samples in local state
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                    if let Ok(timeseries) = result {
+                        value.data = Some(RedisValueData::Timeseries(Arc::new(timeseries)));
+                        cx.emit(ServerEvent::ValueUpdated);
+                    }
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+
+    /// Filters the samples by timestamp range in the form of `from [to]`.
+    pub fn filter_timeseries_value(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        let Some(timeseries) = self.value.as_ref().and_then(|v| v.timeseries_value()) else {
+            return;
+        };
+        let query = timeseries.query.with_range(&keyword);
+        self.query_timeseries_value(query, cx);
+    }
+
+    /// Reloads the samples with the current query.
+    fn reload_timeseries_value(&mut self, cx: &mut Context<Self>) {
+        let Some(timeseries) = self.value.as_ref().and_then(|v| v.timeseries_value()) else {
+            return;
+        };
+        let query = timeseries.query.clone();
+        self.query_timeseries_value(query, cx);
+    }
+
+    /// Adds a sample to the time series (TS.ADD).
+    ///
+    /// # Arguments
+    /// * `timestamp` - Timestamp in milliseconds, `*` (or empty) for the server time
+    /// * `sample` - Value of the sample
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn add_timeseries_value(&mut self, timestamp: SharedString, sample: f64, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let timestamp = if timestamp.trim().is_empty() {
+            "*".to_string()
+        } else {
+            timestamp.trim().to_string()
+        };
+
+        self.spawn(
+            ServerTask::AddTimeseriesValue,
+            // Async operation: execute TS.ADD on Redis
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let timestamp: i64 = cmd("TS.ADD")
+                    .arg(&key)
+                    .arg(timestamp.as_str())
+                    .arg(sample)
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(timestamp)
+            },
+            // UI callback: notify and reload the samples
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if let Ok(timestamp) = result {
+                    let title = i18n_timeseries_editor(cx, "add_value_success");
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let msg = t!(
+                        "timeseries_editor.add_value_success_tips",
+                        timestamp = timestamp,
+                        locale = locale
+                    )
+                    .to_string();
+                    this.emit_success_notification(msg.into(), title, cx);
+                    this.reload_timeseries_value(cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+
+    /// Deletes the samples between two timestamps, inclusive (TS.DEL).
+    ///
+    /// # Arguments
+    /// * `from` - Start timestamp in milliseconds, `-` (or empty) for the earliest sample
+    /// * `to` - End timestamp in milliseconds, `+` (or empty) for the latest sample
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn remove_timeseries_value(&mut self, from: SharedString, to: SharedString, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        // TS.DEL does not accept `-` and `+`, so use the bounds of the timestamp space
+        let from = match from.trim() {
+            "" | "-" => "0".to_string(),
+            from => from.to_string(),
+        };
+        let to = match to.trim() {
+            "" | "+" => i64::MAX.to_string(),
+            to => to.to_string(),
+        };
+
+        self.spawn(
+            ServerTask::RemoveTimeseriesValue,
+            // Async operation: execute TS.DEL on Redis
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let count: usize = cmd("TS.DEL")
+                    .arg(&key)
+                    .arg(from.as_str())
+                    .arg(to.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(count)
+            },
+            // UI callback: notify and reload the samples
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if let Ok(count) = result {
+                    let title = i18n_timeseries_editor(cx, "remove_value_success");
+                    let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+                    let msg = t!(
+                        "timeseries_editor.remove_value_success_tips",
+                        count = count,
+                        locale = locale
+                    )
+                    .to_string();
+                    this.emit_success_notification(msg.into(), title, cx);
+                    this.reload_timeseries_value(cx);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+}
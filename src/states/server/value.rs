@@ -12,13 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use super::{ServerEvent, ServerTask, ZedisServerState};
+use super::{
+    ServerEvent, ServerTask, ZedisServerState,
+    decoder::{COMMAND_DECODER, get_decoder},
+    json::{JSON_ROOT_PATH, validate_json},
+    string::{CompressionFormat, TextEncoding},
+    timeseries::TimeseriesQuery,
+};
 use crate::connection::get_connection_manager;
+use crate::helpers::{RedisBytes, RedisKey, is_php_serialized};
+use crate::states::i18n_editor;
 use bytes::Bytes;
 use chrono::Local;
 use gpui::{Hsla, SharedString, prelude::*};
 use redis::cmd;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::io::Cursor;
 use std::sync::Arc;
 
@@ -39,6 +48,17 @@ pub enum DataFormat {
     Snappy,
     Protobuf,
     MessagePack,
+    Cbor,
+    Bson,
+    Avro,
+    Php,
+    Pickle,
+    /// Java object serialization stream, decoded read only
+    Java,
+    /// Output of the external command of a decoder rule
+    Command,
+    /// HyperLogLog written by PFADD, starting with the `HYLL` header
+    HyperLogLog,
 }
 
 impl DataFormat {
@@ -58,10 +78,33 @@ impl DataFormat {
             DataFormat::Zstd => "zstd",
             DataFormat::Protobuf => "protobuf",
             DataFormat::MessagePack => "messagepack",
+            DataFormat::Cbor => "cbor",
+            DataFormat::Bson => "bson",
+            DataFormat::Avro => "avro",
+            DataFormat::Php => "php",
+            DataFormat::Pickle => "pickle",
+            DataFormat::Java => "java",
+            DataFormat::Command => "command",
+            DataFormat::HyperLogLog => "hyperloglog",
         }
     }
 }
 
+/// Joins the layers of a decode chain from the outermost one, e.g. `base64 › zstd › protobuf`
+pub(crate) fn format_chain(
+    encoding: Option<TextEncoding>,
+    compression: Option<CompressionFormat>,
+    format: DataFormat,
+) -> String {
+    encoding
+        .map(|encoding| encoding.as_str())
+        .into_iter()
+        .chain(compression.map(|compression| compression.as_str()))
+        .chain(std::iter::once(format.as_str()))
+        .collect::<Vec<_>>()
+        .join(" › ")
+}
+
 fn is_valid_messagepack(bytes: &[u8]) -> bool {
     if bytes.is_empty() {
         return false;
@@ -118,6 +161,49 @@ fn is_svg(bytes: &[u8]) -> bool {
     false
 }
 
+/// Length of the HyperLogLog header: magic, encoding, unused bytes and cached cardinality
+const HYPERLOGLOG_HEADER_LENGTH: usize = 16;
+
+fn is_hyperloglog(bytes: &[u8]) -> bool {
+    bytes.len() >= HYPERLOGLOG_HEADER_LENGTH && bytes.starts_with(b"HYLL") && bytes[4] <= 1
+}
+
+fn is_java_serialized(bytes: &[u8]) -> bool {
+    // STREAM_MAGIC and STREAM_VERSION
+    bytes.starts_with(&[0xAC, 0xED, 0x00, 0x05])
+}
+
+fn is_avro_container(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"Obj\x01")
+}
+
+fn is_pickle(bytes: &[u8]) -> bool {
+    // PROTO opcode with protocol 2-5, ends with the STOP opcode
+    bytes.len() > 3 && bytes[0] == 0x80 && (2..=5).contains(&bytes[1]) && bytes.last() == Some(&b'.')
+}
+
+fn is_bson(bytes: &[u8]) -> bool {
+    // document size (little-endian i32) followed by the elements and a trailing NUL
+    if bytes.len() < 5 || bytes.last() != Some(&0) {
+        return false;
+    }
+    let size = i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    size as usize == bytes.len() && bson::Document::from_reader(bytes).is_ok()
+}
+
+fn is_cbor(bytes: &[u8]) -> bool {
+    // self-described CBOR tag 55799
+    if bytes.starts_with(&[0xD9, 0xD9, 0xF7]) {
+        return true;
+    }
+    // maps (major type 5), arrays overlap the MessagePack fix types
+    if !bytes.first().is_some_and(|b| (0xA0..=0xBF).contains(b)) {
+        return false;
+    }
+    let mut reader = bytes;
+    ciborium::from_reader::<ciborium::Value, _>(&mut reader).is_ok() && reader.is_empty()
+}
+
 fn is_snappy_framed(bytes: &[u8]) -> bool {
     if bytes.len() < 10 {
         return false;
@@ -129,13 +215,31 @@ pub fn detect_format(bytes: &[u8]) -> (DataFormat, Option<SharedString>) {
     if bytes.is_empty() {
         return (DataFormat::Bytes, None);
     }
+    if is_hyperloglog(bytes) {
+        return (DataFormat::HyperLogLog, None);
+    }
     let Some(kind) = infer::get(bytes) else {
         return if is_snappy_framed(bytes) {
             (DataFormat::Snappy, Some("application/snappy".to_string().into()))
         } else if is_svg(bytes) {
             (DataFormat::Svg, Some("image/svg+xml".to_string().into()))
+        } else if is_java_serialized(bytes) {
+            (
+                DataFormat::Java,
+                Some("application/x-java-serialized-object".to_string().into()),
+            )
+        } else if is_avro_container(bytes) {
+            (DataFormat::Avro, Some("application/avro".to_string().into()))
+        } else if is_pickle(bytes) {
+            (DataFormat::Pickle, None)
+        } else if is_bson(bytes) {
+            (DataFormat::Bson, Some("application/bson".to_string().into()))
         } else if is_valid_messagepack(bytes) {
             (DataFormat::MessagePack, None)
+        } else if is_cbor(bytes) {
+            (DataFormat::Cbor, Some("application/cbor".to_string().into()))
+        } else if is_php_serialized(bytes) {
+            (DataFormat::Php, None)
         } else {
             (DataFormat::Bytes, None)
         };
@@ -161,6 +265,10 @@ pub enum RedisValueData {
     Set(Arc<RedisSetValue>),
     Zset(Arc<RedisZsetValue>),
     Hash(Arc<RedisHashValue>),
+    Stream(Arc<RedisStreamValue>),
+    Vectorset(Arc<RedisVectorsetValue>),
+    Timeseries(Arc<RedisTimeseriesValue>),
+    Probabilistic(Arc<RedisProbabilisticValue>),
 }
 
 /// Redis Set value structure with pagination support
@@ -169,7 +277,7 @@ pub struct RedisSetValue {
     pub keyword: Option<SharedString>,
     pub cursor: u64,
     pub size: usize,
-    pub values: Vec<SharedString>,
+    pub values: Vec<RedisBytes>,
     pub done: bool,
 }
 
@@ -178,6 +286,7 @@ pub struct RedisSetValue {
 pub enum SortOrder {
     #[default]
     Asc, // Ascending order (default)
+    Desc, // Descending order
 }
 
 /// Redis Sorted Set value structure with pagination and sorting support
@@ -186,9 +295,39 @@ pub struct RedisZsetValue {
     pub keyword: Option<SharedString>,
     pub cursor: u64,
     pub size: usize,
-    pub values: Vec<(SharedString, f64)>,
+    pub values: Vec<(RedisBytes, f64)>,
     pub done: bool,
     pub sort_order: SortOrder,
+    /// Positions of the loaded members, loaded by the geo view
+    pub geo: Option<Arc<RedisGeoValue>>,
+}
+
+/// Position of a member of a geo sorted set
+#[derive(Debug, Clone, Default)]
+pub struct RedisGeoMember {
+    pub member: RedisBytes,
+    pub longitude: f64,
+    pub latitude: f64,
+    /// Standard 11 characters geohash (GEOHASH)
+    pub geohash: SharedString,
+    /// Distance from the search origin, in the unit of the search
+    pub distance: Option<f64>,
+}
+
+/// Geo view of a sorted set, members with a score that is not a geohash are left out
+#[derive(Debug, Clone, Default)]
+pub struct RedisGeoValue {
+    /// Positions of the loaded members (GEOPOS)
+    pub members: Vec<RedisGeoMember>,
+    /// Members found by the last GEOSEARCH, shown instead of the loaded members
+    pub search: Option<Vec<RedisGeoMember>>,
+}
+
+impl RedisGeoValue {
+    /// Returns the members shown by the geo view: the search result or else the loaded members
+    pub fn rows(&self) -> &[RedisGeoMember] {
+        self.search.as_deref().unwrap_or(&self.members)
+    }
 }
 
 /// Redis Hash value structure with pagination support
@@ -198,7 +337,135 @@ pub struct RedisHashValue {
     pub keyword: Option<SharedString>,
     pub size: usize,
     pub done: bool,
-    pub values: Vec<(SharedString, SharedString)>,
+    pub values: Vec<(RedisBytes, RedisBytes)>,
+    /// Whether the server supports hash field expiration (Redis 7.4+)
+    pub field_ttl: bool,
+    /// Only keep the fields with a ttl while scanning
+    pub expiring_only: bool,
+    /// Expiration unix timestamps (ms) of the loaded fields with a ttl
+    pub field_expire_at: HashMap<RedisBytes, i64>,
+}
+
+/// A single Redis Stream entry: the entry ID and its field-value pairs in insertion order
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamEntry {
+    pub id: SharedString,
+    pub fields: Vec<(SharedString, SharedString)>,
+}
+
+/// Consumer group of a Redis Stream (XINFO GROUPS)
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamGroup {
+    pub name: SharedString,
+    pub consumers: u64,
+    pub pending: u64,
+    pub last_delivered_id: SharedString,
+    /// Only available since Redis 7.0
+    pub lag: Option<u64>,
+}
+
+/// Consumer of a stream consumer group (XINFO CONSUMERS)
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamConsumer {
+    pub name: SharedString,
+    pub pending: u64,
+    /// Milliseconds since the last interaction of the consumer
+    pub idle: u64,
+}
+
+/// Pending entry of a stream consumer group (XPENDING)
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamPendingEntry {
+    pub id: SharedString,
+    pub consumer: SharedString,
+    /// Milliseconds since the entry was last delivered
+    pub idle: u64,
+    pub delivery_count: u64,
+}
+
+/// Consumer groups of a stream, with the consumers and pending entries of the selected group
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamGroups {
+    pub groups: Vec<RedisStreamGroup>,
+    pub selected: Option<SharedString>,
+    pub consumers: Vec<RedisStreamConsumer>,
+    pub pending: Vec<RedisStreamPendingEntry>,
+}
+
+/// Redis Stream value structure with ID range pagination support
+#[derive(Debug, Clone, Default)]
+pub struct RedisStreamValue {
+    /// ID range filter in the form of `start [end]`
+    pub keyword: Option<SharedString>,
+    pub size: usize,
+    pub values: Vec<RedisStreamEntry>,
+    pub done: bool,
+    pub sort_order: SortOrder,
+    /// Consumer groups, `None` until they are loaded
+    pub groups: Option<Arc<RedisStreamGroups>>,
+}
+
+/// A single element of a Redis Vector Set with its attributes and embedding
+#[derive(Debug, Clone, Default)]
+pub struct RedisVectorsetElement {
+    pub name: SharedString,
+    /// JSON attributes (VGETATTR)
+    pub attributes: Option<SharedString>,
+    /// Approximate embedding (VEMB)
+    pub embedding: Vec<f64>,
+}
+
+/// Redis Vector Set value structure with pagination and similarity search results
+#[derive(Debug, Clone, Default)]
+pub struct RedisVectorsetValue {
+    /// Element name prefix filter
+    pub keyword: Option<SharedString>,
+    /// Number of elements (VCARD)
+    pub size: usize,
+    /// Dimension of the vectors (VDIM)
+    pub dim: usize,
+    /// Field-value pairs reported by VINFO
+    pub info: Vec<(SharedString, SharedString)>,
+    pub values: Vec<RedisVectorsetElement>,
+    pub done: bool,
+    /// Ranked VSIM results: (element, similarity score)
+    pub similar: Option<Vec<(SharedString, f64)>>,
+}
+
+/// Compaction rule of a Redis TimeSeries (TS.INFO rules)
+#[derive(Debug, Clone, Default)]
+pub struct RedisTimeseriesRule {
+    pub dest_key: SharedString,
+    /// Bucket duration in milliseconds
+    pub bucket_duration: u64,
+    pub aggregation: SharedString,
+}
+
+/// Redis TimeSeries value structure with range query results
+#[derive(Debug, Clone, Default)]
+pub struct RedisTimeseriesValue {
+    /// Number of samples (TS.INFO totalSamples)
+    pub size: usize,
+    /// Field-value pairs reported by TS.INFO, e.g. retentionTime and duplicatePolicy
+    pub info: Vec<(SharedString, SharedString)>,
+    pub labels: Vec<(SharedString, SharedString)>,
+    pub rules: Vec<RedisTimeseriesRule>,
+    /// Range query of the loaded samples
+    pub query: TimeseriesQuery,
+    /// Samples as (timestamp in milliseconds, value)
+    pub values: Vec<(i64, f64)>,
+    pub done: bool,
+}
+
+/// RedisBloom probabilistic structure (Bloom, Cuckoo, Count-Min, Top-K, t-digest) with query results
+#[derive(Debug, Clone, Default)]
+pub struct RedisProbabilisticValue {
+    /// Field-value pairs reported by BF.INFO, CF.INFO, CMS.INFO, TOPK.INFO or TDIGEST.INFO
+    pub info: Vec<(SharedString, SharedString)>,
+    /// Top-K items with their counts (TOPK.LIST WITHCOUNT)
+    pub items: Vec<(SharedString, SharedString)>,
+    /// Results of the last query: (input, result)
+    pub results: Option<Vec<(SharedString, SharedString)>>,
 }
 
 /// Redis List value structure
@@ -206,7 +473,7 @@ pub struct RedisHashValue {
 pub struct RedisListValue {
     pub keyword: Option<SharedString>,
     pub size: usize,
-    pub values: Vec<SharedString>,
+    pub values: Vec<RedisBytes>,
 }
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum ViewMode {
@@ -214,23 +481,109 @@ pub enum ViewMode {
     Auto,
     Plain,
     Hex,
+    /// Protobuf wire format decoded without a schema, like `protoc --decode_raw`
+    ProtobufRaw,
+    /// Bit grid of a string used as a bitmap
+    Bitmap,
+    /// Collapsible tree of a structured value (JSON, MessagePack, Protobuf, ...)
+    Tree,
+    /// Table of a JSON array of objects, with a column per key
+    Table,
 }
 
 impl ViewMode {
+    /// All view modes, in the order shown in the status bar
+    pub const ALL: [ViewMode; 7] = [
+        ViewMode::Auto,
+        ViewMode::Plain,
+        ViewMode::Hex,
+        ViewMode::ProtobufRaw,
+        ViewMode::Bitmap,
+        ViewMode::Tree,
+        ViewMode::Table,
+    ];
     pub fn as_str(&self) -> &'static str {
         match self {
             ViewMode::Auto => "Auto",
             ViewMode::Plain => "Plain",
             ViewMode::Hex => "Hex",
+            ViewMode::ProtobufRaw => "Protobuf Raw",
+            ViewMode::Bitmap => "Bitmap",
+            ViewMode::Tree => "Tree",
+            ViewMode::Table => "Table",
         }
     }
     pub fn from_str(s: &str) -> Self {
         match s {
             "Plain" => ViewMode::Plain,
             "Hex" => ViewMode::Hex,
+            "Protobuf Raw" => ViewMode::ProtobufRaw,
+            "Bitmap" => ViewMode::Bitmap,
+            "Tree" => ViewMode::Tree,
+            "Table" => ViewMode::Table,
             _ => ViewMode::Auto,
         }
     }
+    /// Whether the view mode shows the decoded document, which is loaded separately
+    pub fn is_document(&self) -> bool {
+        matches!(self, ViewMode::Tree | ViewMode::Table)
+    }
+}
+
+/// Bitmap summary of a string value with the results of BITFIELD operations
+#[derive(Debug, Clone, Default)]
+pub struct RedisBitmapSummary {
+    /// Number of set bits (BITCOUNT)
+    pub count: u64,
+    /// Offset of the first set bit (BITPOS 1), -1 if no bit is set
+    pub first_set: i64,
+    /// Offset of the first clear bit (BITPOS 0)
+    pub first_clear: i64,
+    /// Results of the BITFIELD operations as (operation, result), the latest first
+    pub fields: Vec<(SharedString, SharedString)>,
+}
+
+/// Register encoding of a HyperLogLog, read from its header
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HyperLogLogEncoding {
+    /// 16384 registers of 6 bits
+    #[default]
+    Dense,
+    /// Run-length encoded registers, used while most registers are zero
+    Sparse,
+}
+
+impl HyperLogLogEncoding {
+    /// Reads the encoding from the header of the HyperLogLog bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        match bytes.get(4) {
+            Some(1) => HyperLogLogEncoding::Sparse,
+            _ => HyperLogLogEncoding::Dense,
+        }
+    }
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HyperLogLogEncoding::Dense => "dense",
+            HyperLogLogEncoding::Sparse => "sparse",
+        }
+    }
+}
+
+/// HyperLogLog summary of a string value
+#[derive(Debug, Clone, Default)]
+pub struct RedisHyperLogLog {
+    pub encoding: HyperLogLogEncoding,
+    /// Estimated cardinality (PFCOUNT)
+    pub cardinality: u64,
+    /// Target key and its cardinality after the last PFMERGE
+    pub merged: Option<(SharedString, u64)>,
+}
+
+/// A range of a large string value, loaded on demand for the hex view
+#[derive(Debug, Clone, Default)]
+pub struct BytesPage {
+    pub offset: usize,
+    pub bytes: Bytes,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -240,6 +593,23 @@ pub struct RedisBytesValue {
     pub mime: Option<SharedString>,
     pub text: Option<SharedString>,
     pub view_mode: ViewMode,
+    /// Codec the value was decompressed with, used to re-encode the text on save
+    pub compression: Option<CompressionFormat>,
+    /// Text encoding wrapping the value, applied after compression on save
+    pub encoding: Option<TextEncoding>,
+    /// Name of the decoder the text was decoded with, used to encode the text on save,
+    /// `None` if the text can't be encoded back
+    pub decoder: Option<&'static str>,
+    /// Length of the string (STRLEN), larger than the bytes when only a prefix is loaded
+    pub length: usize,
+    /// Page of a partially loaded value shown in the hex view
+    pub page: Option<BytesPage>,
+    /// Bitmap summary, loaded by the bitmap view
+    pub bitmap: Option<RedisBitmapSummary>,
+    /// HyperLogLog summary, loaded with the value when it has the HyperLogLog header
+    pub hyperloglog: Option<RedisHyperLogLog>,
+    /// Decoded document without truncated strings, loaded by the tree and table views
+    pub document: Option<Arc<serde_json::Value>>,
 }
 
 impl RedisBytesValue {
@@ -252,6 +622,34 @@ impl RedisBytesValue {
     pub fn is_utf8_text(&self) -> bool {
         matches!(self.format, DataFormat::Text | DataFormat::Json)
     }
+    /// Returns true if only a prefix of a large value is loaded
+    pub fn is_partial(&self) -> bool {
+        self.length > self.bytes.len()
+    }
+    /// Returns the offset and the bytes shown in the hex view,
+    /// the loaded page of a partial value or else the whole bytes
+    pub fn hex_page(&self) -> (usize, &Bytes) {
+        match &self.page {
+            Some(page) => (page.offset, &page.bytes),
+            None => (0, &self.bytes),
+        }
+    }
+    /// Returns the decode chain of the value from the outermost layer, e.g. `base64 › zstd › protobuf`
+    pub fn format_chain(&self) -> String {
+        format_chain(self.encoding, self.compression, self.format)
+    }
+    /// Returns true if the text can be edited, structured formats are encoded back on save by their decoder
+    pub fn is_editable(&self) -> bool {
+        // Saving a partial value would truncate it
+        if self.is_partial() {
+            return false;
+        }
+        self.is_utf8_text()
+            || self
+                .decoder
+                .and_then(get_decoder)
+                .is_some_and(|decoder| decoder.is_encodable())
+    }
 }
 
 impl RedisValue {
@@ -286,9 +684,42 @@ impl RedisValue {
         }
         None
     }
+
+    /// Returns the stream value if the data is a Stream type
+    pub fn stream_value(&self) -> Option<&Arc<RedisStreamValue>> {
+        if let Some(RedisValueData::Stream(data)) = self.data.as_ref() {
+            return Some(data);
+        }
+        None
+    }
+
+    /// Returns the vector set value if the data is a Vectorset type
+    pub fn vectorset_value(&self) -> Option<&Arc<RedisVectorsetValue>> {
+        if let Some(RedisValueData::Vectorset(data)) = self.data.as_ref() {
+            return Some(data);
+        }
+        None
+    }
+
+    /// Returns the time series value if the data is a Timeseries type
+    pub fn timeseries_value(&self) -> Option<&Arc<RedisTimeseriesValue>> {
+        if let Some(RedisValueData::Timeseries(data)) = self.data.as_ref() {
+            return Some(data);
+        }
+        None
+    }
+
+    /// Returns the probabilistic structure value if the data is a Probabilistic type
+    pub fn probabilistic_value(&self) -> Option<&Arc<RedisProbabilisticValue>> {
+        if let Some(RedisValueData::Probabilistic(data)) = self.data.as_ref() {
+            return Some(data);
+        }
+        None
+    }
 }
 
-/// Redis key types: string, list, set, zset, hash, stream, and vectorset
+/// Redis key types: string, list, set, zset, hash, stream, vectorset, RedisJSON, RedisTimeSeries,
+/// and the RedisBloom probabilistic structures
 #[derive(Debug, Clone, Copy, Default, PartialEq)]
 pub enum KeyType {
     #[default]
@@ -300,6 +731,13 @@ pub enum KeyType {
     Hash,
     Stream,
     Vectorset,
+    Json,
+    Timeseries,
+    Bloom,
+    Cuckoo,
+    CountMinSketch,
+    TopK,
+    TDigest,
 }
 impl KeyType {
     /// Returns the abbreviated string representation of the key type
@@ -312,21 +750,43 @@ impl KeyType {
             KeyType::Zset => "ZSET",
             KeyType::Stream => "STRM",
             KeyType::Vectorset => "VEC",
+            KeyType::Json => "JSON",
+            KeyType::Timeseries => "TS",
+            KeyType::Bloom => "BF",
+            KeyType::Cuckoo => "CF",
+            KeyType::CountMinSketch => "CMS",
+            KeyType::TopK => "TOPK",
+            KeyType::TDigest => "TDIG",
             KeyType::Unknown => "",
         }
     }
 
+    /// Returns true for the RedisBloom probabilistic structures, which are read-only in the editor
+    pub fn is_probabilistic(&self) -> bool {
+        matches!(
+            self,
+            KeyType::Bloom | KeyType::Cuckoo | KeyType::CountMinSketch | KeyType::TopK | KeyType::TDigest
+        )
+    }
+
     /// Returns the color associated with this key type for UI display
     pub fn color(&self) -> Hsla {
         match self {
-            KeyType::String => gpui::hsla(0.6, 0.5, 0.5, 1.0),    // Blue
-            KeyType::List => gpui::hsla(0.8, 0.5, 0.5, 1.0),      // Purple
-            KeyType::Hash => gpui::hsla(0.1, 0.6, 0.5, 1.0),      // Orange
-            KeyType::Set => gpui::hsla(0.5, 0.5, 0.5, 1.0),       // Cyan
-            KeyType::Zset => gpui::hsla(0.0, 0.6, 0.55, 1.0),     // Red
-            KeyType::Stream => gpui::hsla(0.3, 0.5, 0.4, 1.0),    // Green
-            KeyType::Vectorset => gpui::hsla(0.9, 0.5, 0.5, 1.0), // Pink
-            KeyType::Unknown => gpui::hsla(0.0, 0.0, 0.4, 1.0),   // Gray
+            KeyType::String => gpui::hsla(0.6, 0.5, 0.5, 1.0),          // Blue
+            KeyType::List => gpui::hsla(0.8, 0.5, 0.5, 1.0),            // Purple
+            KeyType::Hash => gpui::hsla(0.1, 0.6, 0.5, 1.0),            // Orange
+            KeyType::Set => gpui::hsla(0.5, 0.5, 0.5, 1.0),             // Cyan
+            KeyType::Zset => gpui::hsla(0.0, 0.6, 0.55, 1.0),           // Red
+            KeyType::Stream => gpui::hsla(0.3, 0.5, 0.4, 1.0),          // Green
+            KeyType::Vectorset => gpui::hsla(0.9, 0.5, 0.5, 1.0),       // Pink
+            KeyType::Json => gpui::hsla(0.15, 0.6, 0.45, 1.0),          // Yellow
+            KeyType::Timeseries => gpui::hsla(0.45, 0.5, 0.4, 1.0),     // Teal
+            KeyType::Bloom => gpui::hsla(0.7, 0.45, 0.55, 1.0),         // Indigo
+            KeyType::Cuckoo => gpui::hsla(0.05, 0.5, 0.45, 1.0),        // Brown
+            KeyType::CountMinSketch => gpui::hsla(0.55, 0.6, 0.4, 1.0), // Steel blue
+            KeyType::TopK => gpui::hsla(0.95, 0.6, 0.45, 1.0),          // Crimson
+            KeyType::TDigest => gpui::hsla(0.25, 0.5, 0.4, 1.0),        // Olive
+            KeyType::Unknown => gpui::hsla(0.0, 0.0, 0.4, 1.0),         // Gray
         }
     }
 }
@@ -420,6 +880,13 @@ impl From<&str> for KeyType {
             "hash" => KeyType::Hash,
             "stream" => KeyType::Stream,
             "vectorset" => KeyType::Vectorset,
+            "ReJSON-RL" | "json" => KeyType::Json,
+            "TSDB-TYPE" | "timeseries" => KeyType::Timeseries,
+            "MBbloom--" => KeyType::Bloom,
+            "MBbloomCF" => KeyType::Cuckoo,
+            "CMSk-TYPE" => KeyType::CountMinSketch,
+            "TopK-TYPE" => KeyType::TopK,
+            "TDIS-TYPE" => KeyType::TDigest,
             "string" => KeyType::String,
             _ => KeyType::Unknown,
         }
@@ -427,12 +894,20 @@ impl From<&str> for KeyType {
 }
 
 impl ZedisServerState {
-    /// Saves a new value for a Redis string key
+    /// Saves a new value for a Redis string or RedisJSON key
     ///
     /// This method updates the UI immediately with the new value and then
     /// asynchronously persists it to Redis. If the save fails, the original
-    /// value is restored.
-    pub fn save_value(&mut self, key: SharedString, new_value: SharedString, cx: &mut Context<Self>) {
+    /// value is restored. RedisJSON documents are replaced at the root path.
+    /// String values are compressed with `compression` before writing, so values
+    /// decompressed for display keep their codec (or switch to another one).
+    pub fn save_value(
+        &mut self,
+        key: RedisKey,
+        new_value: SharedString,
+        compression: Option<CompressionFormat>,
+        cx: &mut Context<Self>,
+    ) {
         let server_id = self.server_id.clone();
         let db = self.db;
         let Some(value) = self.value.as_mut() else {
@@ -442,14 +917,49 @@ impl ZedisServerState {
         let Some(original_bytes_value) = value.bytes_value() else {
             return;
         };
+        if original_bytes_value.is_partial() {
+            return;
+        }
+        // Writing the text as is would corrupt values that can't be encoded back
+        if !original_bytes_value.is_editable() {
+            cx.emit(ServerEvent::ValueEncodeFailed(i18n_editor(cx, "value_not_encodable")));
+            return;
+        }
+        // The external command of the command decoder runs in the task, so it can not block the UI
+        let encode_in_task = original_bytes_value.decoder == Some(COMMAND_DECODER);
+        // Encode the edited JSON back to MessagePack or Protobuf before anything is written
+        let encoded = if encode_in_task {
+            None
+        } else {
+            match original_bytes_value.encode_text(&server_id, key.display_str(), &new_value) {
+                Ok(encoded) => encoded,
+                Err(e) => {
+                    cx.emit(ServerEvent::ValueEncodeFailed(e.to_string().into()));
+                    return;
+                }
+            }
+        };
+        let bytes_value = original_bytes_value.clone();
         let format = original_bytes_value.format;
+        let decoder = original_bytes_value.decoder;
+        let encoding = original_bytes_value.encoding;
+        let view_mode = original_bytes_value.view_mode.clone();
+        // the bitmap summary of the old bytes is loaded again once the value is written
+        let reload_bitmap = original_bytes_value.bitmap.is_some();
         let original_size = value.size;
+        let is_json = value.key_type == KeyType::Json;
+        let compression = if is_json { None } else { compression };
 
         value.status = RedisValueStatus::Updating;
         value.data = Some(RedisValueData::Bytes(Arc::new(RedisBytesValue {
             bytes: Bytes::from(new_value.clone().to_string().into_bytes()),
             text: Some(new_value.clone()),
             format,
+            compression,
+            encoding,
+            decoder,
+            view_mode,
+            length: new_value.len(),
             ..Default::default()
         })));
         let ttl = value.ttl().map(|ttl| ttl.num_milliseconds()).unwrap_or_default();
@@ -460,37 +970,68 @@ impl ZedisServerState {
             move || async move {
                 let client = get_connection_manager().get_client(&server_id, db).await?;
                 let mut conn = client.connection();
-                let mut binding = cmd("SET");
-                let mut new_cmd = binding.arg(key.as_str()).arg(new_value.as_str());
-                // keep ttl if the version is at least 6.0.0
-                new_cmd = if client.is_at_least_version("6.0.0") {
-                    new_cmd.arg("KEEPTTL")
-                } else if ttl > 0 {
-                    new_cmd.arg("PX").arg(ttl)
+                let mut written = None;
+                if is_json {
+                    // JSON.SET keeps the ttl of the key
+                    validate_json(new_value.as_str())?;
+                    let _: () = cmd("JSON.SET")
+                        .arg(&key)
+                        .arg(JSON_ROOT_PATH)
+                        .arg(new_value.as_str())
+                        .query_async(&mut conn)
+                        .await?;
                 } else {
-                    new_cmd
-                };
-                let _: () = new_cmd.query_async(&mut conn).await?;
+                    let encoded = if encode_in_task {
+                        bytes_value.encode_text(&server_id, key.display_str(), &new_value)?
+                    } else {
+                        encoded
+                    };
+                    // Reverse the decode chain: encode the structured format, compress, then apply the text encoding
+                    let payload = encoded.unwrap_or_else(|| Bytes::from(new_value.to_string().into_bytes()));
+                    let mut bytes = match compression {
+                        Some(compression) => Bytes::from(compression.compress(&payload)?),
+                        None => payload,
+                    };
+                    if let Some(encoding) = encoding {
+                        bytes = Bytes::from(encoding.encode(&bytes));
+                    }
+                    let mut binding = cmd("SET");
+                    let mut new_cmd = binding.arg(&key).arg(bytes.as_ref());
+                    // keep ttl if the version is at least 6.0.0
+                    new_cmd = if client.is_at_least_version("6.0.0") {
+                        new_cmd.arg("KEEPTTL")
+                    } else if ttl > 0 {
+                        new_cmd.arg("PX").arg(ttl)
+                    } else {
+                        new_cmd
+                    };
+                    let _: () = new_cmd.query_async(&mut conn).await?;
+                    written = Some(bytes);
+                }
 
                 let mut size = None;
-                if let Ok(memory_usage) = cmd("MEMORY")
-                    .arg("USAGE")
-                    .arg(key.as_str())
-                    .query_async::<u64>(&mut conn)
-                    .await
-                {
+                if let Ok(memory_usage) = cmd("MEMORY").arg("USAGE").arg(&key).query_async::<u64>(&mut conn).await {
                     size = Some(memory_usage);
                 }
 
-                Ok(size)
+                Ok((size, written))
             },
             move |this, result, cx| {
+                let saved = result.is_ok();
                 if let Some(value) = this.value.as_mut() {
                     value.status = RedisValueStatus::Idle;
-                    if let Ok(result_size) = result {
+                    if let Ok((result_size, written)) = result {
                         if let Some(size) = result_size {
                             value.size = size;
                         }
+                        // Keep the bytes stored in Redis, so the hex view shows the compressed data
+                        if let Some(written) = written
+                            && let Some(RedisValueData::Bytes(bytes_value)) = value.data.as_mut()
+                        {
+                            let bytes_value = Arc::make_mut(bytes_value);
+                            bytes_value.length = written.len();
+                            bytes_value.bytes = written;
+                        }
                     } else {
                         // Recover original value if save failed
                         value.size = original_size;
@@ -498,6 +1039,9 @@ impl ZedisServerState {
                     }
                     cx.emit(ServerEvent::ValueUpdated);
                 }
+                if saved && reload_bitmap {
+                    this.load_bitmap_summary(cx);
+                }
                 cx.notify();
             },
             cx,
@@ -512,9 +1056,18 @@ impl ZedisServerState {
         // Directly modify the data in place
         if let Some(RedisValueData::Bytes(bytes_value)) = &mut value.data {
             let bytes_value = Arc::make_mut(bytes_value);
+            let is_bitmap = view_mode == ViewMode::Bitmap;
+            let is_document = view_mode.is_document();
             bytes_value.view_mode = view_mode;
             cx.emit(ServerEvent::ValueModeViewUpdated);
             cx.notify();
+            if is_bitmap {
+                self.load_bitmap_summary(cx);
+            }
+            // the document is decoded again, the value may have been saved in another view mode
+            if is_document {
+                self.load_document(cx);
+            }
         }
     }
 }
@@ -0,0 +1,525 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis Vector Set data type operations module.
+//!
+//! This module provides functionality for managing Redis Vector Set operations including:
+//! - Loading metadata (VCARD, VDIM, VINFO)
+//! - Loading elements with pagination (VRANGE, falling back to VRANDMEMBER)
+//! - Loading element attributes and embeddings (VGETATTR, VEMB)
+//! - Similarity search by element or by vector (VSIM)
+//! - Adding and removing elements (VADD, VREM)
+
+use super::{
+    KeyType, RedisValueData, ServerTask, ZedisServerState,
+    value::{RedisValue, RedisValueStatus, RedisVectorsetElement, RedisVectorsetValue},
+};
+use crate::{
+    connection::{RedisAsyncConn, get_connection_manager},
+    error::Error,
+    helpers::{RedisKey, redis_value_to_string},
+    states::{ServerEvent, i18n_vectorset_editor},
+};
+use gpui::{SharedString, prelude::*};
+use redis::{Value, cmd, pipe};
+use std::sync::Arc;
+
+type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Number of elements loaded per page
+const VECTORSET_PAGE_SIZE: usize = 100;
+
+/// Maximum number of elements loaded by the VRANDMEMBER fallback
+const VECTORSET_RANDOM_MAX: usize = 1_000;
+
+/// Similarity search options for VSIM
+#[derive(Debug, Clone, Default)]
+pub struct VectorsetQuery {
+    /// Query by element name (ELE) instead of by vector (VALUES)
+    pub by_element: bool,
+    /// Element name, or comma separated vector values
+    pub query: SharedString,
+    pub count: usize,
+    /// Exploration factor, `None` to use the server default
+    pub ef: Option<usize>,
+    /// Filter expression on the element attributes
+    pub filter: Option<SharedString>,
+}
+
+/// Parses a comma or whitespace separated list of numbers into a vector.
+pub fn parse_vector(input: &str) -> Option<Vec<f64>> {
+    let values = input
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<f64>().ok())
+        .collect::<Option<Vec<f64>>>()?;
+    if values.is_empty() {
+        return None;
+    }
+    Some(values)
+}
+
+/// Converts a VEMB reply into a vector of numbers.
+fn embedding_from_value(value: &Value) -> Vec<f64> {
+    match value {
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| redis_value_to_string(item).parse().ok())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// Loads attributes (VGETATTR) and embeddings (VEMB) for the given elements.
+async fn get_redis_vectorset_elements(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    names: Vec<String>,
+) -> Result<Vec<RedisVectorsetElement>> {
+    if names.is_empty() {
+        return Ok(vec![]);
+    }
+    let mut pipeline = pipe();
+    for name in names.iter() {
+        pipeline.cmd("VGETATTR").arg(key).arg(name);
+        pipeline.cmd("VEMB").arg(key).arg(name);
+    }
+    let replies: Vec<Value> = pipeline.query_async(conn).await?;
+
+    let elements = names
+        .into_iter()
+        .zip(replies.chunks(2))
+        .map(|(name, chunk)| {
+            let attributes = match chunk.first() {
+                Some(Value::Nil) | None => None,
+                Some(v) => Some(redis_value_to_string(v).into()),
+            };
+            let embedding = chunk.get(1).map(embedding_from_value).unwrap_or_default();
+            RedisVectorsetElement {
+                name: name.into(),
+                attributes,
+                embedding,
+            }
+        })
+        .collect();
+    Ok(elements)
+}
+
+/// Returns `true` if the server does not know the command, e.g. VRANGE before Redis 8.2.
+fn is_unknown_command(err: &redis::RedisError) -> bool {
+    err.code() == Some("ERR") && err.detail().is_some_and(|detail| detail.starts_with("unknown command"))
+}
+
+/// Retrieves a page of vector set elements in lexicographic order.
+///
+/// Uses VRANGE (Redis 8.2+) and falls back to VRANDMEMBER on older servers,
+/// in which case all elements (up to a limit) are returned in a single page.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The vector set key
+/// * `keyword` - Optional element name prefix
+/// * `last` - Last loaded element, the page starts right after it
+/// * `count` - Number of elements to load
+///
+/// # Returns
+/// A tuple of (elements, done)
+async fn get_redis_vectorset_value(
+    conn: &mut RedisAsyncConn,
+    key: &RedisKey,
+    keyword: Option<SharedString>,
+    last: Option<SharedString>,
+    count: usize,
+) -> Result<(Vec<RedisVectorsetElement>, bool)> {
+    let start = match (&last, &keyword) {
+        (Some(last), _) => format!("({last}").into_bytes(),
+        (None, Some(keyword)) => format!("[{keyword}").into_bytes(),
+        (None, None) => b"-".to_vec(),
+    };
+    let end = match &keyword {
+        // 0xff sorts after any UTF-8 byte, so this covers every element with the prefix
+        Some(keyword) => {
+            let mut end = format!("[{keyword}").into_bytes();
+            end.push(0xff);
+            end
+        }
+        None => b"+".to_vec(),
+    };
+
+    let result: redis::RedisResult<Vec<String>> = cmd("VRANGE")
+        .arg(key)
+        .arg(start)
+        .arg(end)
+        .arg(count)
+        .query_async(conn)
+        .await;
+    let (names, done) = match result {
+        Ok(names) => {
+            let done = names.len() < count;
+            (names, done)
+        }
+        Err(e) if last.is_none() && is_unknown_command(&e) => {
+            let names: Vec<String> = cmd("VRANDMEMBER")
+                .arg(key)
+                .arg(VECTORSET_RANDOM_MAX)
+                .query_async(conn)
+                .await?;
+            let mut names: Vec<String> = names
+                .into_iter()
+                .filter(|name| keyword.as_ref().is_none_or(|kw| name.starts_with(kw.as_str())))
+                .collect();
+            names.sort();
+            (names, true)
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let elements = get_redis_vectorset_elements(conn, key, names).await?;
+    Ok((elements, done))
+}
+
+/// Performs initial load of a Redis Vector Set value.
+///
+/// Fetches VCARD, VDIM and VINFO, then loads the first page of elements.
+///
+/// # Arguments
+/// * `conn` - Redis async connection
+/// * `key` - The vector set key to load
+///
+/// # Returns
+/// A `RedisValue` containing vector set metadata and the initial elements
+pub(crate) async fn first_load_vectorset_value(conn: &mut RedisAsyncConn, key: &RedisKey) -> Result<RedisValue> {
+    let (size, dim, raw_info): (usize, usize, Vec<(String, Value)>) = pipe()
+        .cmd("VCARD")
+        .arg(key)
+        .cmd("VDIM")
+        .arg(key)
+        .cmd("VINFO")
+        .arg(key)
+        .query_async(conn)
+        .await?;
+    let info = raw_info
+        .iter()
+        .map(|(name, value)| (name.clone().into(), redis_value_to_string(value).into()))
+        .collect();
+
+    let (values, done) = get_redis_vectorset_value(conn, key, None, None, VECTORSET_PAGE_SIZE).await?;
+
+    Ok(RedisValue {
+        key_type: KeyType::Vectorset,
+        data: Some(RedisValueData::Vectorset(Arc::new(RedisVectorsetValue {
+            size,
+            dim,
+            info,
+            values,
+            done,
+            ..Default::default()
+        }))),
+        ..Default::default()
+    })
+}
+
+impl ZedisServerState {
+    /// Loads the next page of vector set elements.
+    ///
+    /// # Arguments
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn load_more_vectorset_value(&mut self, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(vectorset) = value.vectorset_value() else {
+            return;
+        };
+        if vectorset.done {
+            return;
+        }
+        let keyword = vectorset.keyword.clone();
+        let last = vectorset.values.last().map(|element| element.name.clone());
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        cx.emit(ServerEvent::ValuePaginationStarted);
+
+        self.spawn(
+            ServerTask::LoadMoreValue,
+            // Async operation: fetch next page using VRANGE
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                get_redis_vectorset_value(&mut conn, &key, keyword, last, VECTORSET_PAGE_SIZE).await
+            },
+            // UI callback: append results into local state
+            move |this, result, cx| {
+                if let Ok((new_values, done)) = result
+                    && let Some(RedisValueData::Vectorset(vectorset_data)) =
+                        this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let vectorset = Arc::make_mut(vectorset_data);
+                    vectorset.done = done;
+                    vectorset.values.extend(new_values);
+                }
+
+                cx.emit(ServerEvent::ValuePaginationFinished);
+
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Filters vector set elements by name prefix and reloads them.
+    ///
+    /// # Arguments
+    /// * `keyword` - Element name prefix (empty to clear the filter)
+    /// * `cx` - GPUI context for UI updates
+    pub fn filter_vectorset_value(&mut self, keyword: SharedString, cx: &mut Context<Self>) {
+        let Some((_, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+        let Some(vectorset) = value.vectorset_value() else {
+            return;
+        };
+
+        let keyword = if keyword.is_empty() { None } else { Some(keyword) };
+        let new_vectorset = RedisVectorsetValue {
+            keyword,
+            size: vectorset.size,
+            dim: vectorset.dim,
+            info: vectorset.info.clone(),
+            similar: vectorset.similar.clone(),
+            ..Default::default()
+        };
+        value.data = Some(RedisValueData::Vectorset(Arc::new(new_vectorset)));
+
+        self.load_more_vectorset_value(cx);
+    }
+    /// Adds an element to the vector set, or updates its vector (VADD).
+    ///
+    /// # Arguments
+    /// * `element` - Element name
+    /// * `vector` - Vector values, must match the dimension of the set
+    /// * `attributes` - Optional JSON attributes (SETATTR)
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn add_vectorset_value(
+        &mut self,
+        element: SharedString,
+        vector: Vec<f64>,
+        attributes: Option<SharedString>,
+        cx: &mut Context<Self>,
+    ) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Updating;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::AddVectorsetValue,
+            // Async operation: execute VADD and read back the new element
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let mut command = cmd("VADD");
+                command.arg(&key).arg("VALUES").arg(vector.len());
+                for v in vector.iter() {
+                    command.arg(*v);
+                }
+                command.arg(element.as_str());
+                if let Some(attributes) = attributes.as_ref() {
+                    command.arg("SETATTR").arg(attributes.as_str());
+                }
+                // VADD returns 1 if the element was added, 0 if it was updated
+                let count: usize = command.query_async(&mut conn).await?;
+                let mut elements = get_redis_vectorset_elements(&mut conn, &key, vec![element.to_string()]).await?;
+                Ok((count, elements.pop()))
+            },
+            // UI callback: update local state
+            move |this, result, cx| {
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                if let Ok((count, element)) = result
+                    && let Some(RedisValueData::Vectorset(vectorset_data)) =
+                        this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    let vectorset = Arc::make_mut(vectorset_data);
+                    vectorset.size += count;
+                    if let Some(element) = element {
+                        if let Some(item) = vectorset.values.iter_mut().find(|item| item.name == element.name) {
+                            *item = element;
+                        } else if vectorset.keyword.is_none() {
+                            // Keep the lexicographic order of the loaded page
+                            let index = vectorset.values.partition_point(|item| item.name < element.name);
+                            if index < vectorset.values.len() || vectorset.done {
+                                vectorset.values.insert(index, element);
+                            }
+                        }
+                    }
+                    let title = i18n_vectorset_editor(cx, "add_value_success");
+                    let msg = if count == 0 {
+                        i18n_vectorset_editor(cx, "update_value_success_tips")
+                    } else {
+                        i18n_vectorset_editor(cx, "add_value_success_tips")
+                    };
+                    this.emit_success_notification(msg, title, cx);
+                    cx.emit(ServerEvent::ValueAdded);
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Removes an element from the vector set (VREM).
+    ///
+    /// # Arguments
+    /// * `element` - Element name to remove
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn remove_vectorset_value(&mut self, element: SharedString, cx: &mut Context<Self>) {
+        if !self.ensure_writable(cx) {
+            return;
+        }
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+        let element_clone = element.clone();
+
+        self.spawn(
+            ServerTask::RemoveVectorsetValue,
+            // Async operation: execute VREM on Redis
+            move || async move {
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let count: usize = cmd("VREM")
+                    .arg(&key)
+                    .arg(element.as_str())
+                    .query_async(&mut conn)
+                    .await?;
+                Ok(count)
+            },
+            // UI callback: update local state to reflect removal
+            move |this, result, cx| {
+                if let Ok(count) = result {
+                    if count != 0
+                        && let Some(RedisValueData::Vectorset(vectorset_data)) =
+                            this.value.as_mut().and_then(|v| v.data.as_mut())
+                    {
+                        let vectorset = Arc::make_mut(vectorset_data);
+                        vectorset.values.retain(|item| item.name != element_clone);
+                        vectorset.size = vectorset.size.saturating_sub(count);
+                    }
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Runs a similarity search (VSIM) and stores the ranked results.
+    ///
+    /// # Arguments
+    /// * `query` - Similarity search options
+    /// * `cx` - GPUI context for spawning async tasks and UI updates
+    pub fn search_vectorset_value(&mut self, query: VectorsetQuery, cx: &mut Context<Self>) {
+        let Some((key, value)) = self.try_get_mut_key_value() else {
+            return;
+        };
+
+        value.status = RedisValueStatus::Loading;
+        cx.notify();
+
+        let server_id = self.server_id.clone();
+        let db = self.db;
+
+        self.spawn(
+            ServerTask::SearchVectorsetValue,
+            // Async operation: execute VSIM on Redis
+            move || async move {
+                let mut command = cmd("VSIM");
+                command.arg(&key);
+                if query.by_element {
+                    command.arg("ELE").arg(query.query.as_str());
+                } else {
+                    let Some(vector) = parse_vector(&query.query) else {
+                        return Err(Error::Invalid {
+                            message: format!("Invalid vector: {}", query.query),
+                        });
+                    };
+                    command.arg("VALUES").arg(vector.len());
+                    for v in vector.iter() {
+                        command.arg(*v);
+                    }
+                }
+                command.arg("WITHSCORES").arg("COUNT").arg(query.count);
+                if let Some(ef) = query.ef {
+                    command.arg("EF").arg(ef);
+                }
+                if let Some(filter) = query.filter.as_ref() {
+                    command.arg("FILTER").arg(filter.as_str());
+                }
+
+                let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
+                let values: Vec<(String, f64)> = command.query_async(&mut conn).await?;
+                Ok(values
+                    .into_iter()
+                    .map(|(name, score)| (name.into(), score))
+                    .collect::<Vec<(SharedString, f64)>>())
+            },
+            // UI callback: store results in local state
+            move |this, result, cx| {
+                if let Ok(similar) = result
+                    && let Some(RedisValueData::Vectorset(vectorset_data)) =
+                        this.value.as_mut().and_then(|v| v.data.as_mut())
+                {
+                    Arc::make_mut(vectorset_data).similar = Some(similar);
+                    cx.emit(ServerEvent::ValueUpdated);
+                }
+                if let Some(value) = this.value.as_mut() {
+                    value.status = RedisValueStatus::Idle;
+                }
+                cx.notify();
+            },
+            cx,
+        );
+    }
+    /// Clears the similarity search results.
+    pub fn clear_vectorset_search(&mut self, cx: &mut Context<Self>) {
+        if let Some(RedisValueData::Vectorset(vectorset_data)) = self.value.as_mut().and_then(|v| v.data.as_mut()) {
+            Arc::make_mut(vectorset_data).similar = None;
+            cx.emit(ServerEvent::ValueUpdated);
+            cx.notify();
+        }
+    }
+}
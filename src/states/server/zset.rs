@@ -21,6 +21,7 @@
 //! - Filtering ZSET members with pattern matching (ZSCAN)
 //! - Support for ascending and descending sort orders
 //! - Efficient incremental loading for large ZSETs
+//! - Geo index positions and searches, see the `geo` module
 
 use super::{
     KeyType, RedisValueData, ServerTask, ZedisServerState,
@@ -29,6 +30,7 @@ use super::{
 use crate::{
     connection::{RedisAsyncConn, get_connection_manager},
     error::Error,
+    helpers::{RedisBytes, RedisKey},
     states::{ServerEvent, i18n_zset_editor},
 };
 use gpui::{SharedString, prelude::*};
@@ -53,11 +55,11 @@ type Result<T, E = Error> = std::result::Result<T, E>;
 /// A vector of (member, score) tuples in the specified sort order
 async fn get_redis_zset_value(
     conn: &mut RedisAsyncConn,
-    key: &str,
+    key: &RedisKey,
     sort_order: SortOrder,
     start: usize,
     stop: usize,
-) -> Result<Vec<(SharedString, f64)>> {
+) -> Result<Vec<(RedisBytes, f64)>> {
     // Choose command based on sort order
     let cmd_name = if sort_order == SortOrder::Asc {
         "ZRANGE"
@@ -74,18 +76,10 @@ async fn get_redis_zset_value(
         .query_async(conn)
         .await?;
 
-    // Early return if no values found
-    if raw_values.is_empty() {
-        return Ok(vec![]);
-    }
-
-    // Convert bytes to UTF-8 strings (lossy conversion for non-UTF8 data)
+    // Keep the raw member bytes, binary members are displayed escaped
     let values = raw_values
-        .iter()
-        .map(|(name, score)| {
-            let name = String::from_utf8_lossy(name).to_string();
-            (name.into(), *score)
-        })
+        .into_iter()
+        .map(|(name, score)| (RedisBytes::from(name), score))
         .collect();
 
     Ok(values)
@@ -107,11 +101,11 @@ async fn get_redis_zset_value(
 /// A tuple of (next_cursor, values) where next_cursor is 0 when scan is complete
 async fn search_redis_zset_value(
     conn: &mut RedisAsyncConn,
-    key: &str,
+    key: &RedisKey,
     cursor: u64,
     pattern: &str,
     count: u64,
-) -> Result<(u64, Vec<(SharedString, f64)>)> {
+) -> Result<(u64, Vec<(RedisBytes, f64)>)> {
     // Execute ZSCAN with MATCH and COUNT options
     let (next_cursor, raw_values): (u64, Vec<Vec<u8>>) = cmd("ZSCAN")
         .arg(key)
@@ -138,14 +132,45 @@ async fn search_redis_zset_value(
         let score_str = String::from_utf8_lossy(score_bytes).to_string();
         let score = score_str.parse::<f64>().unwrap_or_default();
 
-        // Convert member to string
-        let name = String::from_utf8_lossy(member).to_string();
-        values.push((name.into(), score));
+        values.push((RedisBytes::from(member.clone()), score));
     }
 
     Ok((next_cursor, values))
 }
 
+impl RedisZsetValue {
+    /// Updates the score of a loaded member, or inserts a new member at the position of its score.
+    ///
+    /// Returns whether the member was already loaded and whether it was inserted.
+    pub(crate) fn upsert(&mut self, member: RedisBytes, score: f64) -> (bool, bool) {
+        // Check if member already exists and update its score
+        if let Some(item) = self.values.iter_mut().find(|(name, _)| *name == member) {
+            item.1 = score;
+            return (true, false);
+        }
+
+        // Members are not inserted while filtering, they may not match the keyword
+        if self.keyword.is_some() {
+            return (false, false);
+        }
+        // Binary search to find insertion point based on sort order
+        let index = self.values.partition_point(|(_, value)| {
+            if self.sort_order == SortOrder::Asc {
+                *value < score
+            } else {
+                *value > score
+            }
+        });
+
+        // Insert at the found position if not at the end
+        if index == self.values.len() {
+            return (false, false);
+        }
+        self.values.insert(index, (member, score));
+        (false, true)
+    }
+}
+
 /// Performs initial load of a Redis ZSET value.
 ///
 /// Fetches the total cardinality (ZCARD) and loads the first batch of members (up to 100).
@@ -160,7 +185,7 @@ async fn search_redis_zset_value(
 /// A `RedisValue` containing ZSET metadata and initial member/score pairs
 pub(crate) async fn first_load_zset_value(
     conn: &mut RedisAsyncConn,
-    key: &str,
+    key: &RedisKey,
     sort_order: SortOrder,
 ) -> Result<RedisValue> {
     // Get total number of members in the ZSET
@@ -195,20 +220,20 @@ impl ZedisServerState {
     /// * `score` - The score to assign to the member
     /// * `cx` - GPUI context for spawning async tasks and UI updates
     pub fn add_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
-        self.add_or_update_zset_value(new_value, score, cx);
+        self.add_or_update_zset_value(new_value.into(), score, cx);
     }
     /// Updates a member in the Redis ZSET with the specified score.
     ///
     /// Uses ZADD command to update the score of the specified member.
     ///
     /// # Arguments
-    /// * `new_value` - The member name to update
+    /// * `member` - The original member bytes to update
     /// * `score` - The score to assign to the member
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn update_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
-        self.add_or_update_zset_value(new_value, score, cx);
+    pub fn update_zset_value(&mut self, member: RedisBytes, score: f64, cx: &mut Context<Self>) {
+        self.add_or_update_zset_value(member, score, cx);
     }
-    fn add_or_update_zset_value(&mut self, new_value: SharedString, score: f64, cx: &mut Context<Self>) {
+    fn add_or_update_zset_value(&mut self, new_value: RedisBytes, score: f64, cx: &mut Context<Self>) {
         // Early return if no key/value is selected
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
@@ -230,9 +255,9 @@ impl ZedisServerState {
 
                 // ZADD returns number of new elements added (0 if updating existing)
                 let count: usize = cmd("ZADD")
-                    .arg(key.as_str())
+                    .arg(&key)
                     .arg(score)
-                    .arg(new_value.as_str())
+                    .arg(&new_value)
                     .query_async(&mut conn)
                     .await?;
                 Ok(count)
@@ -252,36 +277,7 @@ impl ZedisServerState {
                 {
                     let zset = Arc::make_mut(zset_data);
                     zset.size += count;
-
-                    let mut inserted = false;
-                    let mut exists_value = false;
-
-                    // Check if member already exists and update its score
-                    for item in zset.values.iter_mut() {
-                        if item.0 == new_value_clone {
-                            *item = (new_value_clone.clone(), score);
-                            exists_value = true;
-                            break;
-                        }
-                    }
-
-                    // If member doesn't exist and we're not filtering, insert at correct position
-                    if !exists_value && zset.keyword.is_none() {
-                        // Binary search to find insertion point based on sort order
-                        let index = zset.values.partition_point(|(_, value)| {
-                            if zset.sort_order == SortOrder::Asc {
-                                *value < score
-                            } else {
-                                *value > score
-                            }
-                        });
-
-                        // Insert at the found position if not at the end
-                        if index != zset.values.len() {
-                            zset.values.insert(index, (new_value_clone, score));
-                            inserted = true;
-                        }
-                    }
+                    let (exists_value, inserted) = zset.upsert(new_value_clone, score);
 
                     cx.emit(ServerEvent::ValueAdded);
 
@@ -440,7 +436,7 @@ impl ZedisServerState {
     /// # Arguments
     /// * `remove_value` - The member name to remove from the ZSET
     /// * `cx` - GPUI context for spawning async tasks and UI updates
-    pub fn remove_zset_value(&mut self, remove_value: SharedString, cx: &mut Context<Self>) {
+    pub fn remove_zset_value(&mut self, remove_value: RedisBytes, cx: &mut Context<Self>) {
         let Some((key, value)) = self.try_get_mut_key_value() else {
             return;
         };
@@ -460,11 +456,7 @@ impl ZedisServerState {
                 let mut conn = get_connection_manager().get_connection(&server_id, db).await?;
 
                 // ZREM removes the member and returns success
-                let _: () = cmd("ZREM")
-                    .arg(key.as_str())
-                    .arg(remove_value.as_str())
-                    .query_async(&mut conn)
-                    .await?;
+                let _: () = cmd("ZREM").arg(&key).arg(&remove_value).query_async(&mut conn).await?;
                 Ok(())
             },
             // UI callback: update local state to reflect removal
@@ -477,6 +469,13 @@ impl ZedisServerState {
                     // Remove from local values list
                     zset.values.retain(|(name, _)| name != &remove_value_clone);
                     zset.size -= 1;
+                    if let Some(geo) = zset.geo.as_mut() {
+                        let geo = Arc::make_mut(geo);
+                        geo.members.retain(|item| item.member != remove_value_clone);
+                        if let Some(search) = geo.search.as_mut() {
+                            search.retain(|item| item.member != remove_value_clone);
+                        }
+                    }
                 }
 
                 cx.emit(ServerEvent::ValueUpdated);
@@ -13,35 +13,59 @@
 // limitations under the License.
 
 mod about;
+mod avro_editor;
+mod bitmap_editor;
 mod bytes_editor;
 mod content;
+mod decoder_rule_editor;
 mod editor;
+mod geo_editor;
 mod hash_editor;
+mod hyperloglog_editor;
+mod json_table;
 mod key_tree;
 mod kv_table;
 mod list_editor;
+mod probabilistic_editor;
 mod proto_editor;
 mod servers;
 mod set_editor;
 mod setting_editor;
 mod sidebar;
 mod status_bar;
+mod stream_editor;
+mod stream_group_editor;
+mod timeseries_editor;
 mod title_bar;
+mod tree_editor;
+mod vectorset_editor;
 mod zset_editor;
 
 pub use about::open_about_window;
+pub use avro_editor::ZedisAvroEditor;
+pub use bitmap_editor::ZedisBitmapEditor;
 pub use bytes_editor::ZedisBytesEditor;
 pub use content::ZedisContent;
+pub use decoder_rule_editor::ZedisDecoderRuleEditor;
 pub use editor::ZedisEditor;
+pub use geo_editor::ZedisGeoEditor;
 pub use hash_editor::ZedisHashEditor;
+pub use hyperloglog_editor::ZedisHyperLogLogEditor;
+pub use json_table::{ZedisJsonTable, is_json_table};
 pub use key_tree::ZedisKeyTree;
 pub use kv_table::{KvTableColumn, KvTableColumnType, ZedisKvTable};
 pub use list_editor::ZedisListEditor;
+pub use probabilistic_editor::ZedisProbabilisticEditor;
 pub use proto_editor::ZedisProtoEditor;
 pub use servers::ZedisServers;
 pub use set_editor::ZedisSetEditor;
 pub use setting_editor::ZedisSettingEditor;
 pub use sidebar::ZedisSidebar;
 pub use status_bar::ZedisStatusBar;
+pub use stream_editor::ZedisStreamEditor;
+pub use stream_group_editor::ZedisStreamGroupEditor;
+pub use timeseries_editor::ZedisTimeseriesEditor;
 pub use title_bar::ZedisTitleBar;
+pub use tree_editor::ZedisTreeEditor;
+pub use vectorset_editor::ZedisVectorsetEditor;
 pub use zset_editor::ZedisZsetEditor;
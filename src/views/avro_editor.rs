@@ -0,0 +1,682 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Avro schema settings UI component.
+//!
+//! Avro schemas decode the values of the keys matching their pattern:
+//! - Lists the schemas in a table with edit and delete actions
+//! - Edits the server, name, match pattern and match mode of a schema
+//! - Edits the schema JSON (or `.avsc` file path) and the optional schema registry id
+
+use crate::assets::CustomIconName;
+use crate::connection::get_servers;
+use crate::db::{AvroConfig, AvroManager};
+use crate::error::Error;
+use crate::helpers::get_font_family;
+use crate::states::i18n_avro_editor;
+use crate::states::{ZedisGlobalStore, ZedisServerState, dialog_button_props};
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::label::Label;
+use gpui_component::radio::RadioGroup;
+use gpui_component::table::{Column, Table, TableDelegate, TableState};
+use gpui_component::{IconName, h_flex};
+use gpui_component::{
+    IndexPath, WindowExt,
+    alert::Alert,
+    form::{field, v_form},
+    input::{Input, InputEvent, InputState},
+    select::{Select, SelectEvent, SelectItem, SelectState},
+    text::TextView,
+    v_flex,
+};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::error;
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+struct KeyValueOption {
+    key: SharedString,
+    value: SharedString,
+}
+
+impl KeyValueOption {
+    pub fn new(key: SharedString, value: SharedString) -> Self {
+        Self { key, value }
+    }
+}
+impl SelectItem for KeyValueOption {
+    type Value = SharedString;
+    fn title(&self) -> SharedString {
+        self.key.clone()
+    }
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+}
+
+type OnAvroAction = Arc<dyn Fn(usize, &mut Window, &mut Context<TableState<AvroTableDelegate>>) + Send + Sync>;
+
+struct AvroTableDelegate {
+    data: Arc<Vec<(String, AvroConfig)>>,
+    columns: Vec<Column>,
+    servers: Vec<KeyValueOption>,
+    on_edit: OnAvroAction,
+    on_delete: OnAvroAction,
+}
+
+impl AvroTableDelegate {
+    fn new<F1, F2>(
+        data: Arc<Vec<(String, AvroConfig)>>,
+        servers: Vec<KeyValueOption>,
+        columns: Vec<Column>,
+        on_edit: F1,
+        on_delete: F2,
+    ) -> Self
+    where
+        F1: Fn(usize, &mut Window, &mut Context<TableState<AvroTableDelegate>>) + Send + Sync + 'static,
+        F2: Fn(usize, &mut Window, &mut Context<TableState<AvroTableDelegate>>) + Send + Sync + 'static,
+    {
+        Self {
+            data,
+            columns,
+            servers,
+            on_edit: Arc::new(on_edit),
+            on_delete: Arc::new(on_delete),
+        }
+    }
+}
+
+impl TableDelegate for AvroTableDelegate {
+    fn columns_count(&self, _: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _: &App) -> usize {
+        self.data.len()
+    }
+
+    fn column(&self, index: usize, _: &App) -> &Column {
+        &self.columns[index]
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let avro = self.data.get(row_ix);
+        if col_ix == self.columns_count(cx) - 1 {
+            let on_edit = self.on_edit.clone();
+            let on_delete = self.on_delete.clone();
+            return div().size_full().flex().items_center().child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("edit-avro-btn")
+                            .icon(CustomIconName::FilePenLine)
+                            .ghost()
+                            .on_click(cx.listener(move |_this, _, window, cx| {
+                                (on_edit)(row_ix, window, cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("delete-avro-btn")
+                            .icon(CustomIconName::X)
+                            .ghost()
+                            .on_click(cx.listener(move |_this, _, window, cx| {
+                                (on_delete)(row_ix, window, cx);
+                            })),
+                    ),
+            );
+        }
+
+        let text = if let Some((_, avro)) = avro {
+            match col_ix {
+                0 => {
+                    // Convert server_id to server_name
+                    self.servers
+                        .iter()
+                        .find(|s| s.value.as_ref() == avro.server_id)
+                        .map(|s| s.key.to_string())
+                        .unwrap_or_else(|| avro.server_id.clone())
+                }
+                1 => avro.name.clone(),
+                2 => avro.match_pattern.clone(),
+                3 => format!("{:?}", avro.mode),
+                4 => avro.schema_id.map(|id| id.to_string()).unwrap_or_default(),
+                _ => String::new(),
+            }
+        } else {
+            String::new()
+        };
+
+        div().size_full().flex().items_center().child(Label::new(text))
+    }
+}
+
+enum EditorMode {
+    Table,
+    Edit,
+}
+
+pub struct ZedisAvroEditor {
+    server_select_state: Entity<SelectState<Vec<KeyValueOption>>>,
+    name_state: Entity<InputState>,
+    match_pattern_state: Entity<InputState>,
+    match_mode_select_state: Entity<usize>,
+    schema_id_state: Entity<InputState>,
+    schema_state: Entity<InputState>,
+    field_errors: Entity<HashMap<String, SharedString>>,
+
+    avros: Arc<Vec<(String, AvroConfig)>>,
+    servers: Vec<KeyValueOption>,
+    server_id: SharedString,
+    edit_avro_id: Option<String>,
+    editor_mode: EditorMode,
+    table_state: Entity<TableState<AvroTableDelegate>>,
+    needs_table_recreate: Option<bool>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisAvroEditor {
+    fn create_table_state(
+        avros: Arc<Vec<(String, AvroConfig)>>,
+        servers: Vec<KeyValueOption>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<TableState<AvroTableDelegate>> {
+        let view_update_entity = cx.entity();
+        let view_delete_entity = cx.entity();
+
+        let on_edit = move |row_ix: usize, window: &mut Window, cx: &mut Context<TableState<AvroTableDelegate>>| {
+            view_update_entity.update(cx, |this, cx| {
+                this.handle_update(row_ix, window, cx);
+            });
+        };
+
+        let on_delete = move |row_ix: usize, window: &mut Window, cx: &mut Context<TableState<AvroTableDelegate>>| {
+            view_delete_entity.update(cx, |this, cx| {
+                this.handle_delete(row_ix, window, cx);
+            });
+        };
+        let columns = vec![
+            Column::new("server_name", i18n_avro_editor(cx, "server_name")).width(px(150.)),
+            Column::new("name", i18n_avro_editor(cx, "name")).width(px(150.)),
+            Column::new("match_pattern", i18n_avro_editor(cx, "match_pattern")).width(px(200.)),
+            Column::new("mode", i18n_avro_editor(cx, "mode")).width(px(100.)),
+            Column::new("schema_id", i18n_avro_editor(cx, "schema_id")).width(px(120.)),
+            Column::new("actions", i18n_avro_editor(cx, "actions")).width(px(150.)),
+        ];
+
+        let delegate = AvroTableDelegate::new(avros, servers, columns, on_edit, on_delete);
+        cx.new(|cx| TableState::new(delegate, window, cx))
+    }
+
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let server_id = server_state.read(cx).server_id().to_string();
+        let avros = AvroManager::list_avros_with_id();
+        let mut subscriptions = Vec::new();
+        let servers = get_servers()
+            .unwrap_or_default()
+            .iter()
+            .map(|server| KeyValueOption::new(server.name.clone().into(), server.id.clone().into()))
+            .collect::<Vec<_>>();
+        let name_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_avro_editor(cx, "name_placeholder"))
+        });
+        let match_pattern_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_avro_editor(cx, "match_pattern_placeholder"))
+        });
+        let schema_id_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_avro_editor(cx, "schema_id_placeholder"))
+        });
+        let schema_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_avro_editor(cx, "schema_placeholder"))
+                .auto_grow(2, 10)
+        });
+        let match_mode_select_state = cx.new(|_cx| 0_usize);
+        let found = servers
+            .iter()
+            .position(|item| item.value == server_id)
+            .map(IndexPath::new);
+        let servers_for_delegate = servers.clone();
+        let server_select_state = cx.new(|cx| SelectState::new(servers, found, window, cx));
+        let field_errors = cx.new(|_cx| HashMap::new());
+
+        let field_errors_clone = field_errors.clone();
+        subscriptions.push(cx.subscribe(&server_select_state, move |this, view, event, cx| {
+            if let SelectEvent::Confirm(Some(server_id)) = event {
+                this.server_id = server_id.clone();
+                let id = view.entity_id().to_string();
+                if field_errors_clone.read(cx).get(&id).is_some() {
+                    field_errors_clone.update(cx, |state, _cx| {
+                        state.remove(&id);
+                    });
+                }
+            }
+        }));
+        for item in [
+            name_state.clone(),
+            match_pattern_state.clone(),
+            schema_id_state.clone(),
+            schema_state.clone(),
+        ] {
+            subscriptions.push(
+                cx.subscribe_in(&item.clone(), window, move |view, state, event, _window, cx| {
+                    if let InputEvent::Blur = event {
+                        let id = state.entity_id().to_string();
+                        if view.field_errors.read(cx).get(&id).is_some() {
+                            view.field_errors.update(cx, |state, _cx| {
+                                state.remove(&id);
+                            });
+                        }
+                    }
+                }),
+            );
+        }
+
+        let avros = Arc::new(avros);
+        let table_state = Self::create_table_state(avros.clone(), servers_for_delegate.clone(), window, cx);
+
+        Self {
+            server_select_state,
+            name_state,
+            match_pattern_state,
+            match_mode_select_state,
+            schema_id_state,
+            schema_state,
+            editor_mode: EditorMode::Table,
+            table_state,
+            avros,
+            servers: servers_for_delegate,
+            server_id: server_id.into(),
+            needs_table_recreate: None,
+            edit_avro_id: None,
+            field_errors,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    fn handle_save(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let name = self.name_state.read(cx).value();
+        let match_pattern = self.match_pattern_state.read(cx).value();
+        let match_mode = self.match_mode_select_state.read(cx).to_owned();
+        let schema_id = self.schema_id_state.read(cx).value();
+        let schema = self.schema_state.read(cx).value();
+        let field_errors = self.field_errors.clone();
+        field_errors.update(cx, |state, _cx| {
+            state.clear();
+        });
+
+        if server_id.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(
+                    self.server_select_state.entity_id().to_string(),
+                    "server is required".into(),
+                );
+            });
+        }
+        if name.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(self.name_state.entity_id().to_string(), "name is required".into());
+            });
+        }
+        if match_pattern.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(
+                    self.match_pattern_state.entity_id().to_string(),
+                    "match pattern is required".into(),
+                );
+            });
+        }
+        let schema_id = if schema_id.trim().is_empty() {
+            None
+        } else if let Ok(schema_id) = schema_id.trim().parse::<u32>() {
+            Some(schema_id)
+        } else {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(
+                    self.schema_id_state.entity_id().to_string(),
+                    "schema id must be a positive integer".into(),
+                );
+            });
+            None
+        };
+        if schema.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(self.schema_state.entity_id().to_string(), "schema is required".into());
+            });
+        }
+        if !field_errors.read(cx).is_empty() {
+            return;
+        }
+
+        let id = self.edit_avro_id.clone().unwrap_or_else(|| Uuid::now_v7().to_string());
+        let config = AvroConfig {
+            server_id: server_id.to_string(),
+            name: name.to_string(),
+            match_pattern: match_pattern.to_string(),
+            mode: match_mode.into(),
+            schema_id,
+            schema: Some(schema.to_string()),
+        };
+        let schema_field_id = self.schema_state.entity_id().to_string();
+        cx.spawn(async move |handle, cx| {
+            let result: Result<(String, AvroConfig), Error> = cx
+                .background_spawn(async move {
+                    // Validate the schema before it is saved
+                    AvroManager::parse_schema(config.schema.as_deref().unwrap_or_default())?;
+                    AvroManager::upsert_avro(&id, config.clone())?;
+                    Ok((id.to_string(), config))
+                })
+                .await;
+            match result {
+                Ok((id, config)) => {
+                    let _ = handle.update(cx, |this, cx| {
+                        // Update avros: replace if exists, otherwise add new
+                        let mut new_avros = this.avros.as_ref().clone();
+                        if let Some(pos) = new_avros.iter().position(|(existing_id, _)| existing_id == &id) {
+                            new_avros[pos] = (id, config);
+                        } else {
+                            new_avros.push((id, config));
+                        }
+                        this.avros = Arc::new(new_avros);
+
+                        // Mark for recreation of table on next render
+                        this.needs_table_recreate = Some(true);
+                        this.editor_mode = EditorMode::Table;
+                        cx.notify();
+                    });
+                }
+                Err(e) => {
+                    error!(error = %e, "save avro schema fail",);
+                    let _ = handle.update(cx, |this, cx| {
+                        this.field_errors.update(cx, |state, _cx| {
+                            state.insert(schema_field_id, e.to_string().into());
+                        });
+                        cx.notify();
+                    });
+                }
+            }
+        })
+        .detach();
+    }
+    fn reset_form(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.edit_avro_id = None;
+        for state in [
+            self.name_state.clone(),
+            self.match_pattern_state.clone(),
+            self.schema_id_state.clone(),
+            self.schema_state.clone(),
+        ] {
+            state.update(cx, |state, cx| {
+                state.set_value(String::new(), window, cx);
+            });
+        }
+        self.match_mode_select_state.update(cx, |state, _cx| {
+            *state = 0;
+        });
+    }
+    fn handle_update(&mut self, row_ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((id, _)) = self.avros.get(row_ix) else {
+            return;
+        };
+        let Ok(avro) = AvroManager::get_avro(id) else {
+            return;
+        };
+        self.edit_avro_id = Some(id.clone());
+        let selected_index = self
+            .servers
+            .iter()
+            .position(|s| s.value == avro.server_id)
+            .map(IndexPath::new);
+        self.server_id = avro.server_id.clone().into();
+        self.server_select_state.update(cx, |state, cx| {
+            state.set_selected_index(selected_index, window, cx);
+        });
+        self.name_state.update(cx, |state, cx| {
+            state.set_value(avro.name.clone(), window, cx);
+        });
+        self.match_pattern_state.update(cx, |state, cx| {
+            state.set_value(avro.match_pattern.clone(), window, cx);
+        });
+        self.match_mode_select_state.update(cx, |state, _cx| {
+            *state = avro.mode.clone().into();
+        });
+        let schema_id = avro.schema_id.map(|id| id.to_string()).unwrap_or_default();
+        self.schema_id_state.update(cx, |state, cx| {
+            state.set_value(schema_id, window, cx);
+        });
+        let schema = avro.schema.clone().unwrap_or_default();
+        self.schema_state.update(cx, |state, cx| {
+            state.set_value(schema, window, cx);
+        });
+        self.editor_mode = EditorMode::Edit;
+        cx.notify();
+    }
+    fn handle_delete(&mut self, row_ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((id, avro)) = self.avros.get(row_ix) else {
+            return;
+        };
+        let name = avro.name.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let text = t!("avro_editor.remove_avro_prompt", name = name, locale = locale).to_string();
+
+        let id = id.to_string();
+        let view_handle = cx.entity();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let id = id.clone();
+            let view_handle = view_handle.clone();
+            dialog
+                .confirm()
+                .button_props(dialog_button_props(cx))
+                .child(text.clone())
+                .on_ok(move |_, _window, cx| {
+                    let id = id.clone();
+                    let view_handle = view_handle.clone();
+                    cx.spawn(async move |cx| {
+                        let result: Result<String, Error> = cx
+                            .background_spawn({
+                                let id = id.clone();
+                                async move {
+                                    AvroManager::delete_avro(&id)?;
+                                    Ok(id)
+                                }
+                            })
+                            .await;
+                        match result {
+                            Ok(deleted_id) => {
+                                let _ = view_handle.update(cx, |this, cx| {
+                                    // Remove deleted schema from the list
+                                    let new_avros: Vec<_> =
+                                        this.avros.iter().filter(|(id, _)| id != &deleted_id).cloned().collect();
+                                    this.avros = Arc::new(new_avros);
+
+                                    // Mark for recreation of table on next render
+                                    this.needs_table_recreate = Some(true);
+                                    cx.notify();
+                                });
+                            }
+                            Err(e) => {
+                                error!(error = %e, "delete avro schema fail",);
+                            }
+                        }
+                    })
+                    .detach();
+                    true
+                })
+        });
+    }
+    fn render_edit_form(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let match_mode_select_state_clone = self.match_mode_select_state.clone();
+        let match_mode_select_state = self.match_mode_select_state.read(cx);
+        v_flex()
+            .p_5()
+            .size_full()
+            .gap_3()
+            .child(
+                v_form()
+                    .w_full()
+                    .columns(2)
+                    .child(
+                        field()
+                            .label(i18n_avro_editor(cx, "server_name"))
+                            .required(true)
+                            .child(Select::new(&self.server_select_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_avro_editor(cx, "name"))
+                            .required(true)
+                            .child(Input::new(&self.name_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_avro_editor(cx, "match_pattern"))
+                            .required(true)
+                            .child(Input::new(&self.match_pattern_state)),
+                    )
+                    .child(
+                        field().label(i18n_avro_editor(cx, "mode")).required(true).child(
+                            RadioGroup::horizontal("avro-match-mode-group")
+                                .mt(px(8.))
+                                .children(vec!["Prefix", "Suffix", "Regex", "Exact"])
+                                .selected_index(Some(*match_mode_select_state))
+                                .on_click(move |index, _, cx| {
+                                    match_mode_select_state_clone.update(cx, |state, _cx| {
+                                        *state = *index;
+                                    });
+                                }),
+                        ),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_avro_editor(cx, "schema_id"))
+                            .description(i18n_avro_editor(cx, "schema_id_description"))
+                            .child(Input::new(&self.schema_id_state)),
+                    )
+                    .child(
+                        field()
+                            .col_span(2)
+                            .label(i18n_avro_editor(cx, "schema"))
+                            .required(true)
+                            .child(Input::new(&self.schema_state).w_full().font_family(get_font_family())),
+                    ),
+            )
+            .child(v_flex().w_full().flex_1().h_full())
+            .when(!self.field_errors.read(cx).is_empty(), |this| {
+                let title = i18n_avro_editor(cx, "field_errors_title");
+                let list = self
+                    .field_errors
+                    .read(cx)
+                    .values()
+                    .map(|value| format!("- {value}"))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                let markdown = t!("avro_editor.field_errors_message", errors = list);
+                this.child(
+                    Alert::error(
+                        "avro-editor-form-errors",
+                        TextView::markdown("avro-editor-form-errors-message", markdown, window, cx),
+                    )
+                    .title(title)
+                    .mt_4(),
+                )
+            })
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        Button::new("avro-editor-btn-cancel")
+                            .icon(IconName::CircleX)
+                            .label(i18n_avro_editor(cx, "cancel"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.editor_mode = EditorMode::Table;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("avro-editor-btn-save")
+                            .primary()
+                            .icon(CustomIconName::Save)
+                            .label(i18n_avro_editor(cx, "save"))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.handle_save(window, cx);
+                            })),
+                    ),
+            )
+    }
+    fn render_table_view(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if let Some(true) = self.needs_table_recreate.take() {
+            self.table_state = Self::create_table_state(self.avros.clone(), self.servers.clone(), window, cx);
+        }
+        v_flex()
+            .size_full()
+            .p_5()
+            .gap_3()
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .child(Label::new(i18n_avro_editor(cx, "title")).text_xl()),
+            )
+            .child(
+                div().flex_1().w_full().child(
+                    Table::new(&self.table_state)
+                        .stripe(true)
+                        .bordered(true)
+                        .scrollbar_visible(true, true),
+                ),
+            )
+            .child(
+                h_flex().w_full().justify_end().p_2().child(
+                    Button::new("add-avro-bottom-btn")
+                        .primary()
+                        .icon(CustomIconName::FilePlusCorner)
+                        .label(i18n_avro_editor(cx, "add"))
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.reset_form(window, cx);
+                            this.editor_mode = EditorMode::Edit;
+                            cx.notify();
+                        })),
+                ),
+            )
+            .into_any_element()
+    }
+}
+
+impl Render for ZedisAvroEditor {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        match self.editor_mode {
+            EditorMode::Table => self.render_table_view(window, cx).into_any_element(),
+            EditorMode::Edit => self.render_edit_form(window, cx).into_any_element(),
+        }
+    }
+}
@@ -0,0 +1,383 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bitmap view of string values.
+//!
+//! This module shows a string used as a bitmap bit by bit. It supports:
+//! - Summaries of the set bits (BITCOUNT, BITPOS 1, BITPOS 0)
+//! - A bit grid with offsets, clicking a bit toggles it (SETBIT)
+//! - Reading and writing typed integer fields (BITFIELD GET/SET)
+
+use crate::helpers::get_font_family;
+use crate::states::{
+    BITFIELD_ENCODINGS, BitfieldOperation, ServerEvent, ZedisServerState, i18n_bitmap_editor, i18n_common,
+};
+use bytes::Bytes;
+use gpui::{App, Entity, MouseButton, SharedString, Subscription, Window, div, hsla, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, IndexPath, Sizable,
+    button::Button,
+    h_flex,
+    input::{Input, InputState},
+    label::Label,
+    list::{List, ListDelegate, ListItem, ListState},
+    select::{Select, SelectState},
+    v_flex,
+};
+
+/// Bytes per row of the bit grid
+const BITMAP_ROW_BYTES: usize = 8;
+/// Size of a bit cell
+const BIT_CELL_SIZE: f32 = 14.;
+/// Width of the name column of the summary and result lists
+const NAME_COLUMN_WIDTH: f32 = 220.;
+
+/// Bit grid of the loaded bytes, bit 0 is the most significant bit of the first byte
+struct BitmapListDelegate {
+    /// Byte offset of the first byte, the page offset of a large value
+    offset: usize,
+    bytes: Bytes,
+    /// Whether clicking a bit toggles it, false for readonly connections
+    editable: bool,
+    server_state: Entity<ZedisServerState>,
+    selected_index: Option<IndexPath>,
+}
+
+impl ListDelegate for BitmapListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _section: usize, _cx: &App) -> usize {
+        self.bytes.len().div_ceil(BITMAP_ROW_BYTES)
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        _window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> Option<Self::Item> {
+        let offset_color = if cx.theme().is_dark() {
+            hsla(0.108, 0.66, 0.69, 1.0)
+        } else {
+            hsla(0.0892, 0.9462, 0.4373, 1.0)
+        };
+        let start = ix.row * BITMAP_ROW_BYTES;
+        let end = (start + BITMAP_ROW_BYTES).min(self.bytes.len());
+        if start >= end {
+            return None;
+        }
+        let mut row = h_flex().gap_2();
+        for index in start..end {
+            let byte = self.bytes[index];
+            let mut cells = h_flex().gap_px();
+            for bit in 0..8 {
+                let offset = (self.offset + index) * 8 + bit;
+                let set = byte & (0x80 >> bit) != 0;
+                let mut cell = div()
+                    .id(("bitmap-bit", offset))
+                    .size(px(BIT_CELL_SIZE))
+                    .rounded_sm()
+                    .border_1()
+                    .border_color(cx.theme().border);
+                if set {
+                    cell = cell.bg(cx.theme().primary);
+                }
+                if self.editable {
+                    let server_state = self.server_state.clone();
+                    cell = cell
+                        .cursor_pointer()
+                        .on_mouse_down(MouseButton::Left, move |_, _window, cx| {
+                            server_state.update(cx, |state, cx| {
+                                state.toggle_bit(offset, cx);
+                            });
+                        });
+                }
+                cells = cells.child(cell);
+            }
+            row = row.child(cells);
+        }
+        Some(
+            ListItem::new(ix).py_0p5().px_2().child(
+                h_flex()
+                    .child(
+                        Label::new(format!("{:>10}", (self.offset + start) * 8))
+                            .text_color(offset_color)
+                            .mr_4(),
+                    )
+                    .child(row),
+            ),
+        )
+    }
+
+    fn set_selected_index(&mut self, ix: Option<IndexPath>, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
+        self.selected_index = ix;
+    }
+}
+
+/// Bitmap view of a string value.
+///
+/// Shows the bit summaries, the BITFIELD panel and the bit grid of the
+/// loaded bytes, the page of a large value.
+pub struct ZedisBitmapEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// State of the bit grid, its delegate is replaced when the value changes
+    bits_state: Entity<ListState<BitmapListDelegate>>,
+    /// Integer encoding of the BITFIELD field
+    encoding_state: Entity<SelectState<Vec<String>>>,
+    /// Input for the bit offset of the BITFIELD field
+    offset_state: Entity<InputState>,
+    /// Input for the value written by BITFIELD SET
+    value_state: Entity<InputState>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisBitmapEditor {
+    /// Creates a new bitmap view instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let delegate = Self::new_delegate(&server_state, cx);
+        let bits_state = cx.new(|cx| ListState::new(delegate, window, cx));
+        let encodings = BITFIELD_ENCODINGS.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let encoding_state = cx.new(|cx| SelectState::new(encodings, Some(IndexPath::new(0)), window, cx));
+        let offset_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_bitmap_editor(cx, "offset_placeholder"))
+        });
+        let value_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .validate(|s, _| s.is_empty() || s == "-" || s.parse::<i64>().is_ok())
+                .placeholder(i18n_bitmap_editor(cx, "value_placeholder"))
+        });
+
+        let mut subscriptions = vec![];
+        subscriptions.push(cx.subscribe(&server_state, |this, _, event, cx| {
+            if matches!(
+                event,
+                ServerEvent::ValueLoaded | ServerEvent::ValueUpdated | ServerEvent::ValueModeViewUpdated
+            ) {
+                this.update_bits(cx);
+            }
+        }));
+
+        Self {
+            server_state,
+            bits_state,
+            encoding_state,
+            offset_state,
+            value_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Creates the bit grid of the loaded bytes of the current value.
+    fn new_delegate(server_state: &Entity<ZedisServerState>, cx: &App) -> BitmapListDelegate {
+        let state = server_state.read(cx);
+        let (offset, bytes) = state
+            .value()
+            .and_then(|v| v.bytes_value())
+            .map(|v| {
+                let (offset, bytes) = v.hex_page();
+                (offset, bytes.clone())
+            })
+            .unwrap_or_default();
+        BitmapListDelegate {
+            offset,
+            bytes,
+            editable: !state.readonly(),
+            server_state: server_state.clone(),
+            selected_index: None,
+        }
+    }
+
+    /// Replaces the bit grid, the delegate is swapped to keep the scroll position.
+    fn update_bits(&mut self, cx: &mut Context<Self>) {
+        let delegate = Self::new_delegate(&self.server_state, cx);
+        self.bits_state.update(cx, |state, cx| {
+            *state.delegate_mut() = delegate;
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Runs BITFIELD GET or SET with the selected encoding and the inputs.
+    fn handle_bitfield(&mut self, operation: BitfieldOperation, cx: &mut Context<Self>) {
+        let offset = self.offset_state.read(cx).value();
+        if offset.trim().is_empty() {
+            return;
+        }
+        let value = self.value_state.read(cx).value();
+        if operation == BitfieldOperation::Set && value.trim().is_empty() {
+            return;
+        }
+        let encoding = self
+            .encoding_state
+            .read(cx)
+            .selected_value()
+            .map(|value| SharedString::from(value.clone()))
+            .unwrap_or_else(|| BITFIELD_ENCODINGS[0].into());
+        self.server_state.update(cx, |state, cx| {
+            state.run_bitfield(operation, encoding, offset, value, cx);
+        });
+    }
+
+    /// Renders name-value rows in a bordered list.
+    fn render_rows(rows: Vec<(SharedString, SharedString)>, cx: &Context<Self>) -> impl IntoElement {
+        let border_color = cx.theme().border;
+        let muted_color = cx.theme().muted_foreground;
+        v_flex()
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .children(rows.into_iter().map(|(name, value)| {
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        Label::new(name)
+                            .text_sm()
+                            .w(px(NAME_COLUMN_WIDTH))
+                            .text_ellipsis()
+                            .text_color(muted_color),
+                    )
+                    .child(Label::new(value).text_sm().flex_1().text_ellipsis())
+            }))
+    }
+
+    /// Renders the BITFIELD inputs and the results of the operations.
+    fn render_bitfield(&self, fields: Vec<(SharedString, SharedString)>, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let readonly = server_state.readonly();
+
+        let toolbar = h_flex()
+            .gap_2()
+            .items_center()
+            .child(div().w(px(100.)).child(Select::new(&self.encoding_state).small()))
+            .child(div().flex_1().child(Input::new(&self.offset_state).small()))
+            .child(div().w(px(160.)).child(Input::new(&self.value_state).small()))
+            .child(
+                Button::new("bitmap-bitfield-get-btn")
+                    .outline()
+                    .small()
+                    .label(i18n_bitmap_editor(cx, "get"))
+                    .tooltip(i18n_bitmap_editor(cx, "get_tooltip"))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_bitfield(BitfieldOperation::Get, cx);
+                    })),
+            )
+            .child(
+                Button::new("bitmap-bitfield-set-btn")
+                    .outline()
+                    .small()
+                    .label(i18n_bitmap_editor(cx, "set"))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_bitmap_editor(cx, "set_tooltip")
+                    })
+                    .disabled(is_busy || readonly)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_bitfield(BitfieldOperation::Set, cx);
+                    })),
+            );
+
+        v_flex()
+            .gap_2()
+            .child(
+                Label::new(i18n_bitmap_editor(cx, "bitfield_title"))
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(toolbar)
+            .when(!fields.is_empty(), |this| this.child(Self::render_rows(fields, cx)))
+    }
+}
+
+impl Render for ZedisBitmapEditor {
+    /// Renders the bit summaries, the BITFIELD panel and the bit grid.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let Some(value) = self.server_state.read(cx).value().and_then(|v| v.bytes_value()) else {
+            return v_flex().into_any_element();
+        };
+        let bitmap = value.bitmap.clone().unwrap_or_default();
+        let position = |pos: i64| -> SharedString {
+            if pos < 0 {
+                i18n_bitmap_editor(cx, "none")
+            } else {
+                pos.to_string().into()
+            }
+        };
+        let summary = if value.bitmap.is_some() {
+            vec![
+                (
+                    i18n_bitmap_editor(cx, "total_bits"),
+                    (value.length * 8).to_string().into(),
+                ),
+                (i18n_bitmap_editor(cx, "bit_count"), bitmap.count.to_string().into()),
+                (i18n_bitmap_editor(cx, "first_set"), position(bitmap.first_set)),
+                (i18n_bitmap_editor(cx, "first_clear"), position(bitmap.first_clear)),
+            ]
+        } else {
+            vec![(
+                i18n_bitmap_editor(cx, "total_bits"),
+                (value.length * 8).to_string().into(),
+            )]
+        };
+        let readonly = self.server_state.read(cx).readonly();
+        let tips = if readonly {
+            i18n_bitmap_editor(cx, "bits_readonly_tips")
+        } else {
+            i18n_bitmap_editor(cx, "bits_tips")
+        };
+        let muted_color = cx.theme().muted_foreground;
+
+        v_flex()
+            .size_full()
+            .child(
+                v_flex()
+                    .p_2()
+                    .gap_3()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        Label::new(i18n_bitmap_editor(cx, "summary_title"))
+                            .text_sm()
+                            .text_color(muted_color),
+                    )
+                    .child(Self::render_rows(summary, cx))
+                    .child(self.render_bitfield(bitmap.fields, cx))
+                    .child(Label::new(tips).text_xs().text_color(muted_color)),
+            )
+            .child(
+                div()
+                    .flex_1()
+                    .h_0()
+                    .w_full()
+                    .child(List::new(&self.bits_state).font_family(get_font_family())),
+            )
+            .into_any_element()
+    }
+}
@@ -12,17 +12,32 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::helpers::get_font_family;
-use crate::states::{DataFormat, RedisBytesValue, ServerEvent, ViewMode, ZedisGlobalStore, ZedisServerState};
-use gpui::{App, Entity, Image, ObjectFit, SharedString, Subscription, Window, img, px};
+use crate::assets::CustomIconName;
+use crate::components::{FormDialog, FormField, open_add_form_dialog};
+use crate::helpers::{decode_raw_protobuf, get_font_family};
+use crate::states::{
+    BytesEdit, CompressionFormat, DataFormat, JsonPathOperation, KeyType, RedisBytesValue, STRING_PAGE_SIZE,
+    ServerEvent, ViewMode, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_editor, i18n_json_editor,
+};
+use crate::views::{ZedisBitmapEditor, ZedisHyperLogLogEditor, ZedisJsonTable, ZedisTreeEditor, is_json_table};
+use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use bytes::Bytes;
+use gpui::{
+    App, ClipboardItem, Entity, FocusHandle, Image, KeyDownEvent, MouseButton, MouseDownEvent, ObjectFit, SharedString,
+    Subscription, Window, img, px,
+};
 use gpui::{div, hsla, prelude::*};
+use gpui_component::button::Button;
 use gpui_component::highlighter::Language;
 use gpui_component::input::{Input, InputEvent, InputState, TabSize};
 use gpui_component::label::Label;
 use gpui_component::list::{List, ListDelegate, ListItem, ListState};
-use gpui_component::{ActiveTheme, IndexPath, h_flex};
-use pretty_hex::HexConfig;
-use pretty_hex::config_hex;
+use gpui_component::select::{SearchableVec, Select, SelectEvent, SelectState};
+use gpui_component::{ActiveTheme, Disableable, IconName, IndexPath, Sizable, WindowExt, h_flex, v_flex};
+use humansize::{DECIMAL, format_size};
+use rust_i18n::t;
+use std::ops::Range;
+use std::rc::Rc;
 use std::sync::Arc;
 use tracing::info;
 
@@ -34,6 +49,7 @@ const HEX_WIDTH_MEDIUM: usize = 24; // Bytes per line for medium viewports
 const HEX_WIDTH_WIDE: usize = 32; // Bytes per line for wide viewports
 const VIEWPORT_WIDE: f32 = 1400.0; // Pixel width to switch hex display width
 const VIEWPORT_MEDIUM: f32 = 1000.0; // Pixel width to switch hex display width
+const COMPRESSION_SELECT_WIDTH: f32 = 120.0;
 
 /// String value editor component for Redis String data type
 ///
@@ -43,7 +59,16 @@ const VIEWPORT_MEDIUM: f32 = 1000.0; // Pixel width to switch hex display width
 /// - Search functionality
 /// - Soft wrap support
 /// - Automatic hex display for binary data
+/// - Schema-less protobuf field tree view
 /// - Tracks modification state
+/// - Path-level edits for RedisJSON documents
+/// - Re-compression of decompressed values on save, with a codec selector
+/// - Prefix view of large values, with chunked loading and a paged hex view
+/// - Hex grid editing in overwrite and insert modes, with byte range selection and copy
+/// - Bitmap view with SETBIT toggling and BITFIELD fields
+/// - HyperLogLog view with the cardinality, PFADD and PFMERGE
+/// - Collapsible tree view of structured values, with a JSONPath filter
+/// - Sortable table view of JSON arrays of objects, with CSV and JSON export
 pub struct ZedisBytesEditor {
     /// Reference to server state for Redis operations
     server_state: Entity<ZedisServerState>,
@@ -54,6 +79,24 @@ pub struct ZedisBytesEditor {
     /// State for hex viewer list
     hex_viewer_state: Option<Entity<ListState<HexViewerListDelegate>>>,
 
+    /// Focus handle of the hex grid, receiving the typed hex digits
+    hex_focus_handle: FocusHandle,
+
+    /// Flag indicating if the bytes of the hex grid have been edited
+    hex_modified: bool,
+
+    /// Bitmap view, created when the value is first shown as a bitmap
+    bitmap_editor: Option<Entity<ZedisBitmapEditor>>,
+
+    /// HyperLogLog view, created when a HyperLogLog is first shown
+    hyperloglog_editor: Option<Entity<ZedisHyperLogLogEditor>>,
+
+    /// Tree view, created when a structured value is first shown as a tree
+    tree_editor: Option<Entity<ZedisTreeEditor>>,
+
+    /// Table view, created when an array of objects is first shown as a table
+    json_table: Option<Entity<ZedisJsonTable>>,
+
     /// Code editor state with input handling
     editor: Entity<InputState>,
 
@@ -63,6 +106,24 @@ pub struct ZedisBytesEditor {
     /// Whether the editor is readonly
     readonly: bool,
 
+    /// Whether the value is a RedisJSON document
+    json_key: bool,
+
+    /// Codec the value was decompressed with
+    compression: Option<CompressionFormat>,
+
+    /// Codec selected for saving the value
+    selected_compression: Option<CompressionFormat>,
+
+    /// Codec selector state
+    compression_state: Entity<SelectState<SearchableVec<SharedString>>>,
+
+    /// Whether to reset the codec selector to the codec of the value
+    should_reset_compression: bool,
+
+    /// Error of encoding the edited text back to MessagePack or Protobuf
+    encode_error: Option<SharedString>,
+
     /// Whether to update the editor
     should_update_editor: bool,
 
@@ -80,6 +141,10 @@ enum ByteEditorData {
     Image(Arc<Image>),
     Text(SharedString),
     Hex(HexViewerListDelegate),
+    Bitmap,
+    HyperLogLog,
+    Tree,
+    Table(Arc<serde_json::Value>),
 }
 
 impl ByteEditorData {
@@ -90,56 +155,100 @@ impl ByteEditorData {
         }
     }
 }
+/// Create the hex grid of the bytes with a width based on the viewport size,
+/// addresses start at `offset` for pages of large values
+fn create_hex_view(original: Bytes, offset: usize, resizable: bool, readonly: bool, cx: &App) -> ByteEditorData {
+    let width = cx
+        .global::<ZedisGlobalStore>()
+        .read(cx)
+        .content_width()
+        .unwrap_or_default();
+
+    let hex_width = match width {
+        w if w < px(VIEWPORT_MEDIUM) => HEX_WIDTH_NARROW,
+        w if w < px(VIEWPORT_WIDE) => HEX_WIDTH_MEDIUM,
+        _ => HEX_WIDTH_WIDE,
+    };
+
+    ByteEditorData::Hex(HexViewerListDelegate {
+        offset,
+        width: hex_width,
+        data: original.to_vec(),
+        original,
+        cursor: 0,
+        anchor: None,
+        high_nibble: None,
+        mode: HexEditMode::Overwrite,
+        editable: !readonly,
+        resizable,
+        selected_index: None,
+    })
+}
+
 /// Extract string value from Redis value, with hex fallback for binary data
 ///
 /// If the value is a string, returns Text(SharedString).
 /// If the value is binary data, formats it as a hex dump with appropriate width
 /// based on viewport size and returns Hex(SharedString).
+/// Only the prefix of a partial value is shown as text, its hex view shows the loaded page.
 ///
 /// # Arguments
 /// * `value` - Optional Redis value to extract string from
+/// * `readonly` - Whether the hex grid is readonly
 /// * `cx` - App context for viewport size calculation
 ///
 /// # Returns
 /// String representation (either original string or hex dump)
-fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditorData {
+fn format_byte_editor_data(value: &Arc<RedisBytesValue>, readonly: bool, cx: &App) -> ByteEditorData {
     if value.bytes.is_empty() {
         return ByteEditorData::Text(SharedString::default());
     }
 
     let create_hex_view = || {
-        let width = cx
-            .global::<ZedisGlobalStore>()
-            .read(cx)
-            .content_width()
-            .unwrap_or_default();
+        // A page of a large value can only be overwritten, a full SET would truncate the value
+        let (offset, bytes) = value.hex_page();
+        create_hex_view(bytes.clone(), offset, !value.is_partial(), readonly, cx)
+    };
 
-        let hex_width = match width {
-            w if w < px(VIEWPORT_MEDIUM) => HEX_WIDTH_NARROW,
-            w if w < px(VIEWPORT_WIDE) => HEX_WIDTH_MEDIUM,
-            _ => HEX_WIDTH_WIDE,
-        };
+    if value.view_mode == ViewMode::Bitmap {
+        return ByteEditorData::Bitmap;
+    }
 
-        let cfg = HexConfig {
-            title: false,
-            width: hex_width,
-            group: 0,
-            ..Default::default()
+    if value.is_partial() {
+        return match (&value.view_mode, &value.text) {
+            (ViewMode::Hex | ViewMode::ProtobufRaw, _) => create_hex_view(),
+            (ViewMode::Plain, _) => ByteEditorData::Text(String::from_utf8_lossy(&value.bytes).to_string().into()),
+            (_, Some(text)) => ByteEditorData::Text(text.clone()),
+            (_, None) => create_hex_view(),
         };
-
-        let hex_data = config_hex(&value.bytes, cfg);
-        ByteEditorData::Hex(HexViewerListDelegate::new(&hex_data))
-    };
+    }
 
     match value.view_mode {
         ViewMode::Hex => create_hex_view(),
 
+        // Falls back to hex when the bytes are not protobuf wire format
+        ViewMode::ProtobufRaw => match decode_raw_protobuf(&value.bytes) {
+            Ok(text) => ByteEditorData::Text(text.into()),
+            Err(_) => create_hex_view(),
+        },
+
         ViewMode::Plain => {
             let text = String::from_utf8_lossy(&value.bytes).to_string().into();
             ByteEditorData::Text(text)
         }
 
+        // Falls back to the text view when the value is not a structured document
+        ViewMode::Tree if value.document.is_some() => ByteEditorData::Tree,
+        ViewMode::Table => match &value.document {
+            Some(document) if is_json_table(document) => ByteEditorData::Table(document.clone()),
+            _ => value.text.clone().map_or_else(create_hex_view, ByteEditorData::Text),
+        },
+
         _ => {
+            // Without the summary, e.g. a corrupted HyperLogLog, the bytes are shown as hex
+            if value.format == DataFormat::HyperLogLog && value.hyperloglog.is_some() {
+                return ByteEditorData::HyperLogLog;
+            }
             if value.is_image() {
                 let format = match value.format {
                     DataFormat::Png => gpui::ImageFormat::Png,
@@ -160,30 +269,192 @@ fn format_byte_editor_data(value: &Arc<RedisBytesValue>, cx: &App) -> ByteEditor
         }
     }
 }
+/// Editing mode of the hex grid
+#[derive(Clone, Copy, PartialEq, Default)]
+enum HexEditMode {
+    /// Typed bytes replace the bytes at the cursor
+    #[default]
+    Overwrite,
+    /// Typed bytes are inserted before the cursor
+    Insert,
+}
+
+/// Text formats the selected bytes can be copied as
+#[derive(Clone, Copy)]
+enum HexCopyFormat {
+    Hex,
+    Base64,
+    CArray,
+}
+
+/// Editable hex grid of a binary value
 #[derive(Clone)]
 struct HexViewerListDelegate {
-    items: Vec<(SharedString, SharedString, SharedString)>,
+    /// Address of the first byte, the page offset of a large value
+    offset: usize,
+    /// Bytes per row
+    width: usize,
+    /// Bytes as loaded from Redis
+    original: Bytes,
+    /// Bytes with the edits applied
+    data: Vec<u8>,
+    /// Index of the byte at the cursor, `data.len()` appends in insert mode
+    cursor: usize,
+    /// Other end of the selected range, the cursor is the active end
+    anchor: Option<usize>,
+    /// High nibble typed at the cursor, the byte is completed by the low nibble
+    high_nibble: Option<u8>,
+    mode: HexEditMode,
+    /// Whether the bytes can be edited, false for readonly connections
+    editable: bool,
+    /// Whether bytes can be inserted or deleted, false for pages of large values
+    resizable: bool,
     selected_index: Option<IndexPath>,
 }
 
 impl HexViewerListDelegate {
-    fn new(data: &str) -> Self {
-        let items = data
-            .split("\n")
-            .flat_map(|item| {
-                let (address, value) = item.split_once(":")?;
-                let (hex_data, ascii_data) = value.trim_start().split_once("   ")?;
-                Some((
-                    address.to_uppercase().into(),
-                    hex_data.to_string().into(),
-                    ascii_data.to_string().into(),
-                ))
-            })
-            .collect::<Vec<_>>();
-        Self {
-            items,
-            selected_index: None,
+    fn is_modified(&self) -> bool {
+        self.data != self.original.as_ref()
+    }
+    /// Number of cells, insert mode has an extra cell to append bytes
+    fn cell_count(&self) -> usize {
+        if self.mode == HexEditMode::Insert {
+            self.data.len() + 1
+        } else {
+            self.data.len()
+        }
+    }
+    /// Selected range, the byte at the cursor when nothing is selected
+    fn selection(&self) -> Range<usize> {
+        let anchor = self.anchor.unwrap_or(self.cursor);
+        let start = anchor.min(self.cursor).min(self.data.len());
+        let end = (anchor.max(self.cursor) + 1).min(self.data.len());
+        start..end
+    }
+    fn move_cursor(&mut self, cursor: usize, extend: bool) {
+        if extend {
+            self.anchor.get_or_insert(self.cursor);
+        } else {
+            self.anchor = None;
         }
+        self.cursor = cursor.min(self.cell_count().saturating_sub(1));
+        self.high_nibble = None;
+    }
+    fn toggle_mode(&mut self) -> bool {
+        if !self.editable || !self.resizable {
+            return false;
+        }
+        self.mode = match self.mode {
+            HexEditMode::Overwrite => HexEditMode::Insert,
+            HexEditMode::Insert => HexEditMode::Overwrite,
+        };
+        self.move_cursor(self.cursor, false);
+        true
+    }
+    /// Writes a typed hex digit at the cursor, the cursor moves on after the low nibble.
+    fn input_nibble(&mut self, nibble: u8) -> bool {
+        if !self.editable {
+            return false;
+        }
+        self.anchor = None;
+        if let Some(high) = self.high_nibble.take() {
+            let Some(byte) = self.data.get_mut(self.cursor) else {
+                return false;
+            };
+            *byte = (high << 4) | nibble;
+            self.cursor = (self.cursor + 1).min(self.cell_count().saturating_sub(1));
+            return true;
+        }
+        match self.mode {
+            HexEditMode::Insert => {
+                if self.cursor > self.data.len() {
+                    return false;
+                }
+                self.data.insert(self.cursor, nibble << 4);
+            }
+            HexEditMode::Overwrite => {
+                let Some(byte) = self.data.get_mut(self.cursor) else {
+                    return false;
+                };
+                *byte = (nibble << 4) | (*byte & 0x0f);
+            }
+        }
+        self.high_nibble = Some(nibble);
+        true
+    }
+    /// Deletes the selected bytes, or the byte before (backspace) or at the cursor.
+    fn delete(&mut self, backward: bool) -> bool {
+        if !self.editable || !self.resizable || self.data.is_empty() {
+            return false;
+        }
+        let range = if self.anchor.is_some() {
+            self.selection()
+        } else if backward {
+            if self.cursor == 0 {
+                return false;
+            }
+            self.cursor - 1..self.cursor
+        } else {
+            if self.cursor >= self.data.len() {
+                return false;
+            }
+            self.cursor..self.cursor + 1
+        };
+        let start = range.start;
+        self.data.drain(range);
+        self.anchor = None;
+        self.high_nibble = None;
+        self.cursor = start.min(self.cell_count().saturating_sub(1));
+        true
+    }
+    /// Formats the selected bytes, or all the bytes when nothing is selected.
+    fn copy_text(&self, format: HexCopyFormat) -> String {
+        let bytes = if self.anchor.is_some() {
+            &self.data[self.selection()]
+        } else {
+            &self.data[..]
+        };
+        match format {
+            HexCopyFormat::Hex => bytes.iter().map(|b| format!("{b:02x}")).collect(),
+            HexCopyFormat::Base64 => BASE64.encode(bytes),
+            HexCopyFormat::CArray => {
+                let lines = bytes
+                    .chunks(HEX_WIDTH_NARROW)
+                    .map(|chunk| {
+                        let line = chunk.iter().map(|b| format!("0x{b:02x}")).collect::<Vec<_>>();
+                        format!("    {},", line.join(", "))
+                    })
+                    .collect::<Vec<_>>();
+                format!("unsigned char data[{}] = {{\n{}\n}};", bytes.len(), lines.join("\n"))
+            }
+        }
+    }
+    /// Renders a clickable cell of the byte at `index`, the cursor and the selection are highlighted.
+    fn render_cell(
+        &self,
+        id: &'static str,
+        index: usize,
+        text: SharedString,
+        cx: &mut Context<ListState<Self>>,
+    ) -> impl IntoElement {
+        let selection = self.anchor.map(|_| self.selection());
+        let modified = self.data.len() == self.original.len() && self.data.get(index) != self.original.get(index);
+        let mut cell = div().id((id, index)).px_0p5().cursor_text().child(text).on_mouse_down(
+            MouseButton::Left,
+            cx.listener(move |this, event: &MouseDownEvent, _window, cx| {
+                this.delegate_mut().move_cursor(index, event.modifiers.shift);
+                cx.notify();
+            }),
+        );
+        if modified {
+            cell = cell.text_color(cx.theme().yellow);
+        }
+        if index == self.cursor {
+            cell = cell.bg(cx.theme().primary).text_color(cx.theme().primary_foreground);
+        } else if selection.is_some_and(|range| range.contains(&index)) {
+            cell = cell.bg(cx.theme().selection);
+        }
+        cell
     }
 }
 
@@ -191,7 +462,7 @@ impl ListDelegate for HexViewerListDelegate {
     type Item = ListItem;
 
     fn items_count(&self, _section: usize, _cx: &App) -> usize {
-        self.items.len()
+        self.cell_count().div_ceil(self.width)
     }
 
     fn render_item(
@@ -205,18 +476,35 @@ impl ListDelegate for HexViewerListDelegate {
         } else {
             hsla(0.0892, 0.9462, 0.4373, 1.0)
         };
-        self.items.get(ix.row).map(|(address, hex_data, ascii_data)| {
+        let start = ix.row * self.width;
+        let end = (start + self.width).min(self.cell_count());
+        if start >= end {
+            return None;
+        }
+        let mut hex_cells = h_flex().mr_6();
+        let mut ascii_cells = h_flex();
+        for index in start..end {
+            // The append cell of insert mode has no byte
+            let (hex, ascii) = match self.data.get(index) {
+                Some(byte) if byte.is_ascii_graphic() => (format!("{byte:02x}"), (*byte as char).to_string()),
+                Some(byte) => (format!("{byte:02x}"), ".".to_string()),
+                None => ("__".to_string(), " ".to_string()),
+            };
+            hex_cells = hex_cells.child(self.render_cell("hex-byte", index, hex.into(), cx));
+            ascii_cells = ascii_cells.child(self.render_cell("ascii-byte", index, ascii.into(), cx));
+        }
+        Some(
             ListItem::new(ix).py_0().px_2().child(
                 h_flex()
-                    .child(Label::new(address.clone()).text_color(address_color).mr_4())
                     .child(
-                        Label::new(hex_data.clone())
-                            .text_color(cx.theme().muted_foreground)
-                            .mr_6(),
+                        Label::new(format!("{:08X}", self.offset + start))
+                            .text_color(address_color)
+                            .mr_4(),
                     )
-                    .child(Label::new(ascii_data.clone())),
-            )
-        })
+                    .child(hex_cells.text_color(cx.theme().muted_foreground))
+                    .child(ascii_cells),
+            ),
+        )
     }
 
     fn set_selected_index(&mut self, ix: Option<IndexPath>, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
@@ -246,6 +534,13 @@ impl ZedisBytesEditor {
                 ServerEvent::ValueUpdated => {
                     this.update_editor_data(cx);
                 }
+                ServerEvent::ValueLoadProgressed => {
+                    cx.notify();
+                }
+                ServerEvent::ValueEncodeFailed(message) => {
+                    this.encode_error = Some(message.clone());
+                    cx.notify();
+                }
                 ServerEvent::SoftWrapToggled(soft_wrap) => {
                     this.soft_wrap_changed = true;
                     this.soft_wrap = *soft_wrap;
@@ -280,10 +575,37 @@ impl ZedisBytesEditor {
                 let original = this.data.to_string().unwrap_or_default();
 
                 this.value_modified = original != value.as_str();
+                this.encode_error = None;
                 cx.notify();
             }
         }));
 
+        // Codec selector: uncompressed first, then the supported codecs
+        let mut compression_items = vec![i18n_editor(cx, "compression_none")];
+        compression_items.extend(
+            CompressionFormat::ALL
+                .iter()
+                .map(|codec| SharedString::from(codec.as_str())),
+        );
+        let compression_state = cx.new(|cx| {
+            SelectState::new(
+                SearchableVec::new(compression_items),
+                Some(IndexPath::new(0)),
+                window,
+                cx,
+            )
+        });
+        subscriptions.push(cx.subscribe_in(
+            &compression_state,
+            window,
+            |this, _state, event: &SelectEvent<SearchableVec<SharedString>>, _window, cx| match event {
+                SelectEvent::Confirm(value) => {
+                    this.selected_compression = value.as_ref().and_then(|v| CompressionFormat::from_str(v));
+                    cx.notify();
+                }
+            },
+        ));
+
         let readonly = server_state.read(cx).readonly();
         info!("Creating new string editor view");
 
@@ -293,10 +615,22 @@ impl ZedisBytesEditor {
             soft_wrap_changed: false,
             data: ByteEditorData::Text(SharedString::default()),
             hex_viewer_state: None,
+            hex_focus_handle: cx.focus_handle(),
+            hex_modified: false,
+            bitmap_editor: None,
+            hyperloglog_editor: None,
+            tree_editor: None,
+            json_table: None,
             editor,
             should_update_editor: true,
             server_state,
             readonly,
+            json_key: false,
+            compression: None,
+            selected_compression: None,
+            compression_state,
+            should_reset_compression: false,
+            encode_error: None,
             _subscriptions: subscriptions,
         };
         this.update_editor_data(cx);
@@ -317,24 +651,70 @@ impl ZedisBytesEditor {
 
         // Reset modification flag since we're loading a new value
         self.value_modified = false;
+        self.hex_modified = false;
+        self.encode_error = None;
         let readonly = server_state.readonly();
+        self.json_key = value.is_some_and(|v| v.key_type() == KeyType::Json);
 
         let redis_bytes_value = value.and_then(|v| v.bytes_value());
         if let Some(redis_bytes_value) = &redis_bytes_value {
-            self.readonly = readonly || !redis_bytes_value.is_utf8_text();
-            self.data = format_byte_editor_data(redis_bytes_value, cx);
+            // The raw protobuf tree can not be encoded back
+            self.readonly =
+                readonly || !redis_bytes_value.is_editable() || redis_bytes_value.view_mode == ViewMode::ProtobufRaw;
+            // RedisJSON documents can not be written by byte ranges
+            self.data = format_byte_editor_data(redis_bytes_value, readonly || self.json_key, cx);
+            match self.data {
+                ByteEditorData::Hex(_) => self.readonly = readonly || self.json_key,
+                // Bits and elements are written by the bitmap and HyperLogLog views,
+                // the tree and the table are only for browsing
+                ByteEditorData::Bitmap
+                | ByteEditorData::HyperLogLog
+                | ByteEditorData::Tree
+                | ByteEditorData::Table(_) => self.readonly = true,
+                _ => {}
+            }
+            self.compression = redis_bytes_value.compression;
         } else {
             self.data = ByteEditorData::Text(SharedString::default());
+            self.compression = None;
         }
+        self.selected_compression = self.compression;
+        self.should_reset_compression = true;
 
-        if !matches!(self.data, ByteEditorData::Hex(_)) {
-            self.hex_viewer_state = None;
+        // The list state is created from the hex data, so it is recreated for new data
+        self.hex_viewer_state = None;
+        if let ByteEditorData::Table(document) = &self.data
+            && let Some(json_table) = self.json_table.as_ref()
+        {
+            let document = document.clone();
+            json_table.update(cx, |state, cx| {
+                state.set_document(document, cx);
+            });
         }
     }
 
-    /// Check if the current editor value differs from the original Redis value
+    /// Check if the current editor value or the selected codec differs from the original Redis value
     pub fn is_value_modified(&self) -> bool {
-        self.value_modified
+        self.value_modified || self.hex_modified || self.selected_compression != self.compression
+    }
+
+    /// Get the bytes edited in the hex grid, `None` if they are not modified
+    pub fn hex_edit(&self, cx: &App) -> Option<BytesEdit> {
+        if !self.hex_modified {
+            return None;
+        }
+        let state = self.hex_viewer_state.as_ref()?.read(cx);
+        let delegate = state.delegate();
+        Some(BytesEdit {
+            offset: delegate.offset,
+            original: delegate.original.clone(),
+            data: delegate.data.clone(),
+        })
+    }
+
+    /// Get the codec to compress the value with on save
+    pub fn selected_compression(&self) -> Option<CompressionFormat> {
+        self.selected_compression
     }
 
     /// Check if the editor is readonly
@@ -346,6 +726,374 @@ impl ZedisBytesEditor {
     pub fn value(&self, cx: &mut Context<Self>) -> SharedString {
         self.editor.read(cx).value()
     }
+
+    /// Handle the keys of the hex grid: hex digits, cursor movement, deletion and the insert mode toggle
+    fn handle_hex_key_down(&mut self, event: &KeyDownEvent, _window: &mut Window, cx: &mut Context<Self>) {
+        let Some(state) = self.hex_viewer_state.clone() else {
+            return;
+        };
+        let keystroke = &event.keystroke;
+        let modifiers = keystroke.modifiers;
+        // Leave shortcuts such as cmd-s to the actions
+        if modifiers.platform || modifiers.control || modifiers.alt {
+            return;
+        }
+        let extend = modifiers.shift;
+        let handled = state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            let cursor = delegate.cursor;
+            let width = delegate.width;
+            let handled = match keystroke.key.as_str() {
+                "left" => {
+                    delegate.move_cursor(cursor.saturating_sub(1), extend);
+                    true
+                }
+                "right" => {
+                    delegate.move_cursor(cursor + 1, extend);
+                    true
+                }
+                "up" => {
+                    delegate.move_cursor(cursor.saturating_sub(width), extend);
+                    true
+                }
+                "down" => {
+                    delegate.move_cursor(cursor + width, extend);
+                    true
+                }
+                "home" => {
+                    delegate.move_cursor(cursor - cursor % width, extend);
+                    true
+                }
+                "end" => {
+                    delegate.move_cursor(cursor - cursor % width + width - 1, extend);
+                    true
+                }
+                "insert" => delegate.toggle_mode(),
+                "backspace" => delegate.delete(true),
+                "delete" => delegate.delete(false),
+                key => match u8::from_str_radix(key, 16) {
+                    Ok(nibble) if key.len() == 1 => delegate.input_nibble(nibble),
+                    _ => false,
+                },
+            };
+            if handled {
+                cx.notify();
+            }
+            handled
+        });
+        if handled {
+            self.hex_modified = state.read(cx).delegate().is_modified();
+            cx.stop_propagation();
+            cx.notify();
+        }
+    }
+
+    /// Toggle the overwrite and insert modes of the hex grid
+    fn toggle_hex_mode(&mut self, cx: &mut Context<Self>) {
+        if let Some(state) = &self.hex_viewer_state {
+            state.update(cx, |state, cx| {
+                if state.delegate_mut().toggle_mode() {
+                    cx.notify();
+                }
+            });
+            cx.notify();
+        }
+    }
+
+    /// Copy the selected bytes of the hex grid, or all the bytes when nothing is selected
+    fn copy_hex(&mut self, format: HexCopyFormat, cx: &mut Context<Self>) {
+        let Some(state) = &self.hex_viewer_state else {
+            return;
+        };
+        let content = state.read(cx).delegate().copy_text(format);
+        cx.write_to_clipboard(ClipboardItem::new_string(content));
+        self.server_state.update(cx, |state, cx| {
+            state.emit_info_notification(i18n_editor(cx, "copied_bytes_to_clipboard"), cx);
+        });
+    }
+
+    /// Open a dialog to set, append or delete a value at a JSONPath of a RedisJSON document
+    fn handle_update_json_path(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 3 {
+                return false;
+            }
+            let operation = match values[0].as_str() {
+                "1" => JsonPathOperation::Append,
+                "2" => JsonPathOperation::Delete,
+                _ => JsonPathOperation::Set,
+            };
+            if operation != JsonPathOperation::Delete && values[2].trim().is_empty() {
+                return false;
+            }
+            let path = values[1].clone();
+            let value = values[2].clone();
+
+            server_state.update(cx, |this, cx| {
+                this.update_json_path(operation, path, value, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_json_editor(cx, "operation")).with_options(vec![
+                i18n_json_editor(cx, "operation_set"),
+                i18n_json_editor(cx, "operation_append"),
+                i18n_json_editor(cx, "operation_delete"),
+            ]),
+            FormField::new(i18n_json_editor(cx, "path"))
+                .with_placeholder(i18n_json_editor(cx, "path_placeholder"))
+                .with_focus(),
+            FormField::new(i18n_common(cx, "value")).with_placeholder(i18n_json_editor(cx, "value_placeholder")),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_json_editor(cx, "update_path_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Render the toolbar with path-level edit actions for RedisJSON documents
+    fn render_json_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let readonly = server_state.readonly();
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(
+                Button::new("json-editor-update-path-btn")
+                    .outline()
+                    .small()
+                    .icon(CustomIconName::FilePenLine)
+                    .label(i18n_json_editor(cx, "update_path"))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_json_editor(cx, "update_path_tooltip")
+                    })
+                    .disabled(readonly || is_busy)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_update_json_path(window, cx);
+                    })),
+            )
+            .child(
+                Label::new(i18n_json_editor(cx, "path_tips"))
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground),
+            )
+    }
+
+    /// Render the codec selector for values decompressed for display
+    fn render_compression_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(Label::new(i18n_editor(cx, "compression")).text_sm())
+            .child(
+                Select::new(&self.compression_state)
+                    .small()
+                    .w(px(COMPRESSION_SELECT_WIDTH)),
+            )
+            .child(
+                Label::new(i18n_editor(cx, "compression_tips"))
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground),
+            )
+    }
+
+    /// Render the toolbar of a partial value: the loaded size, hex paging and loading the whole value
+    fn render_partial_toolbar(&self, value: &RedisBytesValue, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let progress = server_state.string_load_progress();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let total = value.length;
+
+        let mut toolbar = h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center();
+
+        if matches!(self.data, ByteEditorData::Hex(_) | ByteEditorData::Bitmap) {
+            let (offset, bytes) = value.hex_page();
+            let end = offset + bytes.len();
+            toolbar = toolbar
+                .child(
+                    Button::new("bytes-editor-previous-page-btn")
+                        .outline()
+                        .small()
+                        .icon(IconName::ChevronLeft)
+                        .tooltip(i18n_editor(cx, "previous_page"))
+                        .disabled(is_busy || offset == 0)
+                        .on_click(cx.listener(move |this, _, _window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.load_string_page(offset.saturating_sub(STRING_PAGE_SIZE), cx);
+                            });
+                        })),
+                )
+                .child(
+                    Button::new("bytes-editor-next-page-btn")
+                        .outline()
+                        .small()
+                        .icon(IconName::ChevronRight)
+                        .tooltip(i18n_editor(cx, "next_page"))
+                        .disabled(is_busy || end >= total)
+                        .on_click(cx.listener(move |this, _, _window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.load_string_page(end, cx);
+                            });
+                        })),
+                )
+                .child(Label::new(format!("0x{offset:08X} - 0x{end:08X}")).text_sm());
+        }
+
+        let tips = t!(
+            "editor.partial_value_tips",
+            loaded = format_size(value.bytes.len(), DECIMAL),
+            total = format_size(total, DECIMAL),
+            locale = locale
+        )
+        .to_string();
+        toolbar = toolbar.child(Label::new(tips).text_xs().text_color(cx.theme().muted_foreground));
+
+        let action = if let Some((loaded, total)) = progress {
+            let percent = loaded * 100 / total.max(1);
+            h_flex()
+                .gap_2()
+                .items_center()
+                .child(Label::new(t!("editor.loading_value", percent = percent, locale = locale).to_string()).text_sm())
+                .child(
+                    Button::new("bytes-editor-cancel-load-btn")
+                        .outline()
+                        .small()
+                        .icon(CustomIconName::X)
+                        .label(i18n_common(cx, "cancel"))
+                        .on_click(cx.listener(|this, _, _window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.cancel_string_load(cx);
+                            });
+                        })),
+                )
+        } else {
+            h_flex().child(
+                Button::new("bytes-editor-load-all-btn")
+                    .outline()
+                    .small()
+                    .icon(CustomIconName::ChevronsDown)
+                    .label(i18n_editor(cx, "load_all"))
+                    .tooltip(i18n_editor(cx, "load_all_tooltip"))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            state.load_full_string_value(cx);
+                        });
+                    })),
+            )
+        };
+        toolbar.child(div().flex_1()).child(action)
+    }
+
+    /// Render the toolbar of the hex grid: the editing mode, the selection and the copy actions
+    fn render_hex_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let (mode, resizable, cursor, selected) = self
+            .hex_viewer_state
+            .as_ref()
+            .map(|state| {
+                let delegate = state.read(cx).delegate();
+                let selected = delegate.anchor.map(|_| delegate.selection().len());
+                (
+                    delegate.mode,
+                    delegate.resizable,
+                    delegate.offset + delegate.cursor,
+                    selected,
+                )
+            })
+            .unwrap_or_default();
+        let mode_label = if mode == HexEditMode::Insert {
+            i18n_editor(cx, "hex_insert_mode")
+        } else {
+            i18n_editor(cx, "hex_overwrite_mode")
+        };
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let position = match selected {
+            Some(count) => t!("editor.hex_selected_bytes", count = count, locale = locale).to_string(),
+            None => format!("0x{cursor:08X}"),
+        };
+        let copy_btn = |id: &'static str, label: &'static str, format: HexCopyFormat| {
+            Button::new(id)
+                .outline()
+                .small()
+                .label(label)
+                .tooltip(i18n_editor(cx, "copy_bytes_tooltip"))
+                .on_click(cx.listener(move |this, _, _window, cx| {
+                    this.copy_hex(format, cx);
+                }))
+        };
+
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(
+                Button::new("bytes-editor-hex-mode-btn")
+                    .outline()
+                    .small()
+                    .label(mode_label)
+                    .tooltip(if self.readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_editor(cx, "hex_mode_tooltip")
+                    })
+                    .disabled(self.readonly || !resizable)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.toggle_hex_mode(cx);
+                    })),
+            )
+            .child(Label::new(position).text_sm())
+            .child(div().flex_1())
+            .child(copy_btn("bytes-editor-copy-hex-btn", "Hex", HexCopyFormat::Hex))
+            .child(copy_btn(
+                "bytes-editor-copy-base64-btn",
+                "Base64",
+                HexCopyFormat::Base64,
+            ))
+            .child(copy_btn(
+                "bytes-editor-copy-c-array-btn",
+                "C Array",
+                HexCopyFormat::CArray,
+            ))
+    }
+
+    /// Render the encode error of the edited text
+    fn render_encode_error(&self, error: SharedString, cx: &mut Context<Self>) -> impl IntoElement {
+        h_flex()
+            .px_2()
+            .py_1()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(Label::new(error).text_xs().text_color(cx.theme().red))
+    }
 }
 
 impl Render for ZedisBytesEditor {
@@ -362,7 +1110,17 @@ impl Render for ZedisBytesEditor {
             });
             self.soft_wrap_changed = false;
         }
-        match &self.data {
+        if self.should_reset_compression {
+            self.should_reset_compression = false;
+            let index = self
+                .compression
+                .and_then(|compression| CompressionFormat::ALL.iter().position(|codec| *codec == compression))
+                .map_or(0, |index| index + 1);
+            self.compression_state.update(cx, |state, cx| {
+                state.set_selected_index(Some(IndexPath::new(index)), window, cx);
+            });
+        }
+        let content = match &self.data {
             ByteEditorData::Image(value) => div()
                 .size_full()
                 .flex()
@@ -376,8 +1134,52 @@ impl Render for ZedisBytesEditor {
                     .hex_viewer_state
                     .get_or_insert_with(|| cx.new(|cx| ListState::new(value.clone(), window, cx)))
                     .clone();
-                List::new(&state).font_family(get_font_family()).into_any_element()
+                div()
+                    .size_full()
+                    .track_focus(&self.hex_focus_handle)
+                    .on_key_down(cx.listener(Self::handle_hex_key_down))
+                    .on_mouse_down(
+                        MouseButton::Left,
+                        cx.listener(|this, _, window, _cx| {
+                            this.hex_focus_handle.focus(window);
+                        }),
+                    )
+                    .child(List::new(&state).font_family(get_font_family()))
+                    .into_any_element()
             }
+            ByteEditorData::Bitmap => self
+                .bitmap_editor
+                .get_or_insert_with(|| {
+                    let server_state = self.server_state.clone();
+                    cx.new(|cx| ZedisBitmapEditor::new(server_state, window, cx))
+                })
+                .clone()
+                .into_any_element(),
+            ByteEditorData::HyperLogLog => self
+                .hyperloglog_editor
+                .get_or_insert_with(|| {
+                    let server_state = self.server_state.clone();
+                    cx.new(|cx| ZedisHyperLogLogEditor::new(server_state, window, cx))
+                })
+                .clone()
+                .into_any_element(),
+            ByteEditorData::Tree => self
+                .tree_editor
+                .get_or_insert_with(|| {
+                    let server_state = self.server_state.clone();
+                    cx.new(|cx| ZedisTreeEditor::new(server_state, window, cx))
+                })
+                .clone()
+                .into_any_element(),
+            ByteEditorData::Table(document) => self
+                .json_table
+                .get_or_insert_with(|| {
+                    let server_state = self.server_state.clone();
+                    let document = document.clone();
+                    cx.new(|cx| ZedisJsonTable::new(server_state, document, cx))
+                })
+                .clone()
+                .into_any_element(),
             _ => {
                 if self.should_update_editor {
                     self.should_update_editor = false;
@@ -398,6 +1200,37 @@ impl Render for ZedisBytesEditor {
                     .focus_bordered(false)
                     .into_any_element()
             }
+        };
+        let partial_value = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.bytes_value())
+            .filter(|v| v.is_partial());
+        let mut toolbars = Vec::new();
+        if let Some(value) = partial_value {
+            toolbars.push(self.render_partial_toolbar(&value, cx).into_any_element());
+        }
+        if self.json_key {
+            toolbars.push(self.render_json_toolbar(cx).into_any_element());
+        } else if self.compression.is_some() && matches!(self.data, ByteEditorData::Text(_)) {
+            toolbars.push(self.render_compression_toolbar(cx).into_any_element());
+        }
+        if matches!(self.data, ByteEditorData::Hex(_)) {
+            toolbars.push(self.render_hex_toolbar(cx).into_any_element());
+        }
+        if toolbars.is_empty() && self.encode_error.is_none() {
+            return content;
         }
+        let encode_error = self
+            .encode_error
+            .clone()
+            .map(|error| self.render_encode_error(error, cx));
+        v_flex()
+            .size_full()
+            .children(toolbars)
+            .children(encode_error)
+            .child(div().flex_1().h_0().w_full().child(content))
+            .into_any_element()
     }
 }
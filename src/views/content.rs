@@ -20,7 +20,10 @@ use crate::{
         EditorAction, get_font_family, get_key_tree_widths, redis_value_to_string, starts_with_ignore_ascii_case,
     },
     states::{GlobalEvent, Route, ServerEvent, ZedisGlobalStore, ZedisServerState, save_app_state},
-    views::{ZedisEditor, ZedisKeyTree, ZedisProtoEditor, ZedisServers, ZedisSettingEditor, ZedisStatusBar},
+    views::{
+        ZedisAvroEditor, ZedisDecoderRuleEditor, ZedisEditor, ZedisKeyTree, ZedisProtoEditor, ZedisServers,
+        ZedisSettingEditor, ZedisStatusBar,
+    },
 };
 use gpui::{Entity, FocusHandle, Pixels, ScrollHandle, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
@@ -64,6 +67,8 @@ pub struct ZedisContent {
     servers: Option<Entity<ZedisServers>>,
     setting_editor: Option<Entity<ZedisSettingEditor>>,
     proto_editor: Option<Entity<ZedisProtoEditor>>,
+    avro_editor: Option<Entity<ZedisAvroEditor>>,
+    decoder_rule_editor: Option<Entity<ZedisDecoderRuleEditor>>,
     value_editor: Option<Entity<ZedisEditor>>,
     key_tree: Option<Entity<ZedisKeyTree>>,
     status_bar: Entity<ZedisStatusBar>,
@@ -100,6 +105,12 @@ impl ZedisContent {
         if route != Route::Protos {
             self.proto_editor.take();
         }
+        if route != Route::Avros {
+            self.avro_editor.take();
+        }
+        if route != Route::DecoderRules {
+            self.decoder_rule_editor.take();
+        }
     }
     /// Create a new content view with route-aware view management
     ///
@@ -224,6 +235,8 @@ impl ZedisContent {
             cmd_output_scroll_handle: ScrollHandle::new(),
             focus_handle,
             proto_editor: None,
+            avro_editor: None,
+            decoder_rule_editor: None,
             _subscriptions: subscriptions,
         }
     }
@@ -387,6 +400,26 @@ impl ZedisContent {
             .clone();
         div().size_full().child(proto_editor)
     }
+    fn render_avro_editor(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let avro_editor = self
+            .avro_editor
+            .get_or_insert_with(|| {
+                debug!("Creating new avro editor view");
+                cx.new(|cx| ZedisAvroEditor::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().size_full().child(avro_editor)
+    }
+    fn render_decoder_rule_editor(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let decoder_rule_editor = self
+            .decoder_rule_editor
+            .get_or_insert_with(|| {
+                debug!("Creating new decoder rule editor view");
+                cx.new(|cx| ZedisDecoderRuleEditor::new(self.server_state.clone(), window, cx))
+            })
+            .clone();
+        div().size_full().child(decoder_rule_editor)
+    }
     /// Render a loading skeleton screen with animated placeholders
     ///
     /// Displayed when the application is busy (e.g., connecting to Redis server,
@@ -599,6 +632,10 @@ impl Render for ZedisContent {
             Route::Home => base.child(self.render_servers(window, cx)).into_any_element(),
             Route::Settings => base.child(self.render_settings(window, cx)).into_any_element(),
             Route::Protos => base.child(self.render_proto_editor(window, cx)).into_any_element(),
+            Route::Avros => base.child(self.render_avro_editor(window, cx)).into_any_element(),
+            Route::DecoderRules => base
+                .child(self.render_decoder_rule_editor(window, cx))
+                .into_any_element(),
             _ => {
                 // Route 2: Loading state (show skeleton while connecting/loading)
                 let is_busy = self.server_state.read(cx).is_busy();
@@ -0,0 +1,739 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Decoder rule settings UI component.
+//!
+//! Decoder rules map a server and key pattern to a forced decoder and view mode:
+//! - Lists the rules in a table with edit and delete actions
+//! - Edits the server, name, match pattern and match mode of a rule
+//! - Selects the forced decoder (or auto detection) and view mode
+//! - Sets the external command (and the reverse command for save) of the command decoder
+
+use crate::assets::CustomIconName;
+use crate::connection::get_servers;
+use crate::db::{DecoderRuleConfig, DecoderRuleManager};
+use crate::error::Error;
+use crate::helpers::get_font_family;
+use crate::states::i18n_decoder_rule_editor;
+use crate::states::{ViewMode, ZedisGlobalStore, ZedisServerState, decoder_names, dialog_button_props};
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::button::{Button, ButtonVariants};
+use gpui_component::label::Label;
+use gpui_component::radio::RadioGroup;
+use gpui_component::table::{Column, Table, TableDelegate, TableState};
+use gpui_component::{IconName, h_flex};
+use gpui_component::{
+    IndexPath, WindowExt,
+    alert::Alert,
+    form::{field, v_form},
+    input::{Input, InputEvent, InputState},
+    select::{Select, SelectEvent, SelectItem, SelectState},
+    text::TextView,
+    v_flex,
+};
+use rust_i18n::t;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::error;
+use uuid::Uuid;
+
+/// Decoder option of auto detection
+const DECODER_AUTO: &str = "auto";
+
+#[derive(Debug, Clone)]
+struct KeyValueOption {
+    key: SharedString,
+    value: SharedString,
+}
+
+impl KeyValueOption {
+    pub fn new(key: SharedString, value: SharedString) -> Self {
+        Self { key, value }
+    }
+}
+impl SelectItem for KeyValueOption {
+    type Value = SharedString;
+    fn title(&self) -> SharedString {
+        self.key.clone()
+    }
+    fn value(&self) -> &Self::Value {
+        &self.value
+    }
+}
+
+type OnRuleAction = Arc<dyn Fn(usize, &mut Window, &mut Context<TableState<DecoderRuleTableDelegate>>) + Send + Sync>;
+
+struct DecoderRuleTableDelegate {
+    data: Arc<Vec<(String, DecoderRuleConfig)>>,
+    columns: Vec<Column>,
+    servers: Vec<KeyValueOption>,
+    on_edit: OnRuleAction,
+    on_delete: OnRuleAction,
+}
+
+impl DecoderRuleTableDelegate {
+    fn new<F1, F2>(
+        data: Arc<Vec<(String, DecoderRuleConfig)>>,
+        servers: Vec<KeyValueOption>,
+        columns: Vec<Column>,
+        on_edit: F1,
+        on_delete: F2,
+    ) -> Self
+    where
+        F1: Fn(usize, &mut Window, &mut Context<TableState<DecoderRuleTableDelegate>>) + Send + Sync + 'static,
+        F2: Fn(usize, &mut Window, &mut Context<TableState<DecoderRuleTableDelegate>>) + Send + Sync + 'static,
+    {
+        Self {
+            data,
+            columns,
+            servers,
+            on_edit: Arc::new(on_edit),
+            on_delete: Arc::new(on_delete),
+        }
+    }
+}
+
+impl TableDelegate for DecoderRuleTableDelegate {
+    fn columns_count(&self, _: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _: &App) -> usize {
+        self.data.len()
+    }
+
+    fn column(&self, index: usize, _: &App) -> &Column {
+        &self.columns[index]
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let rule = self.data.get(row_ix);
+        if col_ix == self.columns_count(cx) - 1 {
+            let on_edit = self.on_edit.clone();
+            let on_delete = self.on_delete.clone();
+            return div().size_full().flex().items_center().child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("edit-decoder-rule-btn")
+                            .icon(CustomIconName::FilePenLine)
+                            .ghost()
+                            .on_click(cx.listener(move |_this, _, window, cx| {
+                                (on_edit)(row_ix, window, cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("delete-decoder-rule-btn")
+                            .icon(CustomIconName::X)
+                            .ghost()
+                            .on_click(cx.listener(move |_this, _, window, cx| {
+                                (on_delete)(row_ix, window, cx);
+                            })),
+                    ),
+            );
+        }
+
+        let text = if let Some((_, rule)) = rule {
+            match col_ix {
+                0 => {
+                    // Convert server_id to server_name
+                    self.servers
+                        .iter()
+                        .find(|s| s.value.as_ref() == rule.server_id)
+                        .map(|s| s.key.to_string())
+                        .unwrap_or_else(|| rule.server_id.clone())
+                }
+                1 => rule.name.clone(),
+                2 => rule.match_pattern.clone(),
+                3 => format!("{:?}", rule.mode),
+                4 => rule.decoder.clone().unwrap_or_else(|| DECODER_AUTO.to_string()),
+                5 => rule.view_mode.clone(),
+                _ => String::new(),
+            }
+        } else {
+            String::new()
+        };
+
+        div().size_full().flex().items_center().child(Label::new(text))
+    }
+}
+
+enum EditorMode {
+    Table,
+    Edit,
+}
+
+pub struct ZedisDecoderRuleEditor {
+    server_select_state: Entity<SelectState<Vec<KeyValueOption>>>,
+    name_state: Entity<InputState>,
+    match_pattern_state: Entity<InputState>,
+    match_mode_select_state: Entity<usize>,
+    decoder_select_state: Entity<SelectState<Vec<SharedString>>>,
+    view_mode_select_state: Entity<SelectState<Vec<SharedString>>>,
+    command_state: Entity<InputState>,
+    reverse_command_state: Entity<InputState>,
+    field_errors: Entity<HashMap<String, SharedString>>,
+
+    rules: Arc<Vec<(String, DecoderRuleConfig)>>,
+    servers: Vec<KeyValueOption>,
+    server_id: SharedString,
+    edit_rule_id: Option<String>,
+    editor_mode: EditorMode,
+    table_state: Entity<TableState<DecoderRuleTableDelegate>>,
+    needs_table_recreate: Option<bool>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisDecoderRuleEditor {
+    fn create_table_state(
+        rules: Arc<Vec<(String, DecoderRuleConfig)>>,
+        servers: Vec<KeyValueOption>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<TableState<DecoderRuleTableDelegate>> {
+        let view_update_entity = cx.entity();
+        let view_delete_entity = cx.entity();
+
+        let on_edit =
+            move |row_ix: usize, window: &mut Window, cx: &mut Context<TableState<DecoderRuleTableDelegate>>| {
+                view_update_entity.update(cx, |this, cx| {
+                    this.handle_update(row_ix, window, cx);
+                });
+            };
+
+        let on_delete =
+            move |row_ix: usize, window: &mut Window, cx: &mut Context<TableState<DecoderRuleTableDelegate>>| {
+                view_delete_entity.update(cx, |this, cx| {
+                    this.handle_delete(row_ix, window, cx);
+                });
+            };
+        let columns = vec![
+            Column::new("server_name", i18n_decoder_rule_editor(cx, "server_name")).width(px(150.)),
+            Column::new("name", i18n_decoder_rule_editor(cx, "name")).width(px(150.)),
+            Column::new("match_pattern", i18n_decoder_rule_editor(cx, "match_pattern")).width(px(200.)),
+            Column::new("mode", i18n_decoder_rule_editor(cx, "mode")).width(px(100.)),
+            Column::new("decoder", i18n_decoder_rule_editor(cx, "decoder")).width(px(120.)),
+            Column::new("view_mode", i18n_decoder_rule_editor(cx, "view_mode")).width(px(100.)),
+            Column::new("actions", i18n_decoder_rule_editor(cx, "actions")).width(px(150.)),
+        ];
+
+        let delegate = DecoderRuleTableDelegate::new(rules, servers, columns, on_edit, on_delete);
+        cx.new(|cx| TableState::new(delegate, window, cx))
+    }
+
+    /// Options of the decoder select: auto detection followed by the registered decoders.
+    fn decoder_options() -> Vec<SharedString> {
+        std::iter::once(DECODER_AUTO)
+            .chain(decoder_names())
+            .map(SharedString::from)
+            .collect()
+    }
+
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let server_id = server_state.read(cx).server_id().to_string();
+        let rules = DecoderRuleManager::list_rules_with_id();
+        let mut subscriptions = Vec::new();
+        let servers = get_servers()
+            .unwrap_or_default()
+            .iter()
+            .map(|server| KeyValueOption::new(server.name.clone().into(), server.id.clone().into()))
+            .collect::<Vec<_>>();
+        let name_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_decoder_rule_editor(cx, "name_placeholder"))
+        });
+        let match_pattern_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_decoder_rule_editor(cx, "match_pattern_placeholder"))
+        });
+        let command_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_decoder_rule_editor(cx, "command_placeholder"))
+        });
+        let reverse_command_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_decoder_rule_editor(cx, "reverse_command_placeholder"))
+        });
+        let match_mode_select_state = cx.new(|_cx| 0_usize);
+        let decoder_select_state =
+            cx.new(|cx| SelectState::new(Self::decoder_options(), Some(IndexPath::new(0)), window, cx));
+        let view_mode_select_state = cx.new(|cx| {
+            SelectState::new(
+                ViewMode::ALL
+                    .iter()
+                    .map(|mode| SharedString::from(mode.as_str()))
+                    .collect::<Vec<_>>(),
+                Some(IndexPath::new(0)),
+                window,
+                cx,
+            )
+        });
+        let found = servers
+            .iter()
+            .position(|item| item.value == server_id)
+            .map(IndexPath::new);
+        let servers_for_delegate = servers.clone();
+        let server_select_state = cx.new(|cx| SelectState::new(servers, found, window, cx));
+        let field_errors = cx.new(|_cx| HashMap::new());
+
+        let field_errors_clone = field_errors.clone();
+        subscriptions.push(cx.subscribe(&server_select_state, move |this, view, event, cx| {
+            if let SelectEvent::Confirm(Some(server_id)) = event {
+                this.server_id = server_id.clone();
+                let id = view.entity_id().to_string();
+                if field_errors_clone.read(cx).get(&id).is_some() {
+                    field_errors_clone.update(cx, |state, _cx| {
+                        state.remove(&id);
+                    });
+                }
+            }
+        }));
+        for item in [name_state.clone(), match_pattern_state.clone(), command_state.clone()] {
+            subscriptions.push(
+                cx.subscribe_in(&item.clone(), window, move |view, state, event, _window, cx| {
+                    if let InputEvent::Blur = event {
+                        let id = state.entity_id().to_string();
+                        if view.field_errors.read(cx).get(&id).is_some() {
+                            view.field_errors.update(cx, |state, _cx| {
+                                state.remove(&id);
+                            });
+                        }
+                    }
+                }),
+            );
+        }
+
+        let rules = Arc::new(rules);
+        let table_state = Self::create_table_state(rules.clone(), servers_for_delegate.clone(), window, cx);
+
+        Self {
+            server_select_state,
+            name_state,
+            match_pattern_state,
+            match_mode_select_state,
+            decoder_select_state,
+            view_mode_select_state,
+            command_state,
+            reverse_command_state,
+            editor_mode: EditorMode::Table,
+            table_state,
+            rules,
+            servers: servers_for_delegate,
+            server_id: server_id.into(),
+            needs_table_recreate: None,
+            edit_rule_id: None,
+            field_errors,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    fn handle_save(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let server_id = self.server_id.clone();
+        let name = self.name_state.read(cx).value();
+        let match_pattern = self.match_pattern_state.read(cx).value();
+        let match_mode = self.match_mode_select_state.read(cx).to_owned();
+        let decoder = self
+            .decoder_select_state
+            .read(cx)
+            .selected_value()
+            .filter(|decoder| decoder.as_ref() != DECODER_AUTO)
+            .map(|decoder| decoder.to_string());
+        let view_mode = self
+            .view_mode_select_state
+            .read(cx)
+            .selected_value()
+            .map(|mode| mode.to_string())
+            .unwrap_or_else(|| ViewMode::Auto.as_str().to_string());
+        let command = self.command_state.read(cx).value().trim().to_string();
+        let reverse_command = self.reverse_command_state.read(cx).value().trim().to_string();
+        let field_errors = self.field_errors.clone();
+        field_errors.update(cx, |state, _cx| {
+            state.clear();
+        });
+
+        if server_id.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(
+                    self.server_select_state.entity_id().to_string(),
+                    "server is required".into(),
+                );
+            });
+        }
+        if name.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(self.name_state.entity_id().to_string(), "name is required".into());
+            });
+        }
+        if match_pattern.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(
+                    self.match_pattern_state.entity_id().to_string(),
+                    "match pattern is required".into(),
+                );
+            });
+        }
+        if decoder.as_deref() == Some("command") && command.is_empty() {
+            field_errors.update(cx, |state, _cx| {
+                state.insert(
+                    self.command_state.entity_id().to_string(),
+                    "command is required by the command decoder".into(),
+                );
+            });
+        }
+        if !field_errors.read(cx).is_empty() {
+            return;
+        }
+
+        let id = self.edit_rule_id.clone().unwrap_or_else(|| Uuid::now_v7().to_string());
+        let rule = DecoderRuleConfig {
+            server_id: server_id.to_string(),
+            name: name.to_string(),
+            match_pattern: match_pattern.to_string(),
+            mode: match_mode.into(),
+            decoder,
+            view_mode,
+            command: Some(command).filter(|command| !command.is_empty()),
+            reverse_command: Some(reverse_command).filter(|command| !command.is_empty()),
+        };
+        cx.spawn(async move |handle, cx| {
+            let result: Result<(String, DecoderRuleConfig), Error> = cx
+                .background_spawn(async move {
+                    DecoderRuleManager::upsert_rule(&id, rule.clone())?;
+                    Ok((id.to_string(), rule))
+                })
+                .await;
+            match result {
+                Ok((id, rule)) => {
+                    let _ = handle.update(cx, |this, cx| {
+                        // Update rules: replace if exists, otherwise add new
+                        let mut new_rules = this.rules.as_ref().clone();
+                        if let Some(pos) = new_rules.iter().position(|(existing_id, _)| existing_id == &id) {
+                            new_rules[pos] = (id, rule);
+                        } else {
+                            new_rules.push((id, rule));
+                        }
+                        this.rules = Arc::new(new_rules);
+
+                        // Mark for recreation of table on next render
+                        this.needs_table_recreate = Some(true);
+                        this.editor_mode = EditorMode::Table;
+                        cx.notify();
+                    });
+                }
+                Err(e) => {
+                    error!(error = %e, "save decoder rule fail",);
+                }
+            }
+        })
+        .detach();
+    }
+    fn reset_form(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        self.edit_rule_id = None;
+        self.name_state.update(cx, |state, cx| {
+            state.set_value(String::new(), window, cx);
+        });
+        for state in [
+            self.match_pattern_state.clone(),
+            self.command_state.clone(),
+            self.reverse_command_state.clone(),
+        ] {
+            state.update(cx, |state, cx| {
+                state.set_value(String::new(), window, cx);
+            });
+        }
+        self.match_mode_select_state.update(cx, |state, _cx| {
+            *state = 0;
+        });
+        self.decoder_select_state.update(cx, |state, cx| {
+            state.set_selected_index(Some(IndexPath::new(0)), window, cx);
+        });
+        self.view_mode_select_state.update(cx, |state, cx| {
+            state.set_selected_index(Some(IndexPath::new(0)), window, cx);
+        });
+    }
+    fn handle_update(&mut self, row_ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((id, _)) = self.rules.get(row_ix) else {
+            return;
+        };
+        let Some(rule) = DecoderRuleManager::get_rule(id) else {
+            return;
+        };
+        self.edit_rule_id = Some(id.clone());
+        let selected_index = self
+            .servers
+            .iter()
+            .position(|s| s.value == rule.server_id)
+            .map(IndexPath::new);
+        self.server_id = rule.server_id.clone().into();
+        self.server_select_state.update(cx, |state, cx| {
+            state.set_selected_index(selected_index, window, cx);
+        });
+        self.name_state.update(cx, |state, cx| {
+            state.set_value(rule.name.clone(), window, cx);
+        });
+        self.match_pattern_state.update(cx, |state, cx| {
+            state.set_value(rule.match_pattern.clone(), window, cx);
+        });
+        self.match_mode_select_state.update(cx, |state, _cx| {
+            *state = rule.mode.clone().into();
+        });
+        let decoder = rule.decoder.as_deref().unwrap_or(DECODER_AUTO);
+        let decoder_index = Self::decoder_options()
+            .iter()
+            .position(|item| item.as_ref() == decoder)
+            .unwrap_or_default();
+        self.decoder_select_state.update(cx, |state, cx| {
+            state.set_selected_index(Some(IndexPath::new(decoder_index)), window, cx);
+        });
+        let view_mode = ViewMode::from_str(&rule.view_mode);
+        let view_mode_index = ViewMode::ALL
+            .iter()
+            .position(|mode| *mode == view_mode)
+            .unwrap_or_default();
+        self.view_mode_select_state.update(cx, |state, cx| {
+            state.set_selected_index(Some(IndexPath::new(view_mode_index)), window, cx);
+        });
+        self.command_state.update(cx, |state, cx| {
+            state.set_value(rule.command.clone().unwrap_or_default(), window, cx);
+        });
+        self.reverse_command_state.update(cx, |state, cx| {
+            state.set_value(rule.reverse_command.clone().unwrap_or_default(), window, cx);
+        });
+        self.editor_mode = EditorMode::Edit;
+        cx.notify();
+    }
+    fn handle_delete(&mut self, row_ix: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some((id, rule)) = self.rules.get(row_ix) else {
+            return;
+        };
+        let name = rule.name.clone();
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+        let text = t!("decoder_rule_editor.remove_rule_prompt", name = name, locale = locale).to_string();
+
+        let id = id.to_string();
+        let view_handle = cx.entity();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let id = id.clone();
+            let view_handle = view_handle.clone();
+            dialog
+                .confirm()
+                .button_props(dialog_button_props(cx))
+                .child(text.clone())
+                .on_ok(move |_, _window, cx| {
+                    let id = id.clone();
+                    let view_handle = view_handle.clone();
+                    cx.spawn(async move |cx| {
+                        let result: Result<String, Error> = cx
+                            .background_spawn({
+                                let id = id.clone();
+                                async move {
+                                    DecoderRuleManager::delete_rule(&id)?;
+                                    Ok(id)
+                                }
+                            })
+                            .await;
+                        match result {
+                            Ok(deleted_id) => {
+                                let _ = view_handle.update(cx, |this, cx| {
+                                    // Remove deleted rule from the list
+                                    let new_rules: Vec<_> =
+                                        this.rules.iter().filter(|(id, _)| id != &deleted_id).cloned().collect();
+                                    this.rules = Arc::new(new_rules);
+
+                                    // Mark for recreation of table on next render
+                                    this.needs_table_recreate = Some(true);
+                                    cx.notify();
+                                });
+                            }
+                            Err(e) => {
+                                error!(error = %e, "delete decoder rule fail",);
+                            }
+                        }
+                    })
+                    .detach();
+                    true
+                })
+        });
+    }
+    fn render_edit_form(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let match_mode_select_state_clone = self.match_mode_select_state.clone();
+        let match_mode_select_state = self.match_mode_select_state.read(cx);
+        v_flex()
+            .p_5()
+            .size_full()
+            .gap_3()
+            .child(
+                v_form()
+                    .w_full()
+                    .columns(2)
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "server_name"))
+                            .required(true)
+                            .child(Select::new(&self.server_select_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "name"))
+                            .required(true)
+                            .child(Input::new(&self.name_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "match_pattern"))
+                            .required(true)
+                            .child(Input::new(&self.match_pattern_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "mode"))
+                            .required(true)
+                            .child(
+                                RadioGroup::horizontal("decoder-rule-match-mode-group")
+                                    .mt(px(8.))
+                                    .children(vec!["Prefix", "Suffix", "Regex", "Exact"])
+                                    .selected_index(Some(*match_mode_select_state))
+                                    .on_click(move |index, _, cx| {
+                                        match_mode_select_state_clone.update(cx, |state, _cx| {
+                                            *state = *index;
+                                        });
+                                    }),
+                            ),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "decoder"))
+                            .description(i18n_decoder_rule_editor(cx, "decoder_description"))
+                            .child(Select::new(&self.decoder_select_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "view_mode"))
+                            .child(Select::new(&self.view_mode_select_state)),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "command"))
+                            .description(i18n_decoder_rule_editor(cx, "command_description"))
+                            .child(Input::new(&self.command_state).font_family(get_font_family())),
+                    )
+                    .child(
+                        field()
+                            .label(i18n_decoder_rule_editor(cx, "reverse_command"))
+                            .description(i18n_decoder_rule_editor(cx, "reverse_command_description"))
+                            .child(Input::new(&self.reverse_command_state).font_family(get_font_family())),
+                    ),
+            )
+            .child(v_flex().w_full().flex_1().h_full())
+            .when(!self.field_errors.read(cx).is_empty(), |this| {
+                let title = i18n_decoder_rule_editor(cx, "field_errors_title");
+                let list = self
+                    .field_errors
+                    .read(cx)
+                    .values()
+                    .map(|value| format!("- {value}"))
+                    .collect::<Vec<String>>()
+                    .join("\n");
+                let markdown = t!("decoder_rule_editor.field_errors_message", errors = list);
+                this.child(
+                    Alert::error(
+                        "decoder-rule-editor-form-errors",
+                        TextView::markdown("decoder-rule-editor-form-errors-message", markdown, window, cx),
+                    )
+                    .title(title)
+                    .mt_4(),
+                )
+            })
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        Button::new("decoder-rule-editor-btn-cancel")
+                            .icon(IconName::CircleX)
+                            .label(i18n_decoder_rule_editor(cx, "cancel"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.editor_mode = EditorMode::Table;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Button::new("decoder-rule-editor-btn-save")
+                            .primary()
+                            .icon(CustomIconName::Save)
+                            .label(i18n_decoder_rule_editor(cx, "save"))
+                            .on_click(cx.listener(|this, _, window, cx| {
+                                this.handle_save(window, cx);
+                            })),
+                    ),
+            )
+    }
+    fn render_table_view(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        if let Some(true) = self.needs_table_recreate.take() {
+            self.table_state = Self::create_table_state(self.rules.clone(), self.servers.clone(), window, cx);
+        }
+        v_flex()
+            .size_full()
+            .p_5()
+            .gap_3()
+            .child(
+                h_flex()
+                    .w_full()
+                    .justify_between()
+                    .child(Label::new(i18n_decoder_rule_editor(cx, "title")).text_xl()),
+            )
+            .child(
+                div().flex_1().w_full().child(
+                    Table::new(&self.table_state)
+                        .stripe(true)
+                        .bordered(true)
+                        .scrollbar_visible(true, true),
+                ),
+            )
+            .child(
+                h_flex().w_full().justify_end().p_2().child(
+                    Button::new("add-decoder-rule-bottom-btn")
+                        .primary()
+                        .icon(CustomIconName::FilePlusCorner)
+                        .label(i18n_decoder_rule_editor(cx, "add"))
+                        .on_click(cx.listener(|this, _, window, cx| {
+                            this.reset_form(window, cx);
+                            this.editor_mode = EditorMode::Edit;
+                            cx.notify();
+                        })),
+                ),
+            )
+            .into_any_element()
+    }
+}
+
+impl Render for ZedisDecoderRuleEditor {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        match self.editor_mode {
+            EditorMode::Table => self.render_table_view(window, cx).into_any_element(),
+            EditorMode::Edit => self.render_edit_form(window, cx).into_any_element(),
+        }
+    }
+}
@@ -16,7 +16,10 @@ use crate::{
     assets::CustomIconName,
     helpers::{EditorAction, format_duration, humanize_keystroke, validate_ttl},
     states::{KeyType, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common, i18n_editor},
-    views::{ZedisBytesEditor, ZedisHashEditor, ZedisListEditor, ZedisSetEditor, ZedisZsetEditor},
+    views::{
+        ZedisBytesEditor, ZedisHashEditor, ZedisListEditor, ZedisProbabilisticEditor, ZedisSetEditor,
+        ZedisStreamEditor, ZedisTimeseriesEditor, ZedisVectorsetEditor, ZedisZsetEditor,
+    },
 };
 use gpui::{ClipboardItem, Entity, SharedString, Subscription, Window, div, prelude::*, px};
 use gpui_component::{
@@ -50,6 +53,10 @@ pub struct ZedisEditor {
     set_editor: Option<Entity<ZedisSetEditor>>,
     zset_editor: Option<Entity<ZedisZsetEditor>>,
     hash_editor: Option<Entity<ZedisHashEditor>>,
+    stream_editor: Option<Entity<ZedisStreamEditor>>,
+    vectorset_editor: Option<Entity<ZedisVectorsetEditor>>,
+    timeseries_editor: Option<Entity<ZedisTimeseriesEditor>>,
+    probabilistic_editor: Option<Entity<ZedisProbabilisticEditor>>,
 
     /// TTL editing state
     should_enter_ttl_edit_mode: Option<bool>,
@@ -143,6 +150,10 @@ impl ZedisEditor {
             set_editor: None,
             zset_editor: None,
             hash_editor: None,
+            stream_editor: None,
+            vectorset_editor: None,
+            timeseries_editor: None,
+            probabilistic_editor: None,
             readonly,
             ttl_edit_mode: false,
             ttl_input_state,
@@ -184,7 +195,7 @@ impl ZedisEditor {
         let server_state = self.server_state.clone();
         window.open_dialog(cx, move |dialog, _, cx| {
             let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
-            let message = t!("editor.delete_key_prompt", key = key, locale = locale).to_string();
+            let message = t!("editor.delete_key_prompt", key = key.display(), locale = locale).to_string();
             let server_state = server_state.clone();
             let key = key.clone();
 
@@ -223,9 +234,17 @@ impl ZedisEditor {
             return;
         };
         editor.clone().update(cx, move |state, cx| {
+            // Bytes edited in the hex grid are written by ranges
+            if let Some(edit) = state.hex_edit(cx) {
+                self.server_state.update(cx, move |state, cx| {
+                    state.save_bytes_edit(key, edit, cx);
+                });
+                return;
+            }
             let value = state.value(cx);
+            let compression = state.selected_compression();
             self.server_state.update(cx, move |state, cx| {
-                state.save_value(key, value, cx);
+                state.save_value(key, value, compression, cx);
             });
         });
     }
@@ -415,7 +434,7 @@ impl ZedisEditor {
                 .into_any_element(),
         );
 
-        let content = key.clone();
+        let content = key.display();
         h_flex()
             .p_2()
             .border_b_1()
@@ -441,7 +460,7 @@ impl ZedisEditor {
                     .w_0()
                     .overflow_hidden()
                     .mx_2()
-                    .child(Label::new(key).text_ellipsis().whitespace_nowrap()),
+                    .child(Label::new(key.display()).text_ellipsis().whitespace_nowrap()),
             )
             .children(btns)
     }
@@ -462,6 +481,18 @@ impl ZedisEditor {
         if key_type != KeyType::Hash {
             let _ = self.hash_editor.take();
         }
+        if key_type != KeyType::Stream {
+            let _ = self.stream_editor.take();
+        }
+        if key_type != KeyType::Vectorset {
+            let _ = self.vectorset_editor.take();
+        }
+        if key_type != KeyType::Timeseries {
+            let _ = self.timeseries_editor.take();
+        }
+        if !key_type.is_probabilistic() {
+            let _ = self.probabilistic_editor.take();
+        }
     }
 
     /// Render the appropriate editor based on the key type
@@ -509,6 +540,46 @@ impl ZedisEditor {
                 });
                 editor.clone().into_any_element()
             }
+            KeyType::Stream => {
+                self.reset_editors(KeyType::Stream);
+                let editor = self.stream_editor.get_or_insert_with(|| {
+                    debug!("Creating new stream editor");
+                    cx.new(|cx| ZedisStreamEditor::new(self.server_state.clone(), window, cx))
+                });
+                editor.clone().into_any_element()
+            }
+            KeyType::Vectorset => {
+                self.reset_editors(KeyType::Vectorset);
+                let editor = self.vectorset_editor.get_or_insert_with(|| {
+                    debug!("Creating new vectorset editor");
+                    cx.new(|cx| ZedisVectorsetEditor::new(self.server_state.clone(), window, cx))
+                });
+                editor.clone().into_any_element()
+            }
+            KeyType::Timeseries => {
+                self.reset_editors(KeyType::Timeseries);
+                let editor = self.timeseries_editor.get_or_insert_with(|| {
+                    debug!("Creating new timeseries editor");
+                    cx.new(|cx| ZedisTimeseriesEditor::new(self.server_state.clone(), window, cx))
+                });
+                editor.clone().into_any_element()
+            }
+            key_type if key_type.is_probabilistic() => {
+                self.reset_editors(key_type);
+                // Each structure has its own query, recreate the inspector when the type changes
+                if self
+                    .probabilistic_editor
+                    .as_ref()
+                    .is_some_and(|editor| editor.read(cx).key_type() != key_type)
+                {
+                    let _ = self.probabilistic_editor.take();
+                }
+                let editor = self.probabilistic_editor.get_or_insert_with(|| {
+                    debug!("Creating new probabilistic editor");
+                    cx.new(|cx| ZedisProbabilisticEditor::new(self.server_state.clone(), window, cx))
+                });
+                editor.clone().into_any_element()
+            }
             _ => {
                 // Default to bytes editor for String type and other types
                 self.reset_editors(KeyType::String);
@@ -0,0 +1,484 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Geo view of sorted sets.
+//!
+//! This module shows a geo index (a sorted set with geohash scores) by position. It supports:
+//! - Viewing the longitude, latitude and geohash of the members (GEOPOS, GEOHASH)
+//! - Searching by radius or box from a member or a coordinate (GEOSEARCH)
+//! - Drawing the members as a scatter plot, the search results are highlighted
+//! - Adding members by coordinate (GEOADD) and removing members (ZREM)
+
+use crate::{
+    components::{DecodeInput, FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    states::{
+        GEO_UNITS, GeoSearchQuery, RedisGeoMember, RedisValue, ServerEvent, ZedisServerState, i18n_common,
+        i18n_zset_editor,
+    },
+    views::{KvTableColumn, ZedisKvTable},
+};
+use gpui::{
+    App, Bounds, Corners, Entity, SharedString, Subscription, Window, canvas, div, fill, point, prelude::*, px, size,
+};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, IndexPath, Sizable, WindowExt,
+    button::Button,
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    select::{Select, SelectState},
+    v_flex,
+};
+use std::rc::Rc;
+
+/// Height of the scatter plot in pixels
+const PLOT_HEIGHT: f32 = 180.;
+/// Size of a point of the scatter plot in pixels
+const DOT_SIZE: f32 = 6.;
+/// Width of the coordinate columns in pixels
+const COORDINATE_COLUMN_WIDTH: f32 = 130.;
+/// Search shapes of GEOSEARCH
+const GEO_SHAPES: [&str; 2] = ["BYRADIUS", "BYBOX"];
+
+/// Data adapter for the positions of a geo sorted set to work with the KV table component.
+///
+/// The rows are the GEOSEARCH result when a search is active, otherwise the loaded members.
+struct ZedisGeoValues {
+    /// Current Redis ZSET value data
+    value: RedisValue,
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+}
+
+impl ZedisGeoValues {
+    /// Returns the member shown at the row.
+    fn row(&self, row_ix: usize) -> Option<&RedisGeoMember> {
+        self.value.zset_value()?.geo.as_ref()?.rows().get(row_ix)
+    }
+    /// Checks whether the rows are a GEOSEARCH result.
+    fn is_search(&self) -> bool {
+        self.value
+            .zset_value()
+            .and_then(|zset| zset.geo.as_ref())
+            .is_some_and(|geo| geo.search.is_some())
+    }
+}
+
+impl ZedisKvFetcher for ZedisGeoValues {
+    /// Retrieves a cell value for the table at the given row and column.
+    ///
+    /// Column layout:
+    /// - Column 1: Member name
+    /// - Column 2: Longitude
+    /// - Column 3: Latitude
+    /// - Column 4: Geohash
+    /// - Column 5: Distance from the search origin
+    fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+        let item = self.row(row_ix)?;
+        let value = match col_ix {
+            2 => format!("{:.6}", item.longitude).into(),
+            3 => format!("{:.6}", item.latitude).into(),
+            4 => item.geohash.clone(),
+            5 => item
+                .distance
+                .map(|distance| format!("{distance:.4}").into())
+                .unwrap_or_default(),
+            _ => item.member.display(),
+        };
+        Some(value)
+    }
+
+    /// Returns the member to decode with the value decoders, coordinates are shown as is.
+    fn decode_input(&self, row_ix: usize, col_ix: usize, cx: &App) -> Option<DecodeInput> {
+        if col_ix != 1 {
+            return None;
+        }
+        DecodeInput::new(&self.server_state, None, &self.row(row_ix)?.member, cx)
+    }
+
+    /// Returns the number of search results, or the cardinality of the ZSET.
+    fn count(&self) -> usize {
+        if self.is_search() {
+            return self.rows_count();
+        }
+        self.value.zset_value().map_or(0, |v| v.size)
+    }
+
+    /// Returns the number of shown rows, members without a position are left out.
+    fn rows_count(&self) -> usize {
+        self.value
+            .zset_value()
+            .and_then(|zset| zset.geo.as_ref())
+            .map_or(0, |geo| geo.rows().len())
+    }
+
+    /// Positions are read only, they are changed by adding the member again.
+    fn can_update(&self) -> bool {
+        false
+    }
+
+    /// Checks if all members have been loaded, a search result is complete.
+    fn is_done(&self) -> bool {
+        self.is_search()
+            || self
+                .value
+                .zset_value()
+                .is_some_and(|v| v.values.len() == v.size || v.done)
+    }
+
+    /// Triggers loading of the next batch of members, their positions are loaded after.
+    fn load_more(&self, _window: &mut Window, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.load_more_zset_value(cx);
+        });
+    }
+
+    /// Removes the member of the row with ZREM.
+    fn remove(&self, index: usize, cx: &mut App) {
+        let Some(item) = self.row(index) else {
+            return;
+        };
+        let member = item.member.clone();
+        self.server_state.update(cx, |this, cx| {
+            this.remove_zset_value(member, cx);
+        });
+    }
+
+    /// Applies a filter to the members by pattern matching.
+    fn filter(&self, keyword: SharedString, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.filter_zset_value(keyword, cx);
+        });
+    }
+
+    /// Opens a dialog to add a member by coordinate with GEOADD.
+    fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 3 || values[2].trim().is_empty() {
+                return false;
+            }
+            let (Ok(longitude), Ok(latitude)) = (values[0].trim().parse::<f64>(), values[1].trim().parse::<f64>())
+            else {
+                return false;
+            };
+
+            server_state.update(cx, |this, cx| {
+                this.add_geo_value(longitude, latitude, values[2].clone(), cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_zset_editor(cx, "longitude"))
+                .with_placeholder(i18n_zset_editor(cx, "longitude_placeholder"))
+                .with_focus(),
+            FormField::new(i18n_zset_editor(cx, "latitude"))
+                .with_placeholder(i18n_zset_editor(cx, "latitude_placeholder")),
+            FormField::new(i18n_common(cx, "value")).with_placeholder(i18n_common(cx, "value_placeholder")),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_zset_editor(cx, "geo_add_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Creates a new data adapter instance.
+    fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self {
+        Self { server_state, value }
+    }
+}
+
+/// Geo view of a sorted set.
+///
+/// Shows the GEOSEARCH toolbar and the scatter plot above the table of positions.
+pub struct ZedisGeoEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// The table component that renders the positions
+    table_state: Entity<ZedisKvTable<ZedisGeoValues>>,
+    /// Input for the search origin, a member or `longitude,latitude`
+    origin_state: Entity<InputState>,
+    /// Select for the search shape (radius or box)
+    shape_state: Entity<SelectState<Vec<String>>>,
+    /// Input for the radius, or `width,height` of the box
+    size_state: Entity<InputState>,
+    /// Select for the distance unit
+    unit_state: Entity<SelectState<Vec<String>>>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisGeoEditor {
+    /// Creates a new geo view instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let table_state = cx.new(|cx| {
+            ZedisKvTable::<ZedisGeoValues>::new(
+                vec![
+                    KvTableColumn::new("Member", None).with_readonly(true),
+                    KvTableColumn::new("Longitude", Some(COORDINATE_COLUMN_WIDTH)).with_readonly(true),
+                    KvTableColumn::new("Latitude", Some(COORDINATE_COLUMN_WIDTH)).with_readonly(true),
+                    KvTableColumn::new("Geohash", Some(COORDINATE_COLUMN_WIDTH)).with_readonly(true),
+                    KvTableColumn::new("Distance", Some(COORDINATE_COLUMN_WIDTH)).with_readonly(true),
+                ],
+                server_state.clone(),
+                window,
+                cx,
+            )
+        });
+        let origin_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_zset_editor(cx, "geo_origin_placeholder"))
+        });
+        let shapes = GEO_SHAPES.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let shape_state = cx.new(|cx| SelectState::new(shapes, Some(IndexPath::new(0)), window, cx));
+        let size_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_zset_editor(cx, "geo_size_placeholder"))
+        });
+        let units = GEO_UNITS.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let unit_state = cx.new(|cx| SelectState::new(units, Some(IndexPath::new(1)), window, cx));
+
+        let mut subscriptions = vec![];
+        for state in [&origin_state, &size_state] {
+            subscriptions.push(cx.subscribe(state, |this, _, event, cx| {
+                if matches!(event, InputEvent::PressEnter { .. }) {
+                    this.handle_search(cx);
+                }
+            }));
+        }
+        subscriptions.push(cx.subscribe(&server_state, |_this, _, event, cx| {
+            if matches!(
+                event,
+                ServerEvent::ValueLoaded | ServerEvent::ValueUpdated | ServerEvent::ValueAdded
+            ) {
+                cx.notify();
+            }
+        }));
+
+        Self {
+            server_state,
+            table_state,
+            origin_state,
+            shape_state,
+            size_state,
+            unit_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Runs GEOSEARCH with the origin, shape, size and unit of the toolbar.
+    fn handle_search(&mut self, cx: &mut Context<Self>) {
+        let origin = self.origin_state.read(cx).value();
+        let size = self.size_state.read(cx).value();
+        if origin.trim().is_empty() || size.trim().is_empty() {
+            return;
+        }
+        let by_box = self
+            .shape_state
+            .read(cx)
+            .selected_value()
+            .is_some_and(|value| value.as_str() == GEO_SHAPES[1]);
+        let unit = self
+            .unit_state
+            .read(cx)
+            .selected_value()
+            .map(|value| SharedString::from(value.clone()))
+            .unwrap_or_else(|| GEO_UNITS[0].into());
+        let query = GeoSearchQuery {
+            origin,
+            by_box,
+            size,
+            unit,
+        };
+        self.server_state.update(cx, |state, cx| {
+            state.search_geo_value(query, cx);
+        });
+    }
+
+    /// Renders the toolbar with the GEOSEARCH options.
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_busy = self.server_state.read(cx).value().is_some_and(|v| v.is_busy());
+
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(div().flex_1().child(Input::new(&self.origin_state).small()))
+            .child(div().w(px(120.)).child(Select::new(&self.shape_state).small()))
+            .child(div().w(px(140.)).child(Input::new(&self.size_state).small()))
+            .child(div().w(px(80.)).child(Select::new(&self.unit_state).small()))
+            .child(
+                Button::new("geo-editor-search-btn")
+                    .outline()
+                    .small()
+                    .icon(IconName::Search)
+                    .label(i18n_zset_editor(cx, "geo_search"))
+                    .tooltip(i18n_zset_editor(cx, "geo_search_tooltip"))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_search(cx);
+                    })),
+            )
+            .child(
+                Button::new("geo-editor-clear-btn")
+                    .outline()
+                    .small()
+                    .icon(IconName::CircleX)
+                    .tooltip(i18n_zset_editor(cx, "geo_clear_tooltip"))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            state.clear_geo_search(cx);
+                        });
+                    })),
+            )
+    }
+
+    /// Renders the loaded members as a scatter plot, the search results are highlighted.
+    fn render_plot(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted_color = cx.theme().muted_foreground;
+        let border_color = cx.theme().border;
+        let highlight_color = cx.theme().primary;
+        let geo = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.zset_value())
+            .and_then(|zset| zset.geo.clone());
+        let Some(geo) = geo.filter(|geo| !geo.members.is_empty() || geo.search.as_ref().is_some_and(|s| !s.is_empty()))
+        else {
+            return v_flex()
+                .h(px(PLOT_HEIGHT))
+                .items_center()
+                .justify_center()
+                .border_b_1()
+                .border_color(border_color)
+                .child(
+                    Label::new(i18n_zset_editor(cx, "geo_no_data"))
+                        .text_sm()
+                        .text_color(muted_color),
+                );
+        };
+
+        let points = geo
+            .members
+            .iter()
+            .map(|item| (item.longitude, item.latitude, false))
+            .chain(
+                geo.search
+                    .iter()
+                    .flatten()
+                    .map(|item| (item.longitude, item.latitude, true)),
+            )
+            .collect::<Vec<_>>();
+        let (min_lon, max_lon, min_lat, max_lat) = points.iter().fold(
+            (f64::MAX, f64::MIN, f64::MAX, f64::MIN),
+            |(min_lon, max_lon, min_lat, max_lat), (lon, lat, _)| {
+                (
+                    min_lon.min(*lon),
+                    max_lon.max(*lon),
+                    min_lat.min(*lat),
+                    max_lat.max(*lat),
+                )
+            },
+        );
+        let lon_span = if max_lon > min_lon { max_lon - min_lon } else { 1. };
+        let lat_span = if max_lat > min_lat { max_lat - min_lat } else { 1. };
+
+        let plot = canvas(
+            |_, _, _| {},
+            move |bounds: Bounds<gpui::Pixels>, _, window, _| {
+                let dot = px(DOT_SIZE);
+                let width = bounds.size.width - dot;
+                let height = bounds.size.height - dot;
+                // Search results are painted last, so they stay on top of the members
+                for (lon, lat, highlighted) in points.iter() {
+                    let x_ratio = ((lon - min_lon) / lon_span) as f32;
+                    let y_ratio = (1. - (lat - min_lat) / lat_span) as f32;
+                    let origin = point(bounds.origin.x + width * x_ratio, bounds.origin.y + height * y_ratio);
+                    let color = if *highlighted { highlight_color } else { muted_color };
+                    window.paint_quad(
+                        fill(Bounds::new(origin, size(dot, dot)), color).corner_radii(Corners::all(dot / 2.)),
+                    );
+                }
+            },
+        )
+        .size_full();
+
+        let coordinate_label = |value: f64| Label::new(format!("{value:.4}")).text_xs().text_color(muted_color);
+
+        v_flex()
+            .p_2()
+            .gap_1()
+            .border_b_1()
+            .border_color(border_color)
+            .child(
+                h_flex()
+                    .h(px(PLOT_HEIGHT))
+                    .gap_2()
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .justify_between()
+                            .child(coordinate_label(max_lat))
+                            .child(coordinate_label(min_lat)),
+                    )
+                    .child(
+                        div()
+                            .flex_1()
+                            .h_full()
+                            .border_1()
+                            .border_color(border_color)
+                            .child(plot),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .justify_between()
+                    .child(coordinate_label(min_lon))
+                    .child(coordinate_label(max_lon)),
+            )
+    }
+}
+
+impl Render for ZedisGeoEditor {
+    /// Renders the search toolbar, scatter plot and position table.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .child(self.render_toolbar(cx))
+            .child(self.render_plot(cx))
+            .child(v_flex().flex_1().h_0().child(self.table_state.clone()))
+            .into_any_element()
+    }
+}
@@ -22,15 +22,25 @@
 //! - Removing field-value pairs
 //! - Filtering fields with pattern matching
 //! - Incremental loading of large HASHes with pagination
+//! - Showing and updating per-field ttls (Redis 7.4+)
+//! - Decoding compressed and structured field values
 
 use crate::{
-    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
-    states::{RedisValue, ZedisServerState, i18n_common, i18n_hash_editor},
+    assets::CustomIconName,
+    components::{DecodeInput, FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    helpers::{RedisBytes, format_duration},
+    states::{HashFieldTtlOperation, RedisValue, ServerEvent, ZedisServerState, i18n_common, i18n_hash_editor},
     views::{KvTableColumn, ZedisKvTable},
 };
-use gpui::{App, Entity, SharedString, Window, div, prelude::*};
-use gpui_component::WindowExt;
-use std::rc::Rc;
+use chrono::Local;
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*};
+use gpui_component::{
+    ActiveTheme, Disableable, Sizable, WindowExt, button::Button, checkbox::Checkbox, h_flex, v_flex,
+};
+use std::{rc::Rc, time::Duration};
+
+/// Width of the field ttl column in pixels
+const TTL_COLUMN_WIDTH: f32 = 120.;
 
 /// Data adapter for Redis HASH values to work with the KV table component.
 ///
@@ -58,16 +68,36 @@ impl ZedisKvFetcher for ZedisHashValues {
     /// Column layout:
     /// - Column 1: Field name
     /// - Column 2: Field value
+    /// - Column 3: Remaining ttl of the field (Redis 7.4+)
     fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
         let hash = self.value.hash_value()?;
         let (field, value) = hash.values.get(row_ix)?;
 
-        // Column 2 is the value, others show the field name
-        if col_ix == 2 {
-            Some(value.clone())
-        } else {
-            Some(field.clone())
+        match col_ix {
+            2 => Some(value.display()),
+            3 => {
+                // Always return a value, the edit form maps the values by position
+                let ttl = hash.field_expire_at.get(field).map_or_else(
+                    || "--".to_string(),
+                    |expire_at| {
+                        let remaining = expire_at.saturating_sub(Local::now().timestamp_millis()).max(0);
+                        format_duration(Duration::from_millis(remaining as u64))
+                    },
+                );
+                Some(ttl.into())
+            }
+            // Other columns show the field name
+            _ => Some(field.display()),
+        }
+    }
+
+    /// Returns the field value to decode, decoder rules also match the `key#field` of the value.
+    fn decode_input(&self, row_ix: usize, col_ix: usize, cx: &App) -> Option<DecodeInput> {
+        if col_ix != 2 {
+            return None;
         }
+        let (field, value) = self.value.hash_value()?.values.get(row_ix)?;
+        DecodeInput::new(&self.server_state, Some(field), value, cx)
     }
 
     /// Returns the total number of fields in the HASH (from Redis HLEN).
@@ -82,9 +112,9 @@ impl ZedisKvFetcher for ZedisHashValues {
 
     /// Specifies which columns are read-only in the table.
     ///
-    /// Column 1 (field name) is read-only; only the value can be edited inline.
+    /// Column 1 (field name) and column 3 (ttl) are read-only; only the value can be edited inline.
     fn readonly_columns(&self) -> Vec<usize> {
-        vec![1]
+        vec![1, 3]
     }
 
     /// Returns the number of currently loaded rows (not total HASH size).
@@ -141,18 +171,20 @@ impl ZedisKvFetcher for ZedisHashValues {
     ///
     /// Called when the user edits the value column directly in the table.
     /// Updates the value for the existing field using Redis HSET.
-    fn handle_update_value(&self, _row_ix: usize, values: Vec<SharedString>, _window: &mut Window, cx: &mut App) {
-        // Extract field name and new value from values
-        let Some(field) = values.first() else {
+    fn handle_update_value(&self, row_ix: usize, values: Vec<SharedString>, _window: &mut Window, cx: &mut App) {
+        // Use the original field bytes of the row, the displayed name may be escaped
+        let Some((field, _value)) = self.value.hash_value().and_then(|hash| hash.values.get(row_ix)) else {
             return;
         };
         let Some(value) = values.get(1) else {
             return;
         };
 
-        // Execute update operation
+        // Execute update operation, the form shows the escaped display of the value
+        let field = field.clone();
+        let value = RedisBytes::from_display(value);
         self.server_state.update(cx, |this, cx| {
-            this.update_hash_value(field.clone(), value.clone(), cx);
+            this.update_hash_value(field, value, cx);
         });
     }
 
@@ -206,10 +238,15 @@ impl ZedisKvFetcher for ZedisHashValues {
 ///
 /// Provides a table-based UI for viewing and managing Redis HASH values.
 /// Wraps the generic `ZedisKvTable` component with HASH-specific configuration
-/// including two columns (field name and field value).
+/// including two columns (field name and field value), plus a ttl column and
+/// a ttl toolbar when the server supports hash field expiration.
 pub struct ZedisHashEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
     /// The table component that renders the HASH field-value pairs
     table_state: Entity<ZedisKvTable<ZedisHashValues>>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
 }
 
 impl ZedisHashEditor {
@@ -221,7 +258,8 @@ impl ZedisHashEditor {
     /// * `cx` - GPUI context for component initialization
     ///
     /// # Returns
-    /// A new `ZedisHashEditor` instance with a two-column table (Field and Value)
+    /// A new `ZedisHashEditor` instance with a two-column table (Field and Value),
+    /// and a TTL column on Redis 7.4+
     pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
         let window_width = window.viewport_size().width.to_f64();
         let field_width = if window_width > 1800. {
@@ -232,26 +270,151 @@ impl ZedisHashEditor {
             0.4
         };
 
+        let field_ttl = server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.hash_value())
+            .is_some_and(|hash| hash.field_ttl);
+
         // Initialize the KV table with two columns: field and value
-        let table_state = cx.new(|cx| {
-            ZedisKvTable::<ZedisHashValues>::new(
-                vec![
-                    KvTableColumn::new("Field", Some(field_width)).with_readonly(true), // Field name column (flexible width)
-                    KvTableColumn::new("Value", None), // Field value column (flexible width)
-                ],
-                server_state,
-                window,
-                cx,
-            )
+        let mut columns = vec![
+            KvTableColumn::new("Field", Some(field_width)).with_readonly(true), // Field name column (flexible width)
+            KvTableColumn::new("Value", None),                                  // Field value column (flexible width)
+        ];
+        if field_ttl {
+            columns.push(KvTableColumn::new("TTL", Some(TTL_COLUMN_WIDTH)).with_readonly(true));
+        }
+        let table_state = cx.new(|cx| ZedisKvTable::<ZedisHashValues>::new(columns, server_state.clone(), window, cx));
+
+        // Refresh the toolbar when the value changes
+        let subscriptions = vec![cx.subscribe(&server_state, |_this, _, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded | ServerEvent::ValueUpdated) {
+                cx.notify();
+            }
+        })];
+
+        Self {
+            server_state,
+            table_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Opens a dialog to update the ttl of a field with HEXPIRE, HPEXPIREAT or HPERSIST.
+    fn handle_update_field_ttl(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 3 || values[0].is_empty() {
+                return false;
+            }
+            let operation = match values[1].as_str() {
+                "1" => HashFieldTtlOperation::ExpireAt,
+                "2" => HashFieldTtlOperation::Persist,
+                _ => HashFieldTtlOperation::Expire,
+            };
+            if operation != HashFieldTtlOperation::Persist && values[2].trim().is_empty() {
+                return false;
+            }
+
+            server_state.update(cx, |this, cx| {
+                // Binary fields are shown escaped, unescape the typed name to the field bytes
+                let field = RedisBytes::from_display(&values[0]);
+                this.update_hash_field_ttl(field, operation, values[2].clone(), cx);
+            });
+
+            window.close_dialog(cx);
+            true
         });
 
-        Self { table_state }
+        let fields = vec![
+            FormField::new(i18n_common(cx, "field"))
+                .with_placeholder(i18n_common(cx, "field_placeholder"))
+                .with_focus(),
+            FormField::new(i18n_hash_editor(cx, "field_ttl_operation")).with_options(vec![
+                HashFieldTtlOperation::Expire.as_str().into(),
+                HashFieldTtlOperation::ExpireAt.as_str().into(),
+                HashFieldTtlOperation::Persist.as_str().into(),
+            ]),
+            FormField::new(i18n_hash_editor(cx, "field_ttl_value"))
+                .with_placeholder(i18n_hash_editor(cx, "field_ttl_value_placeholder")),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_hash_editor(cx, "field_ttl_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Renders the toolbar with the expiring fields filter and the field ttl action.
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let readonly = server_state.readonly();
+        let (expiring_only, is_busy) = server_state
+            .value()
+            .map(|v| (v.hash_value().is_some_and(|hash| hash.expiring_only), v.is_busy()))
+            .unwrap_or_default();
+
+        h_flex()
+            .p_2()
+            .gap_3()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(
+                Button::new("hash-editor-field-ttl-btn")
+                    .outline()
+                    .small()
+                    .icon(CustomIconName::FilePenLine)
+                    .label(i18n_hash_editor(cx, "field_ttl"))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_hash_editor(cx, "field_ttl_tooltip")
+                    })
+                    .disabled(readonly || is_busy)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_update_field_ttl(window, cx);
+                    })),
+            )
+            .child(
+                Checkbox::new("hash-editor-expiring-only")
+                    .label(i18n_hash_editor(cx, "expiring_only"))
+                    .checked(expiring_only)
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, checked: &bool, _window, cx| {
+                        let expiring_only = *checked;
+                        this.server_state.update(cx, |state, cx| {
+                            state.filter_hash_expiring(expiring_only, cx);
+                        });
+                    })),
+            )
     }
 }
 
 impl Render for ZedisHashEditor {
-    /// Renders the HASH editor as a full-size container with the table.
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        div().size_full().child(self.table_state.clone()).into_any_element()
+    /// Renders the HASH editor as a full-size container with the table,
+    /// and the field ttl toolbar on Redis 7.4+.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let field_ttl = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.hash_value())
+            .is_some_and(|hash| hash.field_ttl);
+        if !field_ttl {
+            return div().size_full().child(self.table_state.clone()).into_any_element();
+        }
+
+        v_flex()
+            .size_full()
+            .child(self.render_toolbar(cx))
+            .child(v_flex().flex_1().h_0().child(self.table_state.clone()))
+            .into_any_element()
     }
 }
@@ -0,0 +1,262 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! HyperLogLog view of string values.
+//!
+//! This module shows a HyperLogLog by its estimated cardinality instead of the raw registers. It supports:
+//! - Showing the register encoding (sparse or dense) and the cardinality (PFCOUNT)
+//! - Adding elements (PFADD)
+//! - Merging into another key (PFMERGE)
+
+use crate::states::{ServerEvent, ZedisServerState, i18n_common, i18n_hyperloglog_editor};
+use gpui::{Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, Sizable,
+    button::Button,
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    scroll::ScrollableElement,
+    v_flex,
+};
+use humansize::{DECIMAL, format_size};
+
+/// Width of the name column of the summary list
+const NAME_COLUMN_WIDTH: f32 = 220.;
+/// Number of registers of a HyperLogLog
+const HYPERLOGLOG_REGISTERS: usize = 16384;
+
+/// HyperLogLog view of a string value.
+///
+/// Shows the encoding and the cardinality, with inputs to add elements
+/// and to merge the HyperLogLog into another key.
+pub struct ZedisHyperLogLogEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// Input for the whitespace separated elements to add
+    elements_state: Entity<InputState>,
+    /// Input for the key to merge into
+    target_state: Entity<InputState>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisHyperLogLogEditor {
+    /// Creates a new HyperLogLog view instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let elements_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_hyperloglog_editor(cx, "elements_placeholder"))
+        });
+        let target_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_hyperloglog_editor(cx, "target_placeholder"))
+        });
+
+        let mut subscriptions = vec![];
+        subscriptions.push(cx.subscribe_in(&elements_state, window, |this, _, event, window, cx| {
+            if matches!(event, InputEvent::PressEnter { .. }) {
+                this.handle_add(window, cx);
+            }
+        }));
+        subscriptions.push(cx.subscribe_in(&target_state, window, |this, _, event, window, cx| {
+            if matches!(event, InputEvent::PressEnter { .. }) {
+                this.handle_merge(window, cx);
+            }
+        }));
+        subscriptions.push(cx.subscribe(&server_state, |_this, _, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded | ServerEvent::ValueUpdated) {
+                cx.notify();
+            }
+        }));
+
+        Self {
+            server_state,
+            elements_state,
+            target_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Adds the elements of the input with PFADD, the input is cleared.
+    fn handle_add(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let input = self.elements_state.read(cx).value();
+        if input.trim().is_empty() {
+            return;
+        }
+        self.server_state.update(cx, |state, cx| {
+            state.add_hyperloglog_elements(input, cx);
+        });
+        self.elements_state.update(cx, |state, cx| {
+            state.set_value(SharedString::default(), window, cx);
+        });
+    }
+
+    /// Merges the HyperLogLog into the key of the input with PFMERGE.
+    fn handle_merge(&mut self, _window: &mut Window, cx: &mut Context<Self>) {
+        let target = self.target_state.read(cx).value();
+        if target.trim().is_empty() {
+            return;
+        }
+        self.server_state.update(cx, |state, cx| {
+            state.merge_hyperloglog(target, cx);
+        });
+    }
+
+    /// Renders name-value rows in a bordered list.
+    fn render_rows(rows: Vec<(SharedString, SharedString)>, cx: &Context<Self>) -> impl IntoElement {
+        let border_color = cx.theme().border;
+        let muted_color = cx.theme().muted_foreground;
+        v_flex()
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .children(rows.into_iter().map(|(name, value)| {
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        Label::new(name)
+                            .text_sm()
+                            .w(px(NAME_COLUMN_WIDTH))
+                            .text_ellipsis()
+                            .text_color(muted_color),
+                    )
+                    .child(Label::new(value).text_sm().flex_1().text_ellipsis())
+            }))
+    }
+
+    /// Renders an input with its action button, disabled in readonly mode.
+    fn render_action(
+        &self,
+        id: &'static str,
+        state: &Entity<InputState>,
+        icon: IconName,
+        action: &'static str,
+        handler: fn(&mut Self, &mut Window, &mut Context<Self>),
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let readonly = server_state.readonly();
+        h_flex()
+            .gap_2()
+            .items_center()
+            .child(div().flex_1().child(Input::new(state).small()))
+            .child(
+                Button::new(id)
+                    .outline()
+                    .small()
+                    .icon(icon)
+                    .label(i18n_hyperloglog_editor(cx, action))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_hyperloglog_editor(cx, &format!("{action}_tooltip"))
+                    })
+                    .disabled(is_busy || readonly)
+                    .on_click(cx.listener(move |this, _, window, cx| {
+                        handler(this, window, cx);
+                    })),
+            )
+    }
+}
+
+impl Render for ZedisHyperLogLogEditor {
+    /// Renders the HyperLogLog summary, the PFADD input and the PFMERGE input.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let Some(value) = self.server_state.read(cx).value().and_then(|v| v.bytes_value()) else {
+            return v_flex().into_any_element();
+        };
+        let Some(hyperloglog) = value.hyperloglog.clone() else {
+            return v_flex().into_any_element();
+        };
+        let mut rows = vec![
+            (
+                i18n_hyperloglog_editor(cx, "cardinality"),
+                hyperloglog.cardinality.to_string().into(),
+            ),
+            (
+                i18n_hyperloglog_editor(cx, "encoding"),
+                i18n_hyperloglog_editor(cx, hyperloglog.encoding.as_str()),
+            ),
+            (
+                i18n_hyperloglog_editor(cx, "registers"),
+                HYPERLOGLOG_REGISTERS.to_string().into(),
+            ),
+            (
+                i18n_hyperloglog_editor(cx, "size"),
+                format_size(value.bytes.len(), DECIMAL).into(),
+            ),
+        ];
+        if let Some((target, cardinality)) = hyperloglog.merged {
+            rows.push((
+                format!("{} {target}", i18n_hyperloglog_editor(cx, "merged_into")).into(),
+                cardinality.to_string().into(),
+            ));
+        }
+        let muted_color = cx.theme().muted_foreground;
+        let elements_state = self.elements_state.clone();
+        let target_state = self.target_state.clone();
+
+        v_flex()
+            .size_full()
+            .p_2()
+            .gap_3()
+            .overflow_y_scrollbar()
+            .child(
+                Label::new(i18n_hyperloglog_editor(cx, "title"))
+                    .text_sm()
+                    .text_color(muted_color),
+            )
+            .child(Self::render_rows(rows, cx))
+            .child(
+                Label::new(i18n_hyperloglog_editor(cx, "add_title"))
+                    .text_sm()
+                    .text_color(muted_color),
+            )
+            .child(self.render_action(
+                "hyperloglog-add-btn",
+                &elements_state,
+                IconName::Plus,
+                "add",
+                Self::handle_add,
+                cx,
+            ))
+            .child(
+                Label::new(i18n_hyperloglog_editor(cx, "merge_title"))
+                    .text_sm()
+                    .text_color(muted_color),
+            )
+            .child(self.render_action(
+                "hyperloglog-merge-btn",
+                &target_state,
+                IconName::ArrowRight,
+                "merge",
+                Self::handle_merge,
+                cx,
+            ))
+            .into_any_element()
+    }
+}
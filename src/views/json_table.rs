@@ -0,0 +1,380 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Table view of JSON arrays of objects.
+//!
+//! This module shows the records of a JSON array as table rows. It supports:
+//! - Inferring the columns from the union of the object keys, in the order they appear
+//! - Sorting by a column, values of different types are ordered by type
+//! - Exporting the rows in the sorted order as CSV or JSON
+//!
+//! It is used by the table view mode of string values and by the detail pane of hash and list cells.
+
+use crate::helpers::{get_font_family, get_home_dir};
+use crate::states::{ZedisServerState, i18n_json_table};
+use gpui::{App, Edges, Entity, SharedString, TextAlign, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, IconName, Sizable, StyledExt,
+    button::Button,
+    h_flex,
+    label::Label,
+    table::{Column, ColumnSort, Table, TableDelegate, TableState},
+    v_flex,
+};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+/// Width of the index column in pixels
+const INDEX_COLUMN_WIDTH: f32 = 60.;
+/// Width of a value column in pixels
+const VALUE_COLUMN_WIDTH: f32 = 160.;
+/// Maximum number of chars of a cell
+const CELL_MAX_CHARS: usize = 200;
+
+/// Returns whether the value is a non-empty JSON array of objects, which can be shown as a table.
+pub fn is_json_table(value: &Value) -> bool {
+    value
+        .as_array()
+        .is_some_and(|items| !items.is_empty() && items.iter().all(Value::is_object))
+}
+
+/// Returns the text of a cell, strings are not quoted and null is empty.
+fn cell_text(value: Option<&Value>) -> String {
+    match value {
+        None | Some(Value::Null) => String::new(),
+        Some(Value::String(s)) => s.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// Compares two cells, missing and null values first, then booleans, numbers, strings and the others.
+fn compare_cells(a: Option<&Value>, b: Option<&Value>) -> Ordering {
+    let rank = |value: Option<&Value>| match value {
+        None | Some(Value::Null) => 0,
+        Some(Value::Bool(_)) => 1,
+        Some(Value::Number(_)) => 2,
+        Some(Value::String(_)) => 3,
+        Some(_) => 4,
+    };
+    match (a, b) {
+        (Some(Value::Bool(a)), Some(Value::Bool(b))) => a.cmp(b),
+        (Some(Value::Number(a)), Some(Value::Number(b))) => {
+            let a = a.as_f64().unwrap_or_default();
+            let b = b.as_f64().unwrap_or_default();
+            a.total_cmp(&b)
+        }
+        (Some(Value::String(a)), Some(Value::String(b))) => a.cmp(b),
+        _ => rank(a).cmp(&rank(b)).then_with(|| cell_text(a).cmp(&cell_text(b))),
+    }
+}
+
+/// Quotes a CSV field when it contains a separator, a quote or a line break.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Export format of the table
+#[derive(Clone, Copy, PartialEq)]
+enum ExportFormat {
+    Csv,
+    Json,
+}
+
+impl ExportFormat {
+    fn file_name(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "table.csv",
+            ExportFormat::Json => "table.json",
+        }
+    }
+}
+
+/// Rows of the table, sorted by keeping the indices of the records
+struct JsonTableDelegate {
+    document: Arc<Value>,
+    /// Keys of the value columns, the first column is the index
+    keys: Vec<String>,
+    columns: Vec<Column>,
+    /// Indices of the records in the sorted order
+    order: Vec<usize>,
+}
+
+impl JsonTableDelegate {
+    fn new(document: Arc<Value>) -> Self {
+        let records = document.as_array().map(Vec::as_slice).unwrap_or_default();
+        let mut keys: Vec<String> = vec![];
+        for record in records {
+            if let Some(map) = record.as_object() {
+                for key in map.keys() {
+                    if !keys.contains(key) {
+                        keys.push(key.clone());
+                    }
+                }
+            }
+        }
+        let paddings = Some(Edges {
+            top: px(2.),
+            bottom: px(2.),
+            left: px(10.),
+            right: px(10.),
+        });
+        let mut index_column = Column::new("#", "#").width(px(INDEX_COLUMN_WIDTH));
+        index_column.align = TextAlign::Right;
+        index_column.paddings = paddings;
+        let mut columns = vec![index_column];
+        columns.extend(keys.iter().map(|key| {
+            let mut column = Column::new(key.clone(), key.clone())
+                .width(px(VALUE_COLUMN_WIDTH))
+                .sortable();
+            column.paddings = paddings;
+            column
+        }));
+        Self {
+            order: (0..records.len()).collect(),
+            document,
+            keys,
+            columns,
+        }
+    }
+    fn records(&self) -> &[Value] {
+        self.document.as_array().map(Vec::as_slice).unwrap_or_default()
+    }
+    /// Returns the cell of the record, `None` when the record does not have the key.
+    fn cell(&self, row_ix: usize, key_ix: usize) -> Option<&Value> {
+        let record = self.records().get(*self.order.get(row_ix)?)?;
+        record.get(self.keys.get(key_ix)?)
+    }
+    /// Returns the rows in the sorted order as CSV, with a header of the keys.
+    fn to_csv(&self) -> String {
+        let mut lines = vec![self.keys.iter().map(|key| csv_field(key)).collect::<Vec<_>>().join(",")];
+        for row_ix in 0..self.order.len() {
+            let fields: Vec<String> = (0..self.keys.len())
+                .map(|key_ix| csv_field(&cell_text(self.cell(row_ix, key_ix))))
+                .collect();
+            lines.push(fields.join(","));
+        }
+        lines.join("\n")
+    }
+    /// Returns the rows in the sorted order as a JSON array.
+    fn to_json(&self) -> String {
+        let records = self.records();
+        let rows: Vec<&Value> = self.order.iter().filter_map(|index| records.get(*index)).collect();
+        serde_json::to_string_pretty(&rows).unwrap_or_default()
+    }
+}
+
+impl TableDelegate for JsonTableDelegate {
+    fn columns_count(&self, _: &App) -> usize {
+        self.columns.len()
+    }
+
+    fn rows_count(&self, _: &App) -> usize {
+        self.order.len()
+    }
+
+    fn column(&self, index: usize, _: &App) -> &Column {
+        &self.columns[index]
+    }
+
+    fn render_td(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        _window: &mut Window,
+        cx: &mut Context<TableState<Self>>,
+    ) -> impl IntoElement {
+        let column = self.column(col_ix, cx);
+        let base = h_flex()
+            .size_full()
+            .when_some(column.paddings, |this, paddings| this.paddings(paddings));
+        let align = column.align;
+        if col_ix == 0 {
+            return base.child(Label::new((row_ix + 1).to_string()).text_align(align).w_full());
+        }
+        let value = self.cell(row_ix, col_ix - 1);
+        let mut text = cell_text(value);
+        if let Some((index, _)) = text.char_indices().nth(CELL_MAX_CHARS) {
+            text.truncate(index);
+            text.push('…');
+        }
+        // line breaks are not shown in a single-line cell
+        let text = text.replace(['\n', '\r'], " ");
+        let label = Label::new(text).text_align(align);
+        let label = match value {
+            Some(Value::String(_)) | None => label,
+            Some(_) => label.text_color(cx.theme().muted_foreground),
+        };
+        base.child(label)
+    }
+
+    /// Sorts the rows by the column, the default sort restores the order of the array.
+    fn perform_sort(
+        &mut self,
+        col_ix: usize,
+        sort: ColumnSort,
+        _window: &mut Window,
+        _cx: &mut Context<TableState<Self>>,
+    ) {
+        let records = self.document.as_array().map(Vec::as_slice).unwrap_or_default();
+        let Some(key) = col_ix.checked_sub(1).and_then(|key_ix| self.keys.get(key_ix)) else {
+            return;
+        };
+        self.order = (0..records.len()).collect();
+        let compare = |a: &usize, b: &usize| compare_cells(records[*a].get(key), records[*b].get(key));
+        match sort {
+            ColumnSort::Ascending => self.order.sort_by(compare),
+            ColumnSort::Descending => self.order.sort_by(|a, b| compare(b, a)),
+            ColumnSort::Default => {}
+        }
+    }
+}
+
+/// Table view of a JSON array of objects.
+///
+/// Shows the row and column counts with the export actions above the virtualized table.
+/// The table is recreated on the next render when the document changes.
+pub struct ZedisJsonTable {
+    /// Reference to server state for the export notifications
+    server_state: Entity<ZedisServerState>,
+    /// Document shown in the table
+    document: Arc<Value>,
+    /// State of the table, `None` until it is created for the document
+    table_state: Option<Entity<TableState<JsonTableDelegate>>>,
+}
+
+impl ZedisJsonTable {
+    /// Creates a new table view instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for notifications
+    /// * `document` - JSON array of objects to show
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, document: Arc<Value>, _cx: &mut Context<Self>) -> Self {
+        Self {
+            server_state,
+            document,
+            table_state: None,
+        }
+    }
+
+    /// Shows another document, the sort of the table is reset.
+    pub fn set_document(&mut self, document: Arc<Value>, cx: &mut Context<Self>) {
+        if Arc::ptr_eq(&document, &self.document) {
+            return;
+        }
+        self.document = document;
+        self.table_state = None;
+        cx.notify();
+    }
+
+    /// Exports the rows in the sorted order to a file chosen by the user.
+    fn handle_export(&mut self, format: ExportFormat, cx: &mut Context<Self>) {
+        let Some(table_state) = self.table_state.as_ref() else {
+            return;
+        };
+        let delegate = table_state.read(cx).delegate();
+        let content = match format {
+            ExportFormat::Csv => delegate.to_csv(),
+            ExportFormat::Json => delegate.to_json(),
+        };
+        let directory = get_home_dir().unwrap_or_default();
+        let receiver = cx.prompt_for_new_path(&directory, Some(format.file_name()));
+        let server_state = self.server_state.clone();
+        cx.spawn(async move |_handle, cx| {
+            let Ok(Ok(Some(path))) = receiver.await else {
+                return;
+            };
+            let result = cx.background_spawn(async move { std::fs::write(&path, content) }).await;
+            let _ = server_state.update(cx, |state, cx| match result {
+                Ok(()) => state.emit_info_notification(i18n_json_table(cx, "export_success"), cx),
+                Err(e) => state.emit_error_notification(e.to_string().into(), cx),
+            });
+        })
+        .detach();
+    }
+}
+
+impl Render for ZedisJsonTable {
+    /// Renders the summary with the export actions and the table.
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let document = self.document.clone();
+        let table_state = self
+            .table_state
+            .get_or_insert_with(|| cx.new(|cx| TableState::new(JsonTableDelegate::new(document), window, cx)))
+            .clone();
+        let (rows_count, columns_count) = {
+            let delegate = table_state.read(cx).delegate();
+            (delegate.order.len(), delegate.keys.len())
+        };
+        let summary: SharedString = format!(
+            "{} {rows_count} · {} {columns_count}",
+            i18n_json_table(cx, "rows"),
+            i18n_json_table(cx, "columns")
+        )
+        .into();
+
+        let export_button = |id: &'static str, label: &'static str| {
+            Button::new(id)
+                .outline()
+                .small()
+                .icon(IconName::ArrowDown)
+                .label(i18n_json_table(cx, label))
+                .tooltip(i18n_json_table(cx, &format!("{label}_tooltip")))
+        };
+
+        v_flex()
+            .size_full()
+            .child(
+                h_flex()
+                    .p_2()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .items_center()
+                    .child(
+                        Label::new(summary)
+                            .text_sm()
+                            .flex_1()
+                            .text_color(cx.theme().muted_foreground),
+                    )
+                    .child(
+                        export_button("json-table-export-csv-btn", "export_csv").on_click(cx.listener(
+                            |this, _, _window, cx| {
+                                this.handle_export(ExportFormat::Csv, cx);
+                            },
+                        )),
+                    )
+                    .child(
+                        export_button("json-table-export-json-btn", "export_json").on_click(cx.listener(
+                            |this, _, _window, cx| {
+                                this.handle_export(ExportFormat::Json, cx);
+                            },
+                        )),
+                    ),
+            )
+            .child(
+                div().flex_1().h_0().w_full().font_family(get_font_family()).child(
+                    Table::new(&table_state)
+                        .stripe(true)
+                        .bordered(true)
+                        .scrollbar_visible(true, true),
+                ),
+            )
+    }
+}
@@ -525,7 +525,7 @@ impl ZedisKeyTree {
         // Auto-expand all folders if key count is small
         let expand_all = server_state.scan_count() < AUTO_EXPAND_THRESHOLD;
         let keys_snapshot: Vec<(SharedString, KeyType)> =
-            server_state.keys().iter().map(|(k, v)| (k.clone(), *v)).collect();
+            server_state.keys().iter().map(|(k, v)| (k.display(), *v)).collect();
         let readonly = server_state.readonly();
         let expanded_items = self.state.expanded_items.clone();
 
@@ -613,7 +613,7 @@ impl ZedisKeyTree {
     }
 
     fn handle_add_key(&mut self, window: &mut Window, cx: &mut Context<Self>) {
-        let category_list = ["String", "List", "Set", "Zset", "Hash"];
+        let category_list = ["String", "List", "Set", "Zset", "Hash", "Stream", "Json", "Timeseries"];
         let fields = vec![
             FormField::new(i18n_key_tree(cx, "category"))
                 .with_options(category_list.iter().map(|s| s.to_string().into()).collect()),
@@ -735,11 +735,13 @@ impl ZedisKeyTree {
             }
             self.update_key_tree(true, cx);
         } else {
-            let is_selected = self.server_state.read(cx).key().as_ref() == Some(&item_id);
+            let is_selected = self.server_state.read(cx).key().map(|k| k.display()) == Some(item_id.clone());
             // Select Key
             if !is_selected {
                 self.server_state.update(cx, |state, cx| {
-                    state.select_key(item_id.clone(), cx);
+                    // Tree ids are display names, resolve them back to the raw key bytes
+                    let key = state.find_key(&item_id);
+                    state.select_key(key, cx);
                 });
             }
         }
@@ -962,7 +964,8 @@ impl Render for ZedisKeyTree {
                             .child(text)
                             .on_ok(move |_, _, cx| {
                                 server_state.update(cx, |state, cx| {
-                                    state.unlink_key(keys.clone(), cx);
+                                    let keys = keys.iter().map(|key| state.find_key(key)).collect();
+                                    state.unlink_key(keys, cx);
                                 });
                                 true
                             })
@@ -983,7 +986,8 @@ impl Render for ZedisKeyTree {
                             .child(text)
                             .on_ok(move |_, _, cx| {
                                 server_state.update(cx, |state, cx| {
-                                    state.delete_key(id.clone(), cx);
+                                    let key = state.find_key(&id);
+                                    state.delete_key(key, cx);
                                 });
                                 true
                             })
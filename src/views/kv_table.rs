@@ -16,12 +16,13 @@ use crate::helpers::get_font_family;
 use crate::{
     assets::CustomIconName,
     components::{INDEX_COLUMN_NAME, ZedisKvDelegate, ZedisKvFetcher},
-    states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_kv_table},
+    states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_common, i18n_json_table, i18n_kv_table},
+    views::{ZedisJsonTable, is_json_table},
 };
 use gpui::{Entity, SharedString, Subscription, TextAlign, Window, div, prelude::*, px};
 use gpui_component::highlighter::Language;
 use gpui_component::{
-    ActiveTheme, Disableable, Icon, IconName, PixelsExt,
+    ActiveTheme, Disableable, Icon, IconName, PixelsExt, Sizable,
     button::{Button, ButtonVariants},
     form::field,
     h_flex,
@@ -30,11 +31,14 @@ use gpui_component::{
     table::{Table, TableState},
     v_flex,
 };
+use serde_json::Value;
 use std::sync::Arc;
 use tracing::info;
 
 /// Width of the keyword search input field in pixels
 const KEYWORD_INPUT_WIDTH: f32 = 200.0;
+/// Width of the action column in pixels, fits the detail, edit and remove buttons
+const ACTION_COLUMN_WIDTH: f32 = 130.0;
 
 /// Defines the type of table column for different purposes.
 #[derive(Clone, Default, PartialEq, Eq, Debug)]
@@ -85,6 +89,7 @@ impl KvTableColumn {
 /// - Keyword search/filtering
 /// - Real-time updates via server events
 /// - Loading states and pagination indicators
+/// - A detail pane with the decoded values of a row, arrays of objects can be shown as a table
 pub struct ZedisKvTable<T: ZedisKvFetcher> {
     /// Table state managing the delegate and data
     table_state: Entity<TableState<ZedisKvDelegate<T>>>,
@@ -104,6 +109,16 @@ pub struct ZedisKvTable<T: ZedisKvFetcher> {
     readonly: bool,
     /// The row index that is being edited
     edit_row: Option<usize>,
+    /// The row index whose decoded values are shown
+    detail_row: Option<usize>,
+    /// Decode chains of the values shown in the detail pane, by edit column
+    detail_formats: Vec<Option<SharedString>>,
+    /// Decoded values that are arrays of objects, by edit column
+    detail_documents: Vec<Option<Arc<Value>>>,
+    /// Edit column whose decoded value is shown as a table
+    detail_table: Option<(usize, Entity<ZedisJsonTable>)>,
+    /// Reference to server state for the table of a decoded value
+    server_state: Entity<ZedisServerState>,
     /// Columns configuration
     edit_columns: Vec<KvTableColumn>,
     /// Input states for editable cells, keyed by column index.
@@ -124,7 +139,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
     ///
     /// # Logic:
     /// 1. Adds an index column at the start (80px, right-aligned)
-    /// 2. Adds an action column at the end (100px, center-aligned)
+    /// 2. Adds an action column at the end (130px, center-aligned)
     /// 3. Calculates remaining space for columns without fixed widths
     /// 4. Distributes remaining width evenly among flexible columns
     fn new_columns(mut columns: Vec<KvTableColumn>, window: &Window, cx: &mut Context<Self>) -> Vec<KvTableColumn> {
@@ -147,7 +162,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         columns.push(KvTableColumn {
             column_type: KvTableColumnType::Action,
             name: i18n_common(cx, "action"),
-            width: Some(100.0),
+            width: Some(ACTION_COLUMN_WIDTH),
             align: Some(TextAlign::Center),
             ..Default::default()
         });
@@ -222,6 +237,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
                 // Clear search when key selection changes
                 ServerEvent::KeySelected => {
                     this.key_changed = true;
+                    this.detail_row = None;
                 }
                 _ => {}
             }
@@ -243,7 +259,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
 
         let readonly = server_state.read(cx).readonly();
         // Initialize table data and state
-        let fetcher = Self::new_values(server_state, cx);
+        let fetcher = Self::new_values(server_state.clone(), cx);
         let done = fetcher.is_done();
         let items_count = fetcher.rows_count();
         let total_count = fetcher.count();
@@ -272,6 +288,13 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             })));
         }
 
+        let view = cx.entity();
+        delegate.set_on_detail(Some(Box::new(move |row_ix, values, window, cx| {
+            view.update(cx, |state, cx| {
+                state.set_detail_values(row_ix, values, window, cx);
+            });
+        })));
+
         let table_state = cx.new(|cx| TableState::new(delegate, window, cx));
         let value_states = edit_columns
             .iter()
@@ -301,6 +324,11 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
             loading: false,
             key_changed: false,
             edit_row: None,
+            detail_row: None,
+            detail_formats: Vec::new(),
+            detail_documents: Vec::new(),
+            detail_table: None,
+            server_state,
             value_states,
             readonly,
             fetcher,
@@ -316,6 +344,7 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         window: &mut Window,
         cx: &mut Context<Self>,
     ) {
+        self.detail_row = None;
         if self.edit_row == Some(row_ix) {
             self.edit_row = None;
             return;
@@ -331,6 +360,60 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         });
     }
 
+    /// Shows the decoded values of a row in the detail pane, toggled by the same row.
+    fn set_detail_values(
+        &mut self,
+        row_ix: usize,
+        values: Vec<(SharedString, Option<SharedString>)>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.edit_row = None;
+        if self.detail_row == Some(row_ix) {
+            self.detail_row = None;
+            cx.notify();
+            return;
+        }
+        self.detail_row = Some(row_ix);
+        self.detail_formats = values.iter().map(|(_, format)| format.clone()).collect();
+        self.detail_documents = values
+            .iter()
+            .map(|(text, format)| {
+                format
+                    .as_ref()
+                    .and_then(|_| serde_json::from_str::<Value>(text).ok())
+                    .filter(is_json_table)
+                    .map(Arc::new)
+            })
+            .collect();
+        self.detail_table = None;
+        self.value_states.iter().enumerate().for_each(|(index, state)| {
+            state.update(cx, |input, cx| {
+                let Some((value, _)) = values.get(index) else {
+                    return;
+                };
+                input.set_value(value.clone(), window, cx);
+            });
+        });
+        cx.notify();
+    }
+
+    /// Shows the decoded value of the edit column as a table, or as text again.
+    fn toggle_detail_table(&mut self, index: usize, cx: &mut Context<Self>) {
+        if self
+            .detail_table
+            .as_ref()
+            .is_some_and(|(table_index, _)| *table_index == index)
+        {
+            self.detail_table = None;
+        } else if let Some(document) = self.detail_documents.get(index).cloned().flatten() {
+            let server_state = self.server_state.clone();
+            let json_table = cx.new(|cx| ZedisJsonTable::new(server_state, document, cx));
+            self.detail_table = Some((index, json_table));
+        }
+        cx.notify();
+    }
+
     /// Triggers a filter operation using the current keyword from the input field.
     fn handle_filter(&mut self, cx: &mut Context<Self>) {
         let keyword = self.keyword_state.read(cx).value();
@@ -351,40 +434,94 @@ impl<T: ZedisKvFetcher> ZedisKvTable<T> {
         self.fetcher.handle_update_value(row_ix, values, window, cx);
         self.edit_row = None;
     }
-    /// Renders the edit form for the current row.
+    /// Renders the edit form for the current row, or the readonly detail pane of the decoded values.
     fn render_edit_form(&mut self, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_detail = self.detail_row.is_some();
         let mut form = v_flex().size_full().gap_3();
         for (index, column) in self.edit_columns.iter().enumerate() {
             let Some(value_state) = self.value_states.get(index) else {
                 continue;
             };
+            // Decoded values are labeled with their decode chain
+            let label = match self.detail_formats.get(index).cloned().flatten() {
+                Some(format) if is_detail => format!("{} ({format})", column.name).into(),
+                _ => column.name.clone(),
+            };
             let input = Input::new(value_state)
-                .disabled(column.readonly)
+                .disabled(column.readonly || is_detail)
                 .h_full()
                 .p_0()
                 .font_family(get_font_family())
                 .focus_bordered(false);
 
-            let inner_content = if index != 0 {
+            // Readonly columns (e.g. the field name) are shown as compact single-line fields
+            let inner_content = if !column.readonly {
+                let has_document = is_detail && self.detail_documents.get(index).is_some_and(Option::is_some);
+                let json_table = self
+                    .detail_table
+                    .as_ref()
+                    .filter(|(table_index, _)| *table_index == index)
+                    .map(|(_, json_table)| json_table.clone());
+                let content = match json_table.clone() {
+                    Some(json_table) => json_table.into_any_element(),
+                    None => input.into_any_element(),
+                };
                 v_flex()
                     .size_full()
                     .gap_1()
-                    .child(Label::new(column.name.clone()))
-                    .child(div().flex_1().size_full().child(input))
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .items_center()
+                            .child(Label::new(label))
+                            .when(has_document, |this| {
+                                this.child(
+                                    Button::new(("kv-table-detail-table-btn", index))
+                                        .ghost()
+                                        .xsmall()
+                                        .icon(IconName::LayoutDashboard)
+                                        .label(if json_table.is_some() {
+                                            i18n_json_table(cx, "show_text")
+                                        } else {
+                                            i18n_json_table(cx, "show_table")
+                                        })
+                                        .on_click(cx.listener(move |this, _, _, cx| {
+                                            this.toggle_detail_table(index, cx);
+                                        })),
+                                )
+                            }),
+                    )
+                    .child(div().flex_1().size_full().child(content))
                     .into_any_element()
             } else {
-                field().label(column.name.clone()).child(input).into_any_element()
+                field().label(label).child(input).into_any_element()
             };
 
             let wrapped_field = v_flex()
                 .w_full()
                 .child(inner_content)
-                .when(index != 0, |this| this.flex_1().h_full());
+                .when(!column.readonly, |this| this.flex_1().h_full());
 
             form = form.child(wrapped_field);
         }
         let cancel_label = i18n_common(cx, "cancel");
         let save_label = i18n_common(cx, "save");
+        if is_detail {
+            return form
+                .child(
+                    h_flex().flex_none().w_full().justify_end().child(
+                        Button::new("close-detail-btn")
+                            .icon(IconName::CircleX)
+                            .label(i18n_common(cx, "close"))
+                            .on_click(cx.listener(|this, _, _, cx| {
+                                this.detail_row = None;
+                                this.detail_table = None;
+                                cx.notify();
+                            })),
+                    ),
+                )
+                .into_any_element();
+        }
         form.child(
             div().flex_none().child(
                 field().child(
@@ -503,7 +640,7 @@ impl<T: ZedisKvFetcher> Render for ZedisKvTable<T> {
                             .text_color(text_color),
                     ),
             )
-            .when(self.edit_row.is_some(), |this| {
+            .when(self.edit_row.is_some() || self.detail_row.is_some(), |this| {
                 this.child(
                     div()
                         .id("kv-table-on-edit-overlay")
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use crate::{
-    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
-    helpers::fast_contains_ignore_case,
+    components::{DecodeInput, FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    helpers::{RedisBytes, fast_contains_ignore_case},
     states::{RedisValue, ZedisServerState, i18n_common, i18n_list_editor},
     views::{KvTableColumn, ZedisKvTable},
 };
@@ -29,7 +29,7 @@ use tracing::info;
 /// a mapping between visible items and their original indices when filtering.
 struct ZedisListValues {
     /// Currently visible items (filtered subset or all items)
-    visible_items: Vec<SharedString>,
+    visible_items: Vec<RedisBytes>,
     /// Maps visible item indices to original list indices (Some when filtered, None otherwise)
     visible_item_indexes: Option<Vec<usize>>,
     /// The underlying Redis value data
@@ -69,7 +69,7 @@ impl ZedisListValues {
         let mut visible_items = Vec::with_capacity(capacity);
 
         for (index, item) in value.values.iter().enumerate() {
-            if fast_contains_ignore_case(item.as_str(), &keyword) {
+            if fast_contains_ignore_case(item.display_str(), &keyword) {
                 visible_item_indexes.push(index);
                 visible_items.push(item.clone());
             }
@@ -87,11 +87,23 @@ impl ZedisKvFetcher for ZedisListValues {
     /// otherwise returns directly from the original list values.
     fn get(&self, row_ix: usize, _col_ix: usize) -> Option<SharedString> {
         let value = self.value.list_value()?;
-        if value.keyword.is_some() {
-            self.visible_items.get(row_ix).cloned()
+        let item = if value.keyword.is_some() {
+            self.visible_items.get(row_ix)
         } else {
-            value.values.get(row_ix).cloned()
-        }
+            value.values.get(row_ix)
+        };
+        item.map(|item| item.display())
+    }
+
+    /// Returns the item to decode with the value decoders.
+    fn decode_input(&self, row_ix: usize, _col_ix: usize, cx: &App) -> Option<DecodeInput> {
+        let value = self.value.list_value()?;
+        let item = if value.keyword.is_some() {
+            self.visible_items.get(row_ix)
+        } else {
+            value.values.get(row_ix)
+        };
+        DecodeInput::new(&self.server_state, None, item?, cx)
     }
 
     /// Indicates that list values support in-place updates (LSET command).
@@ -218,7 +230,13 @@ impl ZedisKvFetcher for ZedisListValues {
         };
 
         self.server_state.update(cx, |state, cx| {
-            state.update_list_value(real_index, original_value.clone(), new_value.clone(), cx);
+            // The form shows the escaped display, unescape it back to the original bytes
+            state.update_list_value(
+                real_index,
+                original_value.clone(),
+                RedisBytes::from_display(new_value),
+                cx,
+            );
         });
     }
 
@@ -0,0 +1,230 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RedisBloom probabilistic structure inspector UI component.
+//!
+//! This module provides read-only panels for the RedisBloom structures. It supports:
+//! - Showing the INFO of Bloom, Cuckoo, Count-Min, Top-K and t-digest structures
+//! - Listing the top items of a Top-K with their counts (TOPK.LIST WITHCOUNT)
+//! - Testing membership (BF.MEXISTS, CF.EXISTS, TOPK.QUERY)
+//! - Estimating frequencies (CMS.QUERY) and quantiles (TDIGEST.QUANTILE)
+
+use crate::states::{KeyType, ServerEvent, ZedisServerState, i18n_probabilistic_editor};
+use gpui::{Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, Sizable,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    scroll::ScrollableElement,
+    v_flex,
+};
+
+/// Width of the name column of the info and result lists
+const NAME_COLUMN_WIDTH: f32 = 220.;
+
+/// Returns the i18n key prefix of a probabilistic key type.
+fn type_prefix(key_type: KeyType) -> &'static str {
+    match key_type {
+        KeyType::Cuckoo => "cuckoo",
+        KeyType::CountMinSketch => "cms",
+        KeyType::TopK => "topk",
+        KeyType::TDigest => "tdigest",
+        _ => "bloom",
+    }
+}
+
+/// Read-only inspector view for RedisBloom probabilistic structures.
+///
+/// Shows the structure INFO, the Top-K items and a query box for the
+/// membership, frequency or quantile query of the type.
+pub struct ZedisProbabilisticEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// Probabilistic key type the inspector was created for
+    key_type: KeyType,
+    /// Input for the whitespace separated items or quantiles
+    query_state: Entity<InputState>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisProbabilisticEditor {
+    /// Creates a new probabilistic structure inspector instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let key_type = server_state.read(cx).value().map(|v| v.key_type()).unwrap_or_default();
+        let placeholder = i18n_probabilistic_editor(cx, &format!("{}_query_placeholder", type_prefix(key_type)));
+        let query_state = cx.new(|cx| InputState::new(window, cx).clean_on_escape().placeholder(placeholder));
+
+        let mut subscriptions = vec![];
+        subscriptions.push(cx.subscribe(&query_state, |this, _, event, cx| {
+            if matches!(event, InputEvent::PressEnter { .. }) {
+                this.handle_query(cx);
+            }
+        }));
+        subscriptions.push(cx.subscribe(&server_state, |_this, _, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded | ServerEvent::ValueUpdated) {
+                cx.notify();
+            }
+        }));
+
+        Self {
+            server_state,
+            key_type,
+            query_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Returns the probabilistic key type the inspector was created for.
+    pub fn key_type(&self) -> KeyType {
+        self.key_type
+    }
+
+    /// Runs the query of the type with the current input.
+    fn handle_query(&mut self, cx: &mut Context<Self>) {
+        let input = self.query_state.read(cx).value();
+        if input.trim().is_empty() {
+            return;
+        }
+        self.server_state.update(cx, |state, cx| {
+            state.query_probabilistic_value(input, cx);
+        });
+    }
+
+    /// Renders name-value rows in a bordered list.
+    fn render_rows(rows: Vec<(SharedString, SharedString)>, cx: &Context<Self>) -> impl IntoElement {
+        let border_color = cx.theme().border;
+        let muted_color = cx.theme().muted_foreground;
+        v_flex()
+            .border_1()
+            .border_color(border_color)
+            .rounded_md()
+            .children(rows.into_iter().map(|(name, value)| {
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        Label::new(name)
+                            .text_sm()
+                            .w(px(NAME_COLUMN_WIDTH))
+                            .text_ellipsis()
+                            .text_color(muted_color),
+                    )
+                    .child(Label::new(value).text_sm().flex_1().text_ellipsis())
+            }))
+    }
+
+    /// Renders the query box and its results.
+    fn render_query(&self, key_type: KeyType, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let results = server_state
+            .value()
+            .and_then(|v| v.probabilistic_value())
+            .and_then(|v| v.results.clone());
+        let prefix = type_prefix(key_type);
+
+        let toolbar = h_flex()
+            .gap_2()
+            .items_center()
+            .child(div().flex_1().child(Input::new(&self.query_state).small()))
+            .child(
+                Button::new("probabilistic-query-btn")
+                    .outline()
+                    .small()
+                    .icon(IconName::Search)
+                    .label(i18n_probabilistic_editor(cx, "query"))
+                    .tooltip(i18n_probabilistic_editor(cx, &format!("{prefix}_query_tooltip")))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_query(cx);
+                    })),
+            )
+            .when(results.is_some(), |this| {
+                this.child(
+                    Button::new("probabilistic-query-clear-btn")
+                        .ghost()
+                        .small()
+                        .icon(IconName::CircleX)
+                        .tooltip(i18n_probabilistic_editor(cx, "clear_results"))
+                        .on_click(cx.listener(|this, _, _window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.clear_probabilistic_query(cx);
+                            });
+                        })),
+                )
+            });
+
+        v_flex()
+            .gap_2()
+            .child(
+                Label::new(i18n_probabilistic_editor(cx, "query_title"))
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground),
+            )
+            .child(toolbar)
+            .children(results.map(|results| Self::render_rows(results, cx)))
+    }
+}
+
+impl Render for ZedisProbabilisticEditor {
+    /// Renders the structure INFO, the Top-K items and the query box.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let key_type = self.key_type;
+        let prefix = type_prefix(key_type);
+
+        let Some(probabilistic) = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.probabilistic_value())
+            .cloned()
+        else {
+            return v_flex().into_any_element();
+        };
+        let muted_color = cx.theme().muted_foreground;
+
+        v_flex()
+            .size_full()
+            .p_2()
+            .gap_3()
+            .overflow_y_scrollbar()
+            .child(
+                Label::new(i18n_probabilistic_editor(cx, &format!("{prefix}_title")))
+                    .text_sm()
+                    .text_color(muted_color),
+            )
+            .child(Self::render_rows(probabilistic.info.clone(), cx))
+            .when(key_type == KeyType::TopK, |this| {
+                this.child(
+                    Label::new(i18n_probabilistic_editor(cx, "topk_items"))
+                        .text_sm()
+                        .text_color(muted_color),
+                )
+                .child(Self::render_rows(probabilistic.items.clone(), cx))
+            })
+            .child(self.render_query(key_type, cx))
+            .into_any_element()
+    }
+}
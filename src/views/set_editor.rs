@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use crate::{
-    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    components::{DecodeInput, FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
     states::{RedisValue, ZedisServerState, i18n_common, i18n_set_editor},
     views::{KvTableColumn, ZedisKvTable},
 };
@@ -91,7 +91,13 @@ impl ZedisKvFetcher for ZedisSetValues {
     ///
     /// For SETs, there's only one column (the member value itself).
     fn get(&self, row_ix: usize, _col_ix: usize) -> Option<SharedString> {
-        self.value.set_value()?.values.get(row_ix).cloned()
+        self.value.set_value()?.values.get(row_ix).map(|v| v.display())
+    }
+
+    /// Returns the member to decode with the value decoders.
+    fn decode_input(&self, row_ix: usize, _col_ix: usize, cx: &App) -> Option<DecodeInput> {
+        let member = self.value.set_value()?.values.get(row_ix)?;
+        DecodeInput::new(&self.server_state, None, member, cx)
     }
 
     /// Returns the number of currently loaded rows (not total SET size).
@@ -28,6 +28,7 @@ pub struct ZedisSettingEditor {
     max_key_tree_depth_state: Entity<InputState>,
     key_separator_state: Entity<InputState>,
     max_truncate_length_state: Entity<InputState>,
+    large_value_threshold_state: Entity<InputState>,
     config_dir_state: Entity<InputState>,
     key_scan_count_state: Entity<InputState>,
     redis_connection_timeout_state: Entity<InputState>,
@@ -75,6 +76,7 @@ impl ZedisSettingEditor {
         let max_key_tree_depth = store.max_key_tree_depth();
         let key_separator = store.key_separator().to_string();
         let max_truncate_length = store.max_truncate_length();
+        let large_value_threshold = store.large_value_threshold();
         let redis_connection_timeout = store.redis_connection_timeout();
         let redis_response_timeout = store.redis_response_timeout();
         let key_scan_count = store.key_scan_count();
@@ -101,6 +103,13 @@ impl ZedisSettingEditor {
             max_truncate_length.to_string(),
             Some(|s| s.parse::<usize>().is_ok()),
         );
+        let large_value_threshold_state = Self::create_input_state(
+            window,
+            cx,
+            "large_value_threshold_placeholder",
+            large_value_threshold.to_string(),
+            Some(|s| s.parse::<usize>().is_ok()),
+        );
         let redis_connection_timeout_state = Self::create_input_state(
             window,
             cx,
@@ -209,6 +218,21 @@ impl ZedisSettingEditor {
                 }
             },
         ));
+        // Large Value Threshold
+        subscriptions.push(Self::bind_blur_save(
+            cx,
+            &large_value_threshold_state,
+            window,
+            |text, cx| {
+                if let Ok(value) = text.parse::<usize>()
+                    && value >= 256
+                {
+                    update_app_state_and_save(cx, "save_large_value_threshold", move |state, _| {
+                        state.set_large_value_threshold(value);
+                    });
+                }
+            },
+        ));
         let config_dir_state =
             cx.new(|cx| InputState::new(window, cx).default_value(config_dir.to_string_lossy().to_string()));
 
@@ -217,6 +241,7 @@ impl ZedisSettingEditor {
             key_scan_count_state,
             config_dir_state,
             max_truncate_length_state,
+            large_value_threshold_state,
             key_separator_state,
             max_key_tree_depth_state,
             redis_response_timeout_state,
@@ -259,6 +284,11 @@ impl Render for ZedisSettingEditor {
                         "max_truncate_length",
                         Input::new(&self.max_truncate_length_state),
                     ))
+                    .child(Self::render_field(
+                        cx,
+                        "large_value_threshold",
+                        Input::new(&self.large_value_threshold_state),
+                    ))
                     .child(Self::render_field(
                         cx,
                         "redis_connection_timeout",
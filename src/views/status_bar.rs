@@ -136,7 +136,7 @@ pub struct ZedisStatusBar {
 
     viewer_mode_state: Entity<SelectState<SearchableVec<SharedString>>>,
     db_state: Entity<SelectState<Vec<DbInfo>>>,
-    should_reset_viewer_mode: Option<bool>,
+    should_reset_viewer_mode: Option<usize>,
     should_reset_db: Option<bool>,
     server_state: Entity<ZedisServerState>,
     heartbeat_task: Option<Task<()>>,
@@ -191,9 +191,11 @@ impl ZedisStatusBar {
                 }
                 ServerEvent::ValueLoaded => {
                     let state = server_state.read(cx);
-                    this.should_reset_viewer_mode = Some(true);
+                    this.should_reset_viewer_mode = Some(0);
                     if let Some(value) = state.value().and_then(|item| item.bytes_value()) {
-                        let mut format = value.format.as_str().to_string();
+                        // Decoder rules can force the view mode of the value
+                        this.should_reset_viewer_mode = ViewMode::ALL.iter().position(|mode| *mode == value.view_mode);
+                        let mut format = value.format_chain();
                         if let Some(mime) = &value.mime {
                             format = format!("{}({})", format, mime);
                         }
@@ -210,11 +212,12 @@ impl ZedisStatusBar {
         }));
         let viewer_mode_state = cx.new(|cx| {
             SelectState::new(
-                SearchableVec::new(vec![
-                    ViewMode::Auto.as_str().into(),
-                    ViewMode::Plain.as_str().into(),
-                    ViewMode::Hex.as_str().into(),
-                ]),
+                SearchableVec::new(
+                    ViewMode::ALL
+                        .iter()
+                        .map(|mode| SharedString::from(mode.as_str()))
+                        .collect::<Vec<_>>(),
+                ),
                 Some(IndexPath::new(0)),
                 window,
                 cx,
@@ -525,9 +528,9 @@ impl Render for ZedisStatusBar {
         if self.state.server_state.server_id.is_empty() {
             return h_flex();
         }
-        if let Some(true) = self.should_reset_viewer_mode.take() {
+        if let Some(index) = self.should_reset_viewer_mode.take() {
             self.viewer_mode_state.update(cx, |state, cx| {
-                state.set_selected_index(Some(IndexPath::new(0)), window, cx);
+                state.set_selected_index(Some(IndexPath::new(index)), window, cx);
             });
         }
         if let Some(true) = self.should_reset_db.take() {
@@ -0,0 +1,419 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis STREAM editor UI component.
+//!
+//! This module provides a table-based editor for viewing and managing Redis STREAM values.
+//! It supports operations like:
+//! - Viewing stream entries (ID and field-value pairs) in a two-column table
+//! - Switching between ascending and descending ID order
+//! - Filtering entries by ID range (`start [end]`)
+//! - Appending new entries via a dialog form (XADD)
+//! - Removing entries (XDEL)
+//! - Trimming the stream by length or minimum ID (XTRIM)
+//! - Incremental loading of large streams with pagination
+//! - Inspecting and managing consumer groups in a separate tab
+
+use crate::{
+    assets::CustomIconName,
+    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    states::{
+        RedisValue, ServerEvent, SortOrder, StreamTrimStrategy, ZedisServerState, i18n_common, i18n_stream_editor,
+    },
+    views::{KvTableColumn, ZedisKvTable, ZedisStreamGroupEditor},
+};
+use gpui::{App, Entity, SharedString, Subscription, Window, prelude::*};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, Sizable, WindowExt,
+    button::Button,
+    h_flex,
+    label::Label,
+    tab::{Tab, TabBar},
+    v_flex,
+};
+use std::rc::Rc;
+
+/// Width of the entry ID column in pixels
+const ID_COLUMN_WIDTH: f32 = 220.;
+
+/// Index of the consumer groups tab
+const GROUPS_TAB_INDEX: usize = 1;
+
+/// Formats the field-value pairs of an entry as a JSON-like object,
+/// keeping the insertion order of the fields.
+fn format_stream_fields(fields: &[(SharedString, SharedString)]) -> SharedString {
+    let items: Vec<String> = fields
+        .iter()
+        .map(|(field, value)| {
+            let field = serde_json::to_string(field.as_str()).unwrap_or_default();
+            let value = serde_json::to_string(value.as_str()).unwrap_or_default();
+            format!("{field}: {value}")
+        })
+        .collect();
+    format!("{{{}}}", items.join(", ")).into()
+}
+
+/// Parses the fields input of the add dialog.
+///
+/// Accepts a JSON object whose values are strings, numbers or booleans.
+fn parse_stream_fields(input: &str) -> Option<Vec<(SharedString, SharedString)>> {
+    let serde_json::Value::Object(map) = serde_json::from_str(input).ok()? else {
+        return None;
+    };
+    if map.is_empty() {
+        return None;
+    }
+    map.into_iter()
+        .map(|(field, value)| {
+            let value = match value {
+                serde_json::Value::String(s) => s,
+                serde_json::Value::Number(n) => n.to_string(),
+                serde_json::Value::Bool(b) => b.to_string(),
+                _ => return None,
+            };
+            Some((field.into(), value.into()))
+        })
+        .collect()
+}
+
+/// Data adapter for Redis STREAM values to work with the KV table component.
+///
+/// This struct implements the `ZedisKvFetcher` trait to provide data access
+/// and operations for the two-column table view (entry ID and fields columns).
+struct ZedisStreamValues {
+    /// Current Redis STREAM value data
+    value: RedisValue,
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+}
+
+impl ZedisKvFetcher for ZedisStreamValues {
+    /// Creates a new data adapter instance.
+    fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self {
+        Self { server_state, value }
+    }
+
+    /// Retrieves a cell value for the table at the given row and column.
+    ///
+    /// Column layout:
+    /// - Column 1: Entry ID
+    /// - Column 2: Entry fields
+    fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+        let stream = self.value.stream_value()?;
+        let entry = stream.values.get(row_ix)?;
+
+        if col_ix == 2 {
+            Some(format_stream_fields(&entry.fields))
+        } else {
+            Some(entry.id.clone())
+        }
+    }
+
+    /// Returns the total number of entries in the STREAM (from Redis XLEN).
+    fn count(&self) -> usize {
+        self.value.stream_value().map_or(0, |v| v.size)
+    }
+
+    /// Returns the number of currently loaded entries.
+    fn rows_count(&self) -> usize {
+        self.value.stream_value().map_or(0, |v| v.values.len())
+    }
+
+    /// Checks if all entries in the current ID range have been loaded.
+    fn is_done(&self) -> bool {
+        self.value.stream_value().is_some_and(|v| v.done)
+    }
+
+    /// Triggers loading of the next page of entries.
+    fn load_more(&self, _window: &mut Window, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.load_more_stream_value(cx);
+        });
+    }
+
+    /// Removes the entry at the given index via XDEL.
+    fn remove(&self, index: usize, cx: &mut App) {
+        let Some(stream) = self.value.stream_value() else {
+            return;
+        };
+        let Some(entry) = stream.values.get(index) else {
+            return;
+        };
+        let id = entry.id.clone();
+
+        self.server_state.update(cx, |this, cx| {
+            this.remove_stream_value(id, cx);
+        });
+    }
+
+    /// Applies an ID range filter (`start [end]`) to the stream entries.
+    fn filter(&self, keyword: SharedString, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.filter_stream_value(keyword, cx);
+        });
+    }
+
+    /// Opens a dialog to append a new entry to the STREAM.
+    ///
+    /// The ID defaults to `*` (auto-generated) and the fields are entered as a JSON object.
+    fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 {
+                return false;
+            }
+            let id = if values[0].trim().is_empty() {
+                SharedString::from("*")
+            } else {
+                values[0].trim().to_string().into()
+            };
+            let Some(fields) = parse_stream_fields(&values[1]) else {
+                return false;
+            };
+
+            server_state.update(cx, |this, cx| {
+                this.add_stream_value(id, fields, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_stream_editor(cx, "entry_id"))
+                .with_placeholder(i18n_stream_editor(cx, "entry_id_placeholder")),
+            FormField::new(i18n_stream_editor(cx, "fields"))
+                .with_placeholder(i18n_stream_editor(cx, "fields_placeholder"))
+                .with_focus(),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_stream_editor(cx, "add_value_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+}
+
+/// Main STREAM editor view component.
+///
+/// Wraps the generic `ZedisKvTable` component with STREAM-specific configuration
+/// and adds a toolbar for sort order and trimming. Consumer groups are shown in
+/// a second tab.
+pub struct ZedisStreamEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// The table component that renders the stream entries
+    table_state: Entity<ZedisKvTable<ZedisStreamValues>>,
+    /// Currently selected tab (entries or consumer groups)
+    selected_tab: usize,
+    /// Consumer group tab, created when first opened
+    group_editor: Option<Entity<ZedisStreamGroupEditor>>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisStreamEditor {
+    /// Creates a new STREAM editor instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let table_state = cx.new(|cx| {
+            ZedisKvTable::<ZedisStreamValues>::new(
+                vec![
+                    KvTableColumn::new("ID", Some(ID_COLUMN_WIDTH)).with_readonly(true),
+                    KvTableColumn::new("Fields", None).with_readonly(true),
+                ],
+                server_state.clone(),
+                window,
+                cx,
+            )
+        });
+
+        // Reload consumer groups when another stream key is opened on the groups tab
+        let subscriptions = vec![cx.subscribe(&server_state, |this, server_state, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded) && this.selected_tab == GROUPS_TAB_INDEX {
+                server_state.update(cx, |state, cx| {
+                    state.load_stream_groups(cx);
+                });
+            }
+        })];
+
+        Self {
+            server_state,
+            table_state,
+            selected_tab: 0,
+            group_editor: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Opens a dialog to trim the stream with XTRIM MAXLEN or MINID.
+    fn handle_trim(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 || values[1].trim().is_empty() {
+                return false;
+            }
+            let strategy = if values[0] == "1" {
+                StreamTrimStrategy::MinId
+            } else {
+                StreamTrimStrategy::MaxLen
+            };
+            let threshold: SharedString = values[1].trim().to_string().into();
+
+            server_state.update(cx, |this, cx| {
+                this.trim_stream_value(strategy, threshold, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_stream_editor(cx, "trim_strategy")).with_options(vec![
+                StreamTrimStrategy::MaxLen.as_str().into(),
+                StreamTrimStrategy::MinId.as_str().into(),
+            ]),
+            FormField::new(i18n_stream_editor(cx, "trim_threshold"))
+                .with_placeholder(i18n_stream_editor(cx, "trim_threshold_placeholder"))
+                .with_focus(),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_stream_editor(cx, "trim_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Renders the toolbar with sort order toggle, trim action and filter hint.
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let readonly = server_state.readonly();
+        let (sort_order, is_busy) = server_state
+            .value()
+            .map(|v| (v.stream_value().map(|s| s.sort_order).unwrap_or_default(), v.is_busy()))
+            .unwrap_or_default();
+
+        let (sort_icon, sort_tooltip, next_sort_order) = if sort_order == SortOrder::Asc {
+            (
+                IconName::ArrowUp,
+                i18n_stream_editor(cx, "sort_asc_tooltip"),
+                SortOrder::Desc,
+            )
+        } else {
+            (
+                IconName::ArrowDown,
+                i18n_stream_editor(cx, "sort_desc_tooltip"),
+                SortOrder::Asc,
+            )
+        };
+
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(
+                Button::new("stream-editor-sort-btn")
+                    .outline()
+                    .small()
+                    .icon(sort_icon)
+                    .tooltip(sort_tooltip)
+                    .disabled(is_busy)
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            state.set_stream_sort_order(next_sort_order, cx);
+                        });
+                    })),
+            )
+            .child(
+                Button::new("stream-editor-trim-btn")
+                    .outline()
+                    .small()
+                    .icon(CustomIconName::Eraser)
+                    .label(i18n_stream_editor(cx, "trim"))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_stream_editor(cx, "trim_tooltip")
+                    })
+                    .disabled(readonly || is_busy)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_trim(window, cx);
+                    })),
+            )
+            .child(
+                Label::new(i18n_stream_editor(cx, "filter_tips"))
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground),
+            )
+    }
+}
+
+impl Render for ZedisStreamEditor {
+    /// Renders the STREAM editor with the tab bar above the entries table or consumer groups.
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tab_bar = TabBar::new("stream-editor-tabs")
+            .underline()
+            .px_2()
+            .selected_index(self.selected_tab)
+            .on_click(cx.listener(|this, selected_index: &usize, _window, cx| {
+                this.selected_tab = *selected_index;
+                // Refresh consumer groups every time the tab is opened
+                if this.selected_tab == GROUPS_TAB_INDEX {
+                    this.server_state.update(cx, |state, cx| {
+                        state.load_stream_groups(cx);
+                    });
+                }
+                cx.notify();
+            }))
+            .child(Tab::new().label(i18n_stream_editor(cx, "entries_tab")).p_1())
+            .child(Tab::new().label(i18n_stream_editor(cx, "groups_tab")).p_1());
+
+        if self.selected_tab == GROUPS_TAB_INDEX {
+            let server_state = self.server_state.clone();
+            let group_editor = self
+                .group_editor
+                .get_or_insert_with(|| cx.new(|cx| ZedisStreamGroupEditor::new(server_state, window, cx)));
+            return v_flex()
+                .size_full()
+                .child(tab_bar)
+                .child(v_flex().flex_1().h_0().child(group_editor.clone()))
+                .into_any_element();
+        }
+
+        v_flex()
+            .size_full()
+            .child(tab_bar)
+            .child(self.render_toolbar(cx))
+            .child(v_flex().flex_1().h_0().child(self.table_state.clone()))
+            .into_any_element()
+    }
+}
@@ -0,0 +1,557 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis STREAM consumer group inspector.
+//!
+//! This module provides the consumer group tab of the stream editor. It supports:
+//! - Listing consumer groups with their lag and last delivered ID (XINFO GROUPS)
+//! - Listing consumers of the selected group (XINFO CONSUMERS)
+//! - Listing pending entries of the selected group (XPENDING)
+//! - Acknowledging and claiming pending entries (XACK, XCLAIM, XAUTOCLAIM)
+//! - Creating, resetting and destroying groups (XGROUP CREATE / SETID / DESTROY)
+//!
+//! All write actions are disabled when the server is in a readonly access mode.
+
+use crate::{
+    assets::CustomIconName,
+    components::{FormDialog, FormField, open_add_form_dialog},
+    helpers::format_duration,
+    states::{
+        RedisStreamGroups, ServerEvent, ZedisGlobalStore, ZedisServerState, dialog_button_props, i18n_common,
+        i18n_stream_editor,
+    },
+};
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, Sizable, WindowExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    label::Label,
+    scroll::ScrollableElement,
+    v_flex,
+};
+use rust_i18n::t;
+use std::{rc::Rc, sync::Arc, time::Duration};
+
+/// Width of the consumer group list in pixels
+const GROUP_LIST_WIDTH: f32 = 360.;
+
+/// Formats an idle time in milliseconds for display.
+fn format_idle(idle: u64) -> String {
+    if idle < 1000 {
+        return format!("{idle}ms");
+    }
+    format_duration(Duration::from_millis(idle))
+}
+
+/// Consumer group tab of the STREAM editor.
+pub struct ZedisStreamGroupEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisStreamGroupEditor {
+    /// Creates a new consumer group editor.
+    pub fn new(server_state: Entity<ZedisServerState>, _window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscriptions = vec![cx.subscribe(&server_state, |_this, _server_state, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded | ServerEvent::ValueUpdated) {
+                cx.notify();
+            }
+        })];
+
+        Self {
+            server_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Returns the loaded consumer groups of the current stream.
+    fn groups(&self, cx: &App) -> Option<Arc<RedisStreamGroups>> {
+        self.server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.stream_value())
+            .and_then(|stream| stream.groups.clone())
+    }
+
+    /// Opens a dialog to create a new consumer group.
+    fn handle_create_group(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 || values[0].trim().is_empty() {
+                return false;
+            }
+            let group: SharedString = values[0].trim().to_string().into();
+            let id: SharedString = if values[1].trim().is_empty() {
+                "$".into()
+            } else {
+                values[1].trim().to_string().into()
+            };
+            server_state.update(cx, |this, cx| {
+                this.create_stream_group(group, id, cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_stream_editor(cx, "group"))
+                .with_placeholder(i18n_stream_editor(cx, "group_placeholder"))
+                .with_focus(),
+            FormField::new(i18n_stream_editor(cx, "start_id"))
+                .with_placeholder(i18n_stream_editor(cx, "start_id_placeholder")),
+        ];
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_stream_editor(cx, "create_group_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Opens a dialog to set the last delivered ID of a consumer group.
+    fn handle_set_group_id(&mut self, group: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            let Some(id) = values.first().map(|v| v.trim().to_string()).filter(|v| !v.is_empty()) else {
+                return false;
+            };
+            let group = group.clone();
+            server_state.update(cx, |this, cx| {
+                this.set_stream_group_id(group, id.into(), cx);
+            });
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_stream_editor(cx, "start_id"))
+                .with_placeholder(i18n_stream_editor(cx, "start_id_placeholder"))
+                .with_focus(),
+        ];
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_stream_editor(cx, "set_group_id_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Asks for confirmation and destroys a consumer group.
+    fn handle_destroy_group(&mut self, group: SharedString, window: &mut Window, cx: &mut Context<Self>) {
+        let server_state = self.server_state.clone();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let message = t!("stream_editor.destroy_group_prompt", group = group, locale = locale).to_string();
+            let server_state = server_state.clone();
+            let group = group.clone();
+
+            dialog
+                .confirm()
+                .button_props(dialog_button_props(cx))
+                .child(message)
+                .on_ok(move |_, window, cx| {
+                    let group = group.clone();
+                    server_state.update(cx, move |state, cx| {
+                        state.destroy_stream_group(group, cx);
+                    });
+                    window.close_dialog(cx);
+                    true
+                })
+        });
+    }
+
+    /// Opens a dialog to claim pending entries for another consumer.
+    ///
+    /// Claims the given entries with XCLAIM, or all idle entries with XAUTOCLAIM
+    /// when `ids` is `None`.
+    fn handle_claim(
+        &mut self,
+        group: SharedString,
+        ids: Option<Vec<SharedString>>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let server_state = self.server_state.clone();
+        let title = if ids.is_some() {
+            i18n_stream_editor(cx, "claim_title")
+        } else {
+            i18n_stream_editor(cx, "auto_claim_title")
+        };
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 || values[0].trim().is_empty() {
+                return false;
+            }
+            let consumer: SharedString = values[0].trim().to_string().into();
+            let Ok(min_idle) = (if values[1].trim().is_empty() {
+                Ok(0)
+            } else {
+                values[1].trim().parse::<u64>()
+            }) else {
+                return false;
+            };
+            let group = group.clone();
+            let ids = ids.clone();
+            server_state.update(cx, |this, cx| match ids {
+                Some(ids) => this.claim_stream_pending(group, consumer, min_idle, ids, cx),
+                None => this.auto_claim_stream_pending(group, consumer, min_idle, cx),
+            });
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_stream_editor(cx, "consumer"))
+                .with_placeholder(i18n_stream_editor(cx, "consumer_placeholder"))
+                .with_focus(),
+            FormField::new(i18n_stream_editor(cx, "min_idle"))
+                .with_placeholder(i18n_stream_editor(cx, "min_idle_placeholder"))
+                .with_validate(|s| s.chars().all(|c| c.is_ascii_digit())),
+        ];
+        open_add_form_dialog(
+            FormDialog {
+                title,
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+
+    /// Renders a section header label.
+    fn render_section_title(title: SharedString, cx: &App) -> impl IntoElement {
+        Label::new(title)
+            .text_sm()
+            .px_2()
+            .py_1()
+            .text_color(cx.theme().muted_foreground)
+    }
+
+    /// Renders the list of consumer groups.
+    fn render_groups(&self, groups: &RedisStreamGroups, readonly: bool, cx: &mut Context<Self>) -> impl IntoElement {
+        let list_active_color = cx.theme().list_active;
+        let border_color = cx.theme().border;
+        let muted_color = cx.theme().muted_foreground;
+        let write_tooltip = if readonly {
+            i18n_common(cx, "disable_in_readonly")
+        } else {
+            SharedString::default()
+        };
+
+        let rows = groups.groups.iter().enumerate().map(|(index, group)| {
+            let is_selected = groups.selected.as_ref() == Some(&group.name);
+            let lag = group.lag.map(|lag| lag.to_string()).unwrap_or_else(|| "--".to_string());
+            let detail = format!(
+                "{}: {} · {}: {} · {}: {} · {}: {}",
+                i18n_stream_editor(cx, "consumers"),
+                group.consumers,
+                i18n_stream_editor(cx, "pending"),
+                group.pending,
+                i18n_stream_editor(cx, "lag"),
+                lag,
+                i18n_stream_editor(cx, "last_delivered_id"),
+                group.last_delivered_id,
+            );
+            let name = group.name.clone();
+            let set_id_name = group.name.clone();
+            let destroy_name = group.name.clone();
+            h_flex()
+                .id(("stream-group", index))
+                .w_full()
+                .px_2()
+                .py_1()
+                .gap_1()
+                .border_b_1()
+                .border_color(border_color)
+                .when(is_selected, |this| this.bg(list_active_color))
+                .on_click(cx.listener(move |this, _, _window, cx| {
+                    let name = name.clone();
+                    this.server_state.update(cx, |state, cx| {
+                        state.select_stream_group(name, cx);
+                    });
+                }))
+                .child(
+                    v_flex()
+                        .flex_1()
+                        .w_0()
+                        .child(Label::new(group.name.clone()).text_sm().text_ellipsis())
+                        .child(Label::new(detail).text_xs().text_color(muted_color).text_ellipsis()),
+                )
+                .child(
+                    Button::new(("stream-group-set-id", index))
+                        .ghost()
+                        .small()
+                        .icon(CustomIconName::FilePenLine)
+                        .tooltip(if readonly {
+                            write_tooltip.clone()
+                        } else {
+                            i18n_stream_editor(cx, "set_group_id_title")
+                        })
+                        .disabled(readonly)
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            cx.stop_propagation();
+                            this.handle_set_group_id(set_id_name.clone(), window, cx);
+                        })),
+                )
+                .child(
+                    Button::new(("stream-group-destroy", index))
+                        .ghost()
+                        .small()
+                        .icon(CustomIconName::FileXCorner)
+                        .tooltip(if readonly {
+                            write_tooltip.clone()
+                        } else {
+                            i18n_stream_editor(cx, "destroy_group_tooltip")
+                        })
+                        .disabled(readonly)
+                        .on_click(cx.listener(move |this, _, window, cx| {
+                            cx.stop_propagation();
+                            this.handle_destroy_group(destroy_name.clone(), window, cx);
+                        })),
+                )
+        });
+
+        v_flex()
+            .w(px(GROUP_LIST_WIDTH))
+            .h_full()
+            .border_r_1()
+            .border_color(border_color)
+            .child(Self::render_section_title(i18n_stream_editor(cx, "groups"), cx))
+            .when(groups.groups.is_empty(), |this| {
+                this.child(
+                    Label::new(i18n_stream_editor(cx, "no_groups"))
+                        .text_sm()
+                        .p_2()
+                        .text_color(muted_color),
+                )
+            })
+            .child(v_flex().flex_1().h_0().overflow_y_scrollbar().children(rows))
+    }
+
+    /// Renders the consumers and pending entries of the selected group.
+    fn render_group_detail(
+        &self,
+        groups: &RedisStreamGroups,
+        readonly: bool,
+        cx: &mut Context<Self>,
+    ) -> impl IntoElement {
+        let border_color = cx.theme().border;
+        let muted_color = cx.theme().muted_foreground;
+        let Some(group) = groups.selected.clone() else {
+            return v_flex().flex_1();
+        };
+
+        let consumers = groups.consumers.iter().map(|consumer| {
+            h_flex()
+                .px_2()
+                .py_1()
+                .gap_2()
+                .border_b_1()
+                .border_color(border_color)
+                .child(Label::new(consumer.name.clone()).text_sm().flex_1())
+                .child(
+                    Label::new(format!(
+                        "{}: {} · {}: {}",
+                        i18n_stream_editor(cx, "pending"),
+                        consumer.pending,
+                        i18n_stream_editor(cx, "idle"),
+                        format_idle(consumer.idle)
+                    ))
+                    .text_xs()
+                    .text_color(muted_color),
+                )
+        });
+
+        let pending: Vec<_> = groups
+            .pending
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let ack_group = group.clone();
+                let ack_id = entry.id.clone();
+                let claim_group = group.clone();
+                let claim_id = entry.id.clone();
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .w_0()
+                            .child(Label::new(entry.id.clone()).text_sm())
+                            .child(
+                                Label::new(format!(
+                                    "{}: {} · {}: {} · {}: {}",
+                                    i18n_stream_editor(cx, "consumer"),
+                                    entry.consumer,
+                                    i18n_stream_editor(cx, "idle"),
+                                    format_idle(entry.idle),
+                                    i18n_stream_editor(cx, "deliveries"),
+                                    entry.delivery_count
+                                ))
+                                .text_xs()
+                                .text_color(muted_color)
+                                .text_ellipsis(),
+                            ),
+                    )
+                    .child(
+                        Button::new(("stream-pending-ack", index))
+                            .ghost()
+                            .small()
+                            .icon(IconName::Check)
+                            .tooltip(if readonly {
+                                i18n_common(cx, "disable_in_readonly")
+                            } else {
+                                i18n_stream_editor(cx, "ack_tooltip")
+                            })
+                            .disabled(readonly)
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                let group = ack_group.clone();
+                                let id = ack_id.clone();
+                                this.server_state.update(cx, |state, cx| {
+                                    state.ack_stream_pending(group, vec![id], cx);
+                                });
+                            })),
+                    )
+                    .child(
+                        Button::new(("stream-pending-claim", index))
+                            .ghost()
+                            .small()
+                            .icon(CustomIconName::ChevronsLeftRightEllipsis)
+                            .tooltip(if readonly {
+                                i18n_common(cx, "disable_in_readonly")
+                            } else {
+                                i18n_stream_editor(cx, "claim_title")
+                            })
+                            .disabled(readonly)
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.handle_claim(claim_group.clone(), Some(vec![claim_id.clone()]), window, cx);
+                            })),
+                    )
+            })
+            .collect();
+
+        let ack_all_group = group.clone();
+        let ack_all_ids: Vec<SharedString> = groups.pending.iter().map(|entry| entry.id.clone()).collect();
+        let has_pending = !ack_all_ids.is_empty();
+
+        v_flex()
+            .flex_1()
+            .h_full()
+            .child(Self::render_section_title(i18n_stream_editor(cx, "consumers"), cx))
+            .child(v_flex().max_h(px(200.)).overflow_y_scrollbar().children(consumers))
+            .child(
+                h_flex()
+                    .pr_2()
+                    .gap_1()
+                    .child(div().flex_1().child(Self::render_section_title(
+                        i18n_stream_editor(cx, "pending_entries"),
+                        cx,
+                    )))
+                    .child(
+                        Button::new("stream-pending-ack-all")
+                            .outline()
+                            .small()
+                            .icon(IconName::Check)
+                            .label(i18n_stream_editor(cx, "ack_all"))
+                            .disabled(readonly || !has_pending)
+                            .on_click(cx.listener(move |this, _, _window, cx| {
+                                let group = ack_all_group.clone();
+                                let ids = ack_all_ids.clone();
+                                this.server_state.update(cx, |state, cx| {
+                                    state.ack_stream_pending(group, ids, cx);
+                                });
+                            })),
+                    )
+                    .child(
+                        Button::new("stream-pending-auto-claim")
+                            .outline()
+                            .small()
+                            .icon(CustomIconName::ChevronsLeftRightEllipsis)
+                            .label(i18n_stream_editor(cx, "auto_claim"))
+                            .tooltip(i18n_stream_editor(cx, "auto_claim_tooltip"))
+                            .disabled(readonly || !has_pending)
+                            .on_click(cx.listener(move |this, _, window, cx| {
+                                this.handle_claim(group.clone(), None, window, cx);
+                            })),
+                    ),
+            )
+            .child(v_flex().flex_1().h_0().overflow_y_scrollbar().children(pending))
+    }
+}
+
+impl Render for ZedisStreamGroupEditor {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let readonly = server_state.readonly();
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let groups = self.groups(cx).unwrap_or_default();
+
+        let toolbar = h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .child(
+                Button::new("stream-groups-reload")
+                    .outline()
+                    .small()
+                    .icon(CustomIconName::RotateCw)
+                    .tooltip(i18n_stream_editor(cx, "reload_groups_tooltip"))
+                    .loading(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.server_state.update(cx, |state, cx| {
+                            state.load_stream_groups(cx);
+                        });
+                    })),
+            )
+            .child(
+                Button::new("stream-groups-create")
+                    .outline()
+                    .small()
+                    .icon(CustomIconName::FilePlusCorner)
+                    .label(i18n_stream_editor(cx, "create_group_title"))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_stream_editor(cx, "create_group_tooltip")
+                    })
+                    .disabled(readonly)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_create_group(window, cx);
+                    })),
+            );
+
+        v_flex().size_full().child(toolbar).child(
+            h_flex()
+                .flex_1()
+                .h_0()
+                .items_start()
+                .child(self.render_groups(&groups, readonly, cx))
+                .child(self.render_group_detail(&groups, readonly, cx)),
+        )
+    }
+}
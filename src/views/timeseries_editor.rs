@@ -0,0 +1,580 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RedisTimeSeries editor UI component.
+//!
+//! This module provides a viewer for RedisTimeSeries values. It supports:
+//! - Showing retention, labels and compaction rules (TS.INFO)
+//! - Querying samples by timestamp range in ascending or descending order
+//! - Aggregating samples into buckets (avg, sum, min, max, ...)
+//! - Drawing the loaded samples as a line chart
+//! - Viewing samples in a table with incremental loading
+//! - Adding samples (TS.ADD) and deleting samples by range (TS.DEL)
+
+use crate::{
+    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    states::{
+        RedisValue, ServerEvent, TIMESERIES_AGGREGATIONS, TimeseriesQuery, ZedisGlobalStore, ZedisServerState,
+        dialog_button_props, i18n_common, i18n_timeseries_editor,
+    },
+    views::{KvTableColumn, ZedisKvTable},
+};
+use chrono::{Local, TimeZone};
+use gpui::{
+    App, Bounds, Entity, PathBuilder, SharedString, Subscription, Window, canvas, div, fill, point, prelude::*, px,
+    size,
+};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, IndexPath, Sizable, WindowExt,
+    button::Button,
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    select::{Select, SelectState},
+    v_flex,
+};
+use rust_i18n::t;
+use std::rc::Rc;
+
+/// Height of the line chart in pixels
+const CHART_HEIGHT: f32 = 160.;
+
+/// Width of the timestamp column in pixels
+const TIMESTAMP_COLUMN_WIDTH: f32 = 160.;
+
+/// Option of the aggregation select for raw samples
+const NO_AGGREGATION: &str = "none";
+
+/// Formats a millisecond timestamp as a local date time.
+fn format_timestamp(timestamp: i64) -> SharedString {
+    Local
+        .timestamp_millis_opt(timestamp)
+        .single()
+        .map(|datetime| datetime.format("%Y-%m-%d %H:%M:%S%.3f").to_string())
+        .unwrap_or_default()
+        .into()
+}
+
+/// Data adapter for RedisTimeSeries values to work with the KV table component.
+///
+/// Column layout: timestamp, local date time and sample value.
+struct ZedisTimeseriesValues {
+    /// Current RedisTimeSeries value data
+    value: RedisValue,
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+}
+
+impl ZedisKvFetcher for ZedisTimeseriesValues {
+    /// Creates a new data adapter instance.
+    fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self {
+        Self { server_state, value }
+    }
+
+    /// Retrieves a cell value for the table at the given row and column.
+    ///
+    /// Column layout:
+    /// - Column 1: Timestamp in milliseconds
+    /// - Column 2: Local date time
+    /// - Column 3: Sample value
+    fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+        let timeseries = self.value.timeseries_value()?;
+        let (timestamp, value) = timeseries.values.get(row_ix)?;
+        match col_ix {
+            2 => Some(format_timestamp(*timestamp)),
+            3 => Some(value.to_string().into()),
+            _ => Some(timestamp.to_string().into()),
+        }
+    }
+
+    /// Returns the total number of samples (from TS.INFO),
+    /// or the number of loaded buckets for aggregated queries.
+    fn count(&self) -> usize {
+        self.value.timeseries_value().map_or(0, |v| {
+            if v.query.aggregation.is_some() {
+                v.values.len()
+            } else {
+                v.size
+            }
+        })
+    }
+
+    /// Returns the number of currently loaded samples.
+    fn rows_count(&self) -> usize {
+        self.value.timeseries_value().map_or(0, |v| v.values.len())
+    }
+
+    /// Checks if all samples of the range have been loaded.
+    fn is_done(&self) -> bool {
+        self.value.timeseries_value().is_some_and(|v| v.done)
+    }
+
+    /// Triggers loading of the next page of samples.
+    fn load_more(&self, _window: &mut Window, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.load_more_timeseries_value(cx);
+        });
+    }
+
+    /// Removes the sample at the given index via TS.DEL.
+    fn remove(&self, index: usize, cx: &mut App) {
+        let Some(timeseries) = self.value.timeseries_value() else {
+            return;
+        };
+        let Some((timestamp, _)) = timeseries.values.get(index) else {
+            return;
+        };
+        let timestamp: SharedString = timestamp.to_string().into();
+
+        self.server_state.update(cx, |this, cx| {
+            this.remove_timeseries_value(timestamp.clone(), timestamp, cx);
+        });
+    }
+
+    /// Filters samples by timestamp range (`from [to]`).
+    fn filter(&self, keyword: SharedString, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.filter_timeseries_value(keyword, cx);
+        });
+    }
+
+    /// Opens a dialog to add a sample (TS.ADD).
+    fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
+        let server_state = self.server_state.clone();
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 2 {
+                return false;
+            }
+            let timestamp = values[0].trim();
+            if !timestamp.is_empty() && timestamp != "*" && timestamp.parse::<u64>().is_err() {
+                return false;
+            }
+            let Ok(sample) = values[1].trim().parse::<f64>() else {
+                return false;
+            };
+            let timestamp: SharedString = timestamp.to_string().into();
+
+            server_state.update(cx, |this, cx| {
+                this.add_timeseries_value(timestamp, sample, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_timeseries_editor(cx, "timestamp"))
+                .with_placeholder(i18n_timeseries_editor(cx, "timestamp_placeholder")),
+            FormField::new(i18n_common(cx, "value"))
+                .with_placeholder(i18n_timeseries_editor(cx, "value_placeholder"))
+                .with_focus(),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_timeseries_editor(cx, "add_value_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+}
+
+/// Main RedisTimeSeries editor view component.
+///
+/// Shows the TS.INFO metadata, the query toolbar and the line chart above
+/// the sample table.
+pub struct ZedisTimeseriesEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// The table component that renders the samples
+    table_state: Entity<ZedisKvTable<ZedisTimeseriesValues>>,
+    /// Select for the aggregation type
+    aggregation_state: Entity<SelectState<Vec<String>>>,
+    /// Input for the bucket duration in milliseconds
+    bucket_state: Entity<InputState>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisTimeseriesEditor {
+    /// Creates a new RedisTimeSeries editor instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let table_state = cx.new(|cx| {
+            ZedisKvTable::<ZedisTimeseriesValues>::new(
+                vec![
+                    KvTableColumn::new("Timestamp", Some(TIMESTAMP_COLUMN_WIDTH)).with_readonly(true),
+                    KvTableColumn::new("Time", Some(0.3)).with_readonly(true),
+                    KvTableColumn::new("Value", None).with_readonly(true),
+                ],
+                server_state.clone(),
+                window,
+                cx,
+            )
+        });
+
+        let mut aggregations = vec![NO_AGGREGATION.to_string()];
+        aggregations.extend(TIMESERIES_AGGREGATIONS.iter().map(|s| s.to_string()));
+        let aggregation_state = cx.new(|cx| SelectState::new(aggregations, Some(IndexPath::new(0)), window, cx));
+        let bucket_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .validate(|s, _| s.chars().all(|c| c.is_ascii_digit()))
+                .placeholder(i18n_timeseries_editor(cx, "bucket_placeholder"))
+        });
+
+        let mut subscriptions = vec![];
+        subscriptions.push(cx.subscribe(&bucket_state, |this, _, event, cx| {
+            if matches!(event, InputEvent::PressEnter { .. }) {
+                this.handle_query(None, cx);
+            }
+        }));
+        subscriptions.push(cx.subscribe(&server_state, |_this, _, event, cx| {
+            if matches!(
+                event,
+                ServerEvent::ValueLoaded | ServerEvent::ValueUpdated | ServerEvent::ValuePaginationFinished
+            ) {
+                cx.notify();
+            }
+        }));
+
+        Self {
+            server_state,
+            table_state,
+            aggregation_state,
+            bucket_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Returns the query of the loaded samples.
+    fn current_query(&self, cx: &App) -> TimeseriesQuery {
+        self.server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.timeseries_value())
+            .map(|v| v.query.clone())
+            .unwrap_or_default()
+    }
+
+    /// Runs the range query with the selected aggregation options.
+    ///
+    /// # Arguments
+    /// * `reverse` - New sample order, `None` to keep the current one
+    fn handle_query(&mut self, reverse: Option<bool>, cx: &mut Context<Self>) {
+        let mut query = self.current_query(cx);
+        if let Some(reverse) = reverse {
+            query.reverse = reverse;
+        }
+        let aggregation = self
+            .aggregation_state
+            .read(cx)
+            .selected_value()
+            .filter(|value| value.as_str() != NO_AGGREGATION)
+            .map(|value| SharedString::from(value.clone()));
+        let bucket_duration = self.bucket_state.read(cx).value().parse::<u64>().unwrap_or_default();
+        // An aggregation without bucket duration is rejected by Redis
+        if aggregation.is_some() && bucket_duration == 0 {
+            return;
+        }
+        query.aggregation = aggregation;
+        query.bucket_duration = bucket_duration;
+
+        self.server_state.update(cx, |state, cx| {
+            state.query_timeseries_value(query, cx);
+        });
+    }
+
+    /// Asks for confirmation and deletes all samples of the current range.
+    fn handle_remove_range(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let query = self.current_query(cx);
+        let from: SharedString = if query.from.trim().is_empty() {
+            "-".into()
+        } else {
+            query.from.clone()
+        };
+        let to: SharedString = if query.to.trim().is_empty() {
+            "+".into()
+        } else {
+            query.to.clone()
+        };
+        let server_state = self.server_state.clone();
+        window.open_dialog(cx, move |dialog, _, cx| {
+            let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+            let message = t!(
+                "timeseries_editor.remove_range_prompt",
+                from = from,
+                to = to,
+                locale = locale
+            )
+            .to_string();
+            let server_state = server_state.clone();
+            let from = from.clone();
+            let to = to.clone();
+
+            dialog
+                .confirm()
+                .button_props(dialog_button_props(cx))
+                .child(message)
+                .on_ok(move |_, window, cx| {
+                    let from = from.clone();
+                    let to = to.clone();
+                    server_state.update(cx, move |state, cx| {
+                        state.remove_timeseries_value(from, to, cx);
+                    });
+                    window.close_dialog(cx);
+                    true
+                })
+        });
+    }
+
+    /// Renders TS.INFO metadata, labels and compaction rules.
+    fn render_info(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted_color = cx.theme().muted_foreground;
+        let Some(timeseries) = self.server_state.read(cx).value().and_then(|v| v.timeseries_value()) else {
+            return v_flex();
+        };
+        let info = timeseries
+            .info
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect::<Vec<_>>();
+        let labels = timeseries
+            .labels
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>();
+        let rules = timeseries
+            .rules
+            .iter()
+            .map(|rule| format!("{} ({} {}ms)", rule.dest_key, rule.aggregation, rule.bucket_duration))
+            .collect::<Vec<_>>();
+
+        let render_row = |items: Vec<(String, String)>| {
+            h_flex()
+                .gap_3()
+                .flex_wrap()
+                .children(items.into_iter().map(|(name, value)| {
+                    h_flex()
+                        .gap_1()
+                        .child(Label::new(name).text_xs().text_color(muted_color))
+                        .child(Label::new(value).text_xs())
+                }))
+        };
+        let mut extra = vec![];
+        if !labels.is_empty() {
+            extra.push((i18n_timeseries_editor(cx, "labels").to_string(), labels.join(", ")));
+        }
+        if !rules.is_empty() {
+            extra.push((i18n_timeseries_editor(cx, "rules").to_string(), rules.join(", ")));
+        }
+
+        v_flex()
+            .px_2()
+            .pt_2()
+            .gap_1()
+            .child(render_row(info))
+            .when(!extra.is_empty(), |this| this.child(render_row(extra)))
+    }
+
+    /// Renders the toolbar with order toggle, aggregation options and range deletion.
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let readonly = server_state.readonly();
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let reverse = self.current_query(cx).reverse;
+
+        let (sort_icon, sort_tooltip) = if reverse {
+            (IconName::ArrowDown, i18n_timeseries_editor(cx, "sort_desc_tooltip"))
+        } else {
+            (IconName::ArrowUp, i18n_timeseries_editor(cx, "sort_asc_tooltip"))
+        };
+
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(
+                Button::new("timeseries-editor-sort-btn")
+                    .outline()
+                    .small()
+                    .icon(sort_icon)
+                    .tooltip(sort_tooltip)
+                    .disabled(is_busy)
+                    .on_click(cx.listener(move |this, _, _window, cx| {
+                        this.handle_query(Some(!reverse), cx);
+                    })),
+            )
+            .child(div().w(px(120.)).child(Select::new(&self.aggregation_state)))
+            .child(div().w(px(140.)).child(Input::new(&self.bucket_state).small()))
+            .child(
+                Button::new("timeseries-editor-query-btn")
+                    .outline()
+                    .small()
+                    .icon(IconName::Search)
+                    .label(i18n_timeseries_editor(cx, "query"))
+                    .tooltip(i18n_timeseries_editor(cx, "query_tooltip"))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_query(None, cx);
+                    })),
+            )
+            .child(
+                Button::new("timeseries-editor-remove-range-btn")
+                    .outline()
+                    .small()
+                    .icon(IconName::CircleX)
+                    .label(i18n_timeseries_editor(cx, "remove_range"))
+                    .tooltip(if readonly {
+                        i18n_common(cx, "disable_in_readonly")
+                    } else {
+                        i18n_timeseries_editor(cx, "remove_range_tooltip")
+                    })
+                    .disabled(readonly || is_busy)
+                    .on_click(cx.listener(|this, _, window, cx| {
+                        this.handle_remove_range(window, cx);
+                    })),
+            )
+            .child(
+                Label::new(i18n_timeseries_editor(cx, "filter_tips"))
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground),
+            )
+    }
+
+    /// Renders the loaded samples as a line chart with value and time bounds.
+    fn render_chart(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted_color = cx.theme().muted_foreground;
+        let border_color = cx.theme().border;
+        let line_color = cx.theme().primary;
+        let mut samples = self
+            .server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.timeseries_value())
+            .map(|v| v.values.clone())
+            .unwrap_or_default();
+        if samples.len() < 2 {
+            return v_flex()
+                .h(px(CHART_HEIGHT))
+                .items_center()
+                .justify_center()
+                .border_b_1()
+                .border_color(border_color)
+                .child(
+                    Label::new(i18n_timeseries_editor(cx, "chart_no_data"))
+                        .text_sm()
+                        .text_color(muted_color),
+                );
+        }
+        samples.sort_by_key(|(timestamp, _)| *timestamp);
+
+        let (first_timestamp, last_timestamp) = (samples[0].0, samples[samples.len() - 1].0);
+        let (min_value, max_value) = samples
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(min, max), (_, v)| (min.min(*v), max.max(*v)));
+        let time_span = (last_timestamp - first_timestamp).max(1) as f64;
+        let value_span = if max_value > min_value {
+            max_value - min_value
+        } else {
+            1.
+        };
+
+        let chart = canvas(
+            |_, _, _| {},
+            move |bounds: Bounds<gpui::Pixels>, _, window, _| {
+                let width = bounds.size.width;
+                let height = bounds.size.height;
+                // Horizontal grid lines at the top, middle and bottom
+                for ratio in [0., 0.5, 1.] {
+                    let y = bounds.origin.y + (height - px(1.)) * ratio;
+                    window.paint_quad(fill(
+                        Bounds::new(point(bounds.origin.x, y), size(width, px(1.))),
+                        border_color,
+                    ));
+                }
+
+                let mut builder = PathBuilder::stroke(px(1.5));
+                for (index, (timestamp, value)) in samples.iter().enumerate() {
+                    let x_ratio = ((timestamp - first_timestamp) as f64 / time_span) as f32;
+                    let y_ratio = (1. - (value - min_value) / value_span) as f32;
+                    let position = point(bounds.origin.x + width * x_ratio, bounds.origin.y + height * y_ratio);
+                    if index == 0 {
+                        builder.move_to(position);
+                    } else {
+                        builder.line_to(position);
+                    }
+                }
+                if let Ok(path) = builder.build() {
+                    window.paint_path(path, line_color);
+                }
+            },
+        )
+        .size_full();
+
+        let value_label = |value: f64| Label::new(format!("{value}")).text_xs().text_color(muted_color);
+        let time_label = |timestamp: i64| {
+            Label::new(format_timestamp(timestamp))
+                .text_xs()
+                .text_color(muted_color)
+        };
+
+        v_flex()
+            .p_2()
+            .gap_1()
+            .border_b_1()
+            .border_color(border_color)
+            .child(
+                h_flex()
+                    .h(px(CHART_HEIGHT))
+                    .gap_2()
+                    .child(
+                        v_flex()
+                            .h_full()
+                            .justify_between()
+                            .child(value_label(max_value))
+                            .child(value_label(min_value)),
+                    )
+                    .child(div().flex_1().h_full().child(chart)),
+            )
+            .child(
+                h_flex()
+                    .justify_between()
+                    .child(time_label(first_timestamp))
+                    .child(time_label(last_timestamp)),
+            )
+    }
+}
+
+impl Render for ZedisTimeseriesEditor {
+    /// Renders the metadata, toolbar, line chart and sample table.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .child(self.render_info(cx))
+            .child(self.render_toolbar(cx))
+            .child(self.render_chart(cx))
+            .child(v_flex().flex_1().h_0().child(self.table_state.clone()))
+            .into_any_element()
+    }
+}
@@ -83,6 +83,16 @@ impl ZedisTitleBar {
                 Box::new(SettingsAction::Protos),
                 move |_window, cx| Label::new(i18n_sidebar(cx, "proto_settings")),
             )
+            .menu_element_with_icon(
+                Icon::new(CustomIconName::Binary),
+                Box::new(SettingsAction::Avros),
+                move |_window, cx| Label::new(i18n_sidebar(cx, "avro_settings")),
+            )
+            .menu_element_with_icon(
+                Icon::new(CustomIconName::Binary),
+                Box::new(SettingsAction::DecoderRules),
+                move |_window, cx| Label::new(i18n_sidebar(cx, "decoder_rule_settings")),
+            )
             .menu_element_with_icon(
                 Icon::new(IconName::Settings2),
                 Box::new(SettingsAction::Editor),
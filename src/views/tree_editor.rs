@@ -0,0 +1,603 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tree view of structured string values.
+//!
+//! This module shows a decoded document (JSON, MessagePack, Protobuf, ...) as a tree. It supports:
+//! - Expanding and collapsing objects and arrays, only the expanded nodes are listed
+//! - Node counts and type badges, long strings are shown whole when copied
+//! - Filtering the nodes with JSONPath (`$.items[*].id`) or jq-style paths (`.items[0]`)
+//! - Copying the path or the value of the selected node
+
+use crate::helpers::get_font_family;
+use crate::states::{ServerEvent, ZedisGlobalStore, ZedisServerState, i18n_tree_editor};
+use gpui::{App, ClipboardItem, Entity, MouseButton, SharedString, Subscription, Window, div, hsla, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Icon, IconName, IndexPath, Sizable,
+    button::Button,
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    list::{List, ListDelegate, ListItem, ListState},
+    v_flex,
+};
+use rust_i18n::t;
+use serde_json::Value;
+use serde_json_path::JsonPath;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Indent of a tree level in pixels
+const TREE_INDENT: f32 = 16.;
+/// Maximum number of characters of a value preview
+const PREVIEW_LENGTH: usize = 200;
+/// Maximum number of nodes matched by a filter
+const FILTER_LIMIT: usize = 1000;
+
+/// Node shown at the top level: the document or a node matched by the filter
+#[derive(Clone)]
+struct TreeRoot {
+    name: SharedString,
+    /// Normalized JSONPath, e.g. `$['items'][0]`
+    path: String,
+    /// JSON pointer, e.g. `/items/0`
+    pointer: String,
+}
+
+/// Visible row of the tree
+struct TreeRow {
+    name: SharedString,
+    path: SharedString,
+    /// JSON pointer of the node, the key of the expanded nodes
+    pointer: SharedString,
+    depth: usize,
+    /// Type badge: object, array, string, number, boolean or null
+    kind: &'static str,
+    /// Number of keys or items, `None` for scalar values
+    children: Option<usize>,
+    /// Preview of a scalar value
+    preview: SharedString,
+    expanded: bool,
+}
+
+/// Returns the type name of the value.
+fn value_kind(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "object",
+        Value::Array(_) => "array",
+        Value::String(_) => "string",
+        Value::Number(_) => "number",
+        Value::Bool(_) => "boolean",
+        Value::Null => "null",
+    }
+}
+
+/// Returns the preview of a scalar value, strings are quoted and cut to `PREVIEW_LENGTH` chars.
+fn value_preview(value: &Value) -> SharedString {
+    let text = match value {
+        Value::Object(_) | Value::Array(_) => return SharedString::default(),
+        Value::String(s) if s.chars().count() > PREVIEW_LENGTH => {
+            let prefix: String = s.chars().take(PREVIEW_LENGTH).collect();
+            let quoted = serde_json::to_string(&prefix).unwrap_or_default();
+            // the ellipsis is put inside the closing quote
+            format!("{}…\"", quoted.strip_suffix('"').unwrap_or(&quoted))
+        }
+        _ => value.to_string(),
+    };
+    text.into()
+}
+
+/// Appends the row of the value and the rows of its children when it is expanded.
+fn push_rows(
+    rows: &mut Vec<TreeRow>,
+    expanded: &HashSet<String>,
+    value: &Value,
+    name: SharedString,
+    path: String,
+    pointer: String,
+    depth: usize,
+) {
+    let children = match value {
+        Value::Object(map) => Some(map.len()),
+        Value::Array(items) => Some(items.len()),
+        _ => None,
+    };
+    let is_expanded = children.is_some() && expanded.contains(&pointer);
+    rows.push(TreeRow {
+        name,
+        path: path.clone().into(),
+        pointer: pointer.clone().into(),
+        depth,
+        kind: value_kind(value),
+        children,
+        preview: value_preview(value),
+        expanded: is_expanded,
+    });
+    if !is_expanded {
+        return;
+    }
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let escaped = key.replace('\\', "\\\\").replace('\'', "\\'");
+                let token = key.replace('~', "~0").replace('/', "~1");
+                push_rows(
+                    rows,
+                    expanded,
+                    child,
+                    key.clone().into(),
+                    format!("{path}['{escaped}']"),
+                    format!("{pointer}/{token}"),
+                    depth + 1,
+                );
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                push_rows(
+                    rows,
+                    expanded,
+                    child,
+                    format!("[{index}]").into(),
+                    format!("{path}[{index}]"),
+                    format!("{pointer}/{index}"),
+                    depth + 1,
+                );
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collects the pointers of all objects and arrays under the value.
+fn collect_containers(value: &Value, pointer: String, pointers: &mut HashSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let token = key.replace('~', "~0").replace('/', "~1");
+                collect_containers(child, format!("{pointer}/{token}"), pointers);
+            }
+        }
+        Value::Array(items) => {
+            for (index, child) in items.iter().enumerate() {
+                collect_containers(child, format!("{pointer}/{index}"), pointers);
+            }
+        }
+        _ => return,
+    }
+    pointers.insert(pointer);
+}
+
+/// Converts a jq-style path such as `.items[0].id` to JSONPath.
+fn to_json_path(filter: &str) -> String {
+    match filter {
+        "." => "$".to_string(),
+        _ if filter.starts_with('.') => format!("${filter}"),
+        _ => filter.to_string(),
+    }
+}
+
+/// Virtualized rows of the document tree, only the expanded nodes are listed
+struct TreeListDelegate {
+    document: Arc<Value>,
+    roots: Vec<TreeRoot>,
+    /// Pointers of the expanded objects and arrays
+    expanded: HashSet<String>,
+    rows: Vec<TreeRow>,
+    selected_index: Option<IndexPath>,
+}
+
+impl TreeListDelegate {
+    fn new(document: Arc<Value>) -> Self {
+        let mut delegate = Self {
+            document,
+            roots: vec![],
+            expanded: HashSet::new(),
+            rows: vec![],
+            selected_index: None,
+        };
+        delegate.set_roots(vec![TreeRoot {
+            name: "$".into(),
+            path: "$".to_string(),
+            pointer: String::new(),
+        }]);
+        delegate
+    }
+    /// Shows the nodes at the top level, they are expanded.
+    fn set_roots(&mut self, roots: Vec<TreeRoot>) {
+        self.expanded = roots.iter().map(|root| root.pointer.clone()).collect();
+        self.roots = roots;
+        self.selected_index = None;
+        self.rebuild();
+    }
+    /// Applies a JSONPath or jq-style filter, an empty filter shows the whole document.
+    fn apply_filter(&mut self, filter: &str) -> Result<(), String> {
+        let filter = filter.trim();
+        if filter.is_empty() {
+            self.set_roots(vec![TreeRoot {
+                name: "$".into(),
+                path: "$".to_string(),
+                pointer: String::new(),
+            }]);
+            return Ok(());
+        }
+        let json_path = JsonPath::parse(&to_json_path(filter)).map_err(|e| e.to_string())?;
+        let roots = json_path
+            .query_located(&self.document)
+            .iter()
+            .take(FILTER_LIMIT)
+            .map(|node| {
+                let path = node.location().to_string();
+                TreeRoot {
+                    name: path.clone().into(),
+                    path,
+                    pointer: node.location().to_json_pointer(),
+                }
+            })
+            .collect();
+        self.set_roots(roots);
+        Ok(())
+    }
+    fn rebuild(&mut self) {
+        let mut rows = vec![];
+        for root in &self.roots {
+            if let Some(value) = self.document.pointer(&root.pointer) {
+                push_rows(
+                    &mut rows,
+                    &self.expanded,
+                    value,
+                    root.name.clone(),
+                    root.path.clone(),
+                    root.pointer.clone(),
+                    0,
+                );
+            }
+        }
+        self.rows = rows;
+    }
+    fn toggle(&mut self, pointer: &str) {
+        if !self.expanded.remove(pointer) {
+            self.expanded.insert(pointer.to_string());
+        }
+        self.rebuild();
+    }
+    fn expand_all(&mut self) {
+        for root in &self.roots {
+            if let Some(value) = self.document.pointer(&root.pointer) {
+                collect_containers(value, root.pointer.clone(), &mut self.expanded);
+            }
+        }
+        self.rebuild();
+    }
+    fn collapse_all(&mut self) {
+        self.expanded = self.roots.iter().map(|root| root.pointer.clone()).collect();
+        self.rebuild();
+    }
+    /// Returns the selected row, or the first row when nothing is selected.
+    fn selected_row(&self) -> Option<&TreeRow> {
+        let index = self.selected_index.map_or(0, |ix| ix.row);
+        self.rows.get(index)
+    }
+}
+
+impl ListDelegate for TreeListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self, _section: usize, _cx: &App) -> usize {
+        self.rows.len()
+    }
+
+    fn render_item(
+        &mut self,
+        ix: IndexPath,
+        _window: &mut Window,
+        cx: &mut Context<ListState<Self>>,
+    ) -> Option<Self::Item> {
+        let row = self.rows.get(ix.row)?;
+        let key_color = if cx.theme().is_dark() {
+            hsla(0.108, 0.66, 0.69, 1.0)
+        } else {
+            hsla(0.0892, 0.9462, 0.4373, 1.0)
+        };
+        let badge_color = match row.kind {
+            "object" | "array" => cx.theme().blue,
+            "string" => cx.theme().green,
+            "number" => cx.theme().yellow,
+            "boolean" => cx.theme().red,
+            _ => cx.theme().muted_foreground,
+        };
+        let locale = cx.global::<ZedisGlobalStore>().read(cx).locale();
+
+        let mut toggle = div().w(px(TREE_INDENT)).flex().items_center().justify_center();
+        if row.children.is_some() {
+            let icon = if row.expanded {
+                IconName::ChevronDown
+            } else {
+                IconName::ChevronRight
+            };
+            let pointer = row.pointer.clone();
+            toggle = toggle.cursor_pointer().child(Icon::new(icon).xsmall()).on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, _, _window, cx| {
+                    this.delegate_mut().toggle(&pointer);
+                    cx.notify();
+                }),
+            );
+        }
+        let detail = match (row.kind, row.children) {
+            ("object", Some(count)) => t!("tree_editor.keys", count = count, locale = locale)
+                .to_string()
+                .into(),
+            (_, Some(count)) => t!("tree_editor.items", count = count, locale = locale)
+                .to_string()
+                .into(),
+            (_, None) => row.preview.clone(),
+        };
+
+        Some(
+            ListItem::new(ix).py_0().px_2().child(
+                h_flex()
+                    .gap_2()
+                    .pl(px(TREE_INDENT * row.depth as f32))
+                    .child(toggle)
+                    .child(Label::new(row.name.clone()).text_color(key_color))
+                    .child(
+                        div()
+                            .px_1()
+                            .rounded_sm()
+                            .border_1()
+                            .border_color(badge_color)
+                            .text_xs()
+                            .text_color(badge_color)
+                            .child(row.kind),
+                    )
+                    .child(
+                        Label::new(detail)
+                            .text_ellipsis()
+                            .text_color(cx.theme().muted_foreground),
+                    ),
+            ),
+        )
+    }
+
+    fn set_selected_index(&mut self, ix: Option<IndexPath>, _window: &mut Window, _cx: &mut Context<ListState<Self>>) {
+        self.selected_index = ix;
+    }
+}
+
+/// Tree view of a structured string value.
+///
+/// Shows the filter toolbar above the virtualized tree of the decoded document.
+/// The document is decoded by the server state when the tree view mode is selected.
+pub struct ZedisTreeEditor {
+    /// Reference to server state for reading the decoded document
+    server_state: Entity<ZedisServerState>,
+    /// State of the tree, its delegate is replaced when the document changes
+    tree_state: Entity<ListState<TreeListDelegate>>,
+    /// Input for the JSONPath or jq-style filter
+    filter_state: Entity<InputState>,
+    /// Error of parsing the filter
+    filter_error: Option<SharedString>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisTreeEditor {
+    /// Creates a new tree view instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state of the value
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let delegate = TreeListDelegate::new(Self::current_document(&server_state, cx));
+        let tree_state = cx.new(|cx| ListState::new(delegate, window, cx));
+        let filter_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_tree_editor(cx, "filter_placeholder"))
+        });
+
+        let mut subscriptions = vec![];
+        subscriptions.push(cx.subscribe(&filter_state, |this, _, event, cx| {
+            if matches!(event, InputEvent::PressEnter { .. }) {
+                this.handle_filter(cx);
+            }
+        }));
+        subscriptions.push(cx.subscribe(&server_state, |this, _, event, cx| {
+            if matches!(
+                event,
+                ServerEvent::ValueLoaded | ServerEvent::ValueUpdated | ServerEvent::ValueModeViewUpdated
+            ) {
+                this.update_document(cx);
+            }
+        }));
+
+        Self {
+            server_state,
+            tree_state,
+            filter_state,
+            filter_error: None,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Returns the decoded document of the current value, an empty object when it is not loaded.
+    fn current_document(server_state: &Entity<ZedisServerState>, cx: &App) -> Arc<Value> {
+        server_state
+            .read(cx)
+            .value()
+            .and_then(|v| v.bytes_value())
+            .and_then(|v| v.document.clone())
+            .unwrap_or_else(|| Arc::new(Value::Object(Default::default())))
+    }
+
+    /// Replaces the tree when the document changes, the filter is applied to the new document.
+    fn update_document(&mut self, cx: &mut Context<Self>) {
+        let document = Self::current_document(&self.server_state, cx);
+        if Arc::ptr_eq(&document, &self.tree_state.read(cx).delegate().document) {
+            return;
+        }
+        let filter = self.filter_state.read(cx).value();
+        let mut delegate = TreeListDelegate::new(document);
+        self.filter_error = delegate.apply_filter(&filter).err().map(SharedString::from);
+        self.tree_state.update(cx, |state, cx| {
+            *state.delegate_mut() = delegate;
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Applies the filter of the input to the tree.
+    fn handle_filter(&mut self, cx: &mut Context<Self>) {
+        let filter = self.filter_state.read(cx).value();
+        let result = self.tree_state.update(cx, |state, cx| {
+            let result = state.delegate_mut().apply_filter(&filter);
+            cx.notify();
+            result
+        });
+        self.filter_error = result.err().map(SharedString::from);
+        cx.notify();
+    }
+
+    /// Expands or collapses all nodes of the tree.
+    fn handle_expand(&mut self, expand: bool, cx: &mut Context<Self>) {
+        self.tree_state.update(cx, |state, cx| {
+            let delegate = state.delegate_mut();
+            if expand {
+                delegate.expand_all();
+            } else {
+                delegate.collapse_all();
+            }
+            cx.notify();
+        });
+    }
+
+    /// Copies the path or the value of the selected node, strings are copied without quotes.
+    fn handle_copy(&mut self, copy_value: bool, cx: &mut Context<Self>) {
+        let delegate = self.tree_state.read(cx).delegate();
+        let Some(row) = delegate.selected_row() else {
+            return;
+        };
+        let content = if copy_value {
+            match delegate.document.pointer(&row.pointer) {
+                Some(Value::String(s)) => s.clone(),
+                Some(value) => serde_json::to_string_pretty(value).unwrap_or_default(),
+                None => return,
+            }
+        } else {
+            row.path.to_string()
+        };
+        cx.write_to_clipboard(ClipboardItem::new_string(content));
+        let message = if copy_value { "copied_value" } else { "copied_path" };
+        self.server_state.update(cx, |state, cx| {
+            state.emit_info_notification(i18n_tree_editor(cx, message), cx);
+        });
+    }
+
+    /// Renders the toolbar with the filter input, the expand actions and the copy actions.
+    fn render_toolbar(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let button = |id: &'static str, icon: IconName, label: &'static str| {
+            Button::new(id)
+                .outline()
+                .small()
+                .icon(icon)
+                .label(i18n_tree_editor(cx, label))
+                .tooltip(i18n_tree_editor(cx, &format!("{label}_tooltip")))
+        };
+
+        h_flex()
+            .p_2()
+            .gap_2()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .items_center()
+            .child(div().flex_1().child(Input::new(&self.filter_state).small()))
+            .child(
+                button("tree-editor-filter-btn", IconName::Search, "filter").on_click(cx.listener(
+                    |this, _, _window, cx| {
+                        this.handle_filter(cx);
+                    },
+                )),
+            )
+            .child(
+                button("tree-editor-expand-btn", IconName::ChevronDown, "expand_all").on_click(cx.listener(
+                    |this, _, _window, cx| {
+                        this.handle_expand(true, cx);
+                    },
+                )),
+            )
+            .child(
+                button("tree-editor-collapse-btn", IconName::ChevronRight, "collapse_all").on_click(cx.listener(
+                    |this, _, _window, cx| {
+                        this.handle_expand(false, cx);
+                    },
+                )),
+            )
+            .child(
+                button("tree-editor-copy-path-btn", IconName::Copy, "copy_path").on_click(cx.listener(
+                    |this, _, _window, cx| {
+                        this.handle_copy(false, cx);
+                    },
+                )),
+            )
+            .child(
+                button("tree-editor-copy-value-btn", IconName::Copy, "copy_value").on_click(cx.listener(
+                    |this, _, _window, cx| {
+                        this.handle_copy(true, cx);
+                    },
+                )),
+            )
+    }
+}
+
+impl Render for ZedisTreeEditor {
+    /// Renders the filter toolbar, the filter error and the tree.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let rows_count = self.tree_state.read(cx).delegate().rows.len();
+        let content = if rows_count == 0 {
+            div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(
+                    Label::new(i18n_tree_editor(cx, "no_match"))
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground),
+                )
+                .into_any_element()
+        } else {
+            List::new(&self.tree_state)
+                .font_family(get_font_family())
+                .into_any_element()
+        };
+
+        v_flex()
+            .size_full()
+            .child(self.render_toolbar(cx))
+            .when_some(self.filter_error.clone(), |this, error| {
+                this.child(
+                    h_flex()
+                        .px_2()
+                        .py_1()
+                        .border_b_1()
+                        .border_color(cx.theme().border)
+                        .child(Label::new(error).text_xs().text_color(cx.theme().red)),
+                )
+            })
+            .child(div().flex_1().h_0().w_full().child(content))
+            .into_any_element()
+    }
+}
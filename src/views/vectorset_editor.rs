@@ -0,0 +1,456 @@
+// Copyright 2026 Tree xie.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Redis Vector Set editor UI component.
+//!
+//! This module provides a browser for Redis Vector Set values. It supports:
+//! - Showing cardinality, dimension and VINFO metadata
+//! - Viewing elements with their attributes and embeddings in a table
+//! - Filtering elements by name prefix with incremental loading
+//! - Similarity search by element or by vector (VSIM) with COUNT/EF/FILTER options
+//! - Adding elements via a dialog form (VADD) and removing them (VREM)
+
+use crate::{
+    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    states::{RedisValue, ServerEvent, VectorsetQuery, ZedisServerState, i18n_vectorset_editor, parse_vector},
+    views::{KvTableColumn, ZedisKvTable},
+};
+use gpui::{App, Entity, SharedString, Subscription, Window, div, prelude::*, px};
+use gpui_component::{
+    ActiveTheme, Disableable, IconName, Sizable, WindowExt,
+    button::{Button, ButtonVariants},
+    h_flex,
+    input::{Input, InputEvent, InputState},
+    label::Label,
+    radio::RadioGroup,
+    scroll::ScrollableElement,
+    v_flex,
+};
+use std::rc::Rc;
+
+/// Maximum number of embedding values shown in the table
+const EMBEDDING_PREVIEW_SIZE: usize = 8;
+
+/// Default number of results of a similarity search
+const DEFAULT_SEARCH_COUNT: usize = 10;
+
+/// Formats an embedding as a short preview, e.g. `[0.1, 0.2, … (128)]`.
+fn format_embedding(embedding: &[f64]) -> SharedString {
+    if embedding.is_empty() {
+        return SharedString::default();
+    }
+    let preview: Vec<String> = embedding
+        .iter()
+        .take(EMBEDDING_PREVIEW_SIZE)
+        .map(|v| format!("{v:.4}"))
+        .collect();
+    if embedding.len() > EMBEDDING_PREVIEW_SIZE {
+        format!("[{}, … ({})]", preview.join(", "), embedding.len()).into()
+    } else {
+        format!("[{}]", preview.join(", ")).into()
+    }
+}
+
+/// Data adapter for Redis Vector Set values to work with the KV table component.
+///
+/// Column layout: element name, attributes and embedding preview.
+struct ZedisVectorsetValues {
+    /// Current Redis Vector Set value data
+    value: RedisValue,
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+}
+
+impl ZedisKvFetcher for ZedisVectorsetValues {
+    /// Creates a new data adapter instance.
+    fn new(server_state: Entity<ZedisServerState>, value: RedisValue) -> Self {
+        Self { server_state, value }
+    }
+
+    /// Retrieves a cell value for the table at the given row and column.
+    ///
+    /// Column layout:
+    /// - Column 1: Element name
+    /// - Column 2: Attributes (JSON)
+    /// - Column 3: Embedding preview
+    fn get(&self, row_ix: usize, col_ix: usize) -> Option<SharedString> {
+        let vectorset = self.value.vectorset_value()?;
+        let element = vectorset.values.get(row_ix)?;
+        match col_ix {
+            2 => Some(element.attributes.clone().unwrap_or_default()),
+            3 => Some(format_embedding(&element.embedding)),
+            _ => Some(element.name.clone()),
+        }
+    }
+
+    /// Returns the total number of elements (from Redis VCARD).
+    fn count(&self) -> usize {
+        self.value.vectorset_value().map_or(0, |v| v.size)
+    }
+
+    /// Returns the number of currently loaded elements.
+    fn rows_count(&self) -> usize {
+        self.value.vectorset_value().map_or(0, |v| v.values.len())
+    }
+
+    /// Checks if all elements have been loaded.
+    fn is_done(&self) -> bool {
+        self.value.vectorset_value().is_some_and(|v| v.done)
+    }
+
+    /// Triggers loading of the next page of elements.
+    fn load_more(&self, _window: &mut Window, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.load_more_vectorset_value(cx);
+        });
+    }
+
+    /// Removes the element at the given index via VREM.
+    fn remove(&self, index: usize, cx: &mut App) {
+        let Some(vectorset) = self.value.vectorset_value() else {
+            return;
+        };
+        let Some(element) = vectorset.values.get(index) else {
+            return;
+        };
+        let name = element.name.clone();
+
+        self.server_state.update(cx, |this, cx| {
+            this.remove_vectorset_value(name, cx);
+        });
+    }
+
+    /// Filters elements by name prefix.
+    fn filter(&self, keyword: SharedString, cx: &mut App) {
+        self.server_state.update(cx, |this, cx| {
+            this.filter_vectorset_value(keyword, cx);
+        });
+    }
+
+    /// Opens a dialog to add an element with its vector and attributes (VADD).
+    fn handle_add_value(&self, window: &mut Window, cx: &mut App) {
+        let server_state = self.server_state.clone();
+        let dim = self.value.vectorset_value().map_or(0, |v| v.dim);
+
+        let handle_submit = Rc::new(move |values: Vec<SharedString>, window: &mut Window, cx: &mut App| {
+            if values.len() != 3 || values[0].is_empty() {
+                return false;
+            }
+            // The vector dimension must match the dimension of the set
+            let Some(vector) = parse_vector(&values[1]).filter(|v| dim == 0 || v.len() == dim) else {
+                return false;
+            };
+            let attributes = values[2].trim();
+            if !attributes.is_empty() && serde_json::from_str::<serde_json::Value>(attributes).is_err() {
+                return false;
+            }
+            let attributes = (!attributes.is_empty()).then(|| SharedString::from(attributes.to_string()));
+
+            server_state.update(cx, |this, cx| {
+                this.add_vectorset_value(values[0].clone(), vector, attributes, cx);
+            });
+
+            window.close_dialog(cx);
+            true
+        });
+
+        let fields = vec![
+            FormField::new(i18n_vectorset_editor(cx, "element"))
+                .with_placeholder(i18n_vectorset_editor(cx, "element_placeholder"))
+                .with_focus(),
+            FormField::new(i18n_vectorset_editor(cx, "vector"))
+                .with_placeholder(format!("{} ({dim})", i18n_vectorset_editor(cx, "vector_placeholder")).into()),
+            FormField::new(i18n_vectorset_editor(cx, "attributes"))
+                .with_placeholder(i18n_vectorset_editor(cx, "attributes_placeholder")),
+        ];
+
+        open_add_form_dialog(
+            FormDialog {
+                title: i18n_vectorset_editor(cx, "add_value_title"),
+                fields,
+                handle_submit,
+            },
+            window,
+            cx,
+        );
+    }
+}
+
+/// Main Vector Set editor view component.
+///
+/// Shows the vector set metadata and a similarity search panel above
+/// the element table.
+pub struct ZedisVectorsetEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
+    /// The table component that renders the elements
+    table_state: Entity<ZedisKvTable<ZedisVectorsetValues>>,
+    /// Whether the similarity search queries by element (ELE) or by vector (VALUES)
+    query_by_element: bool,
+    /// Input for the query element or vector
+    query_state: Entity<InputState>,
+    /// Input for the COUNT option
+    count_state: Entity<InputState>,
+    /// Input for the EF option
+    ef_state: Entity<InputState>,
+    /// Input for the FILTER expression
+    filter_state: Entity<InputState>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
+}
+
+impl ZedisVectorsetEditor {
+    /// Creates a new Vector Set editor instance.
+    ///
+    /// # Arguments
+    /// * `server_state` - Reference to the server state for Redis operations
+    /// * `window` - GPUI window handle
+    /// * `cx` - GPUI context for component initialization
+    pub fn new(server_state: Entity<ZedisServerState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let window_width = window.viewport_size().width.to_f64();
+        let element_width = if window_width > 1400. { 0.2 } else { 0.3 };
+
+        let table_state = cx.new(|cx| {
+            ZedisKvTable::<ZedisVectorsetValues>::new(
+                vec![
+                    KvTableColumn::new("Element", Some(element_width)).with_readonly(true),
+                    KvTableColumn::new("Attributes", Some(0.3)).with_readonly(true),
+                    KvTableColumn::new("Embedding", None).with_readonly(true),
+                ],
+                server_state.clone(),
+                window,
+                cx,
+            )
+        });
+
+        let query_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_vectorset_editor(cx, "query_placeholder"))
+        });
+        let count_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .validate(|s, _| s.chars().all(|c| c.is_ascii_digit()))
+                .placeholder(format!("COUNT {DEFAULT_SEARCH_COUNT}"))
+        });
+        let ef_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .validate(|s, _| s.chars().all(|c| c.is_ascii_digit()))
+                .placeholder("EF")
+        });
+        let filter_state = cx.new(|cx| {
+            InputState::new(window, cx)
+                .clean_on_escape()
+                .placeholder(i18n_vectorset_editor(cx, "filter_placeholder"))
+        });
+
+        let mut subscriptions = vec![];
+        for state in [&query_state, &count_state, &ef_state, &filter_state] {
+            subscriptions.push(cx.subscribe(state, |this, _, event, cx| {
+                if matches!(event, InputEvent::PressEnter { .. }) {
+                    this.handle_search(cx);
+                }
+            }));
+        }
+        subscriptions.push(cx.subscribe(&server_state, |_this, _, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded | ServerEvent::ValueUpdated) {
+                cx.notify();
+            }
+        }));
+
+        Self {
+            server_state,
+            table_state,
+            query_by_element: true,
+            query_state,
+            count_state,
+            ef_state,
+            filter_state,
+            _subscriptions: subscriptions,
+        }
+    }
+
+    /// Runs the similarity search with the current query options.
+    fn handle_search(&mut self, cx: &mut Context<Self>) {
+        let query = self.query_state.read(cx).value();
+        if query.trim().is_empty() {
+            return;
+        }
+        let count = self
+            .count_state
+            .read(cx)
+            .value()
+            .parse::<usize>()
+            .ok()
+            .filter(|count| *count > 0)
+            .unwrap_or(DEFAULT_SEARCH_COUNT);
+        let ef = self.ef_state.read(cx).value().parse::<usize>().ok();
+        let filter = self.filter_state.read(cx).value();
+        let filter = if filter.trim().is_empty() { None } else { Some(filter) };
+
+        let query = VectorsetQuery {
+            by_element: self.query_by_element,
+            query,
+            count,
+            ef,
+            filter,
+        };
+        self.server_state.update(cx, |state, cx| {
+            state.search_vectorset_value(query, cx);
+        });
+    }
+
+    /// Renders VCARD, VDIM and VINFO metadata.
+    fn render_info(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let muted_color = cx.theme().muted_foreground;
+        let Some(vectorset) = self.server_state.read(cx).value().and_then(|v| v.vectorset_value()) else {
+            return h_flex();
+        };
+        let mut items = vec![
+            ("VCARD".to_string(), vectorset.size.to_string()),
+            ("VDIM".to_string(), vectorset.dim.to_string()),
+        ];
+        items.extend(
+            vectorset
+                .info
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string())),
+        );
+
+        h_flex()
+            .px_2()
+            .pt_2()
+            .gap_3()
+            .flex_wrap()
+            .children(items.into_iter().map(|(name, value)| {
+                h_flex()
+                    .gap_1()
+                    .child(Label::new(name).text_xs().text_color(muted_color))
+                    .child(Label::new(value).text_xs())
+            }))
+    }
+
+    /// Renders the similarity search panel and its ranked results.
+    fn render_query(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let server_state = self.server_state.read(cx);
+        let is_busy = server_state.value().is_some_and(|v| v.is_busy());
+        let similar = server_state
+            .value()
+            .and_then(|v| v.vectorset_value())
+            .and_then(|v| v.similar.clone());
+        let border_color = cx.theme().border;
+        let muted_color = cx.theme().muted_foreground;
+
+        let options = h_flex()
+            .gap_2()
+            .items_center()
+            .child(
+                RadioGroup::horizontal("vectorset-query-mode")
+                    .children(vec![
+                        i18n_vectorset_editor(cx, "by_element"),
+                        i18n_vectorset_editor(cx, "by_vector"),
+                    ])
+                    .selected_index(Some(if self.query_by_element { 0 } else { 1 }))
+                    .on_click(cx.listener(|this, index: &usize, _window, cx| {
+                        this.query_by_element = *index == 0;
+                        cx.notify();
+                    })),
+            )
+            .child(div().flex_1().child(Input::new(&self.query_state).small()))
+            .child(div().w(px(100.)).child(Input::new(&self.count_state).small()))
+            .child(div().w(px(80.)).child(Input::new(&self.ef_state).small()))
+            .child(div().w(px(220.)).child(Input::new(&self.filter_state).small()))
+            .child(
+                Button::new("vectorset-search-btn")
+                    .outline()
+                    .small()
+                    .icon(IconName::Search)
+                    .label(i18n_vectorset_editor(cx, "search"))
+                    .tooltip(i18n_vectorset_editor(cx, "search_tooltip"))
+                    .disabled(is_busy)
+                    .on_click(cx.listener(|this, _, _window, cx| {
+                        this.handle_search(cx);
+                    })),
+            )
+            .when(similar.is_some(), |this| {
+                this.child(
+                    Button::new("vectorset-search-clear-btn")
+                        .ghost()
+                        .small()
+                        .icon(IconName::CircleX)
+                        .tooltip(i18n_vectorset_editor(cx, "clear_results"))
+                        .on_click(cx.listener(|this, _, _window, cx| {
+                            this.server_state.update(cx, |state, cx| {
+                                state.clear_vectorset_search(cx);
+                            });
+                        })),
+                )
+            });
+
+        let results = similar.map(|similar| {
+            let is_empty = similar.is_empty();
+            let rows = similar.into_iter().enumerate().map(|(index, (name, score))| {
+                h_flex()
+                    .px_2()
+                    .py_1()
+                    .gap_2()
+                    .border_b_1()
+                    .border_color(border_color)
+                    .child(
+                        Label::new(format!("#{}", index + 1))
+                            .text_xs()
+                            .w(px(40.))
+                            .text_color(muted_color),
+                    )
+                    .child(Label::new(name).text_sm().flex_1().text_ellipsis())
+                    .child(Label::new(format!("{score:.6}")).text_sm())
+            });
+            v_flex()
+                .mt_2()
+                .max_h(px(200.))
+                .border_1()
+                .border_color(border_color)
+                .rounded_md()
+                .overflow_y_scrollbar()
+                .when(is_empty, |this| {
+                    this.child(
+                        Label::new(i18n_vectorset_editor(cx, "no_results"))
+                            .text_sm()
+                            .p_2()
+                            .text_color(muted_color),
+                    )
+                })
+                .children(rows)
+        });
+
+        v_flex()
+            .p_2()
+            .border_b_1()
+            .border_color(border_color)
+            .child(options)
+            .children(results)
+    }
+}
+
+impl Render for ZedisVectorsetEditor {
+    /// Renders the metadata, similarity search panel and element table.
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .child(self.render_info(cx))
+            .child(self.render_query(cx))
+            .child(v_flex().flex_1().h_0().child(self.table_state.clone()))
+            .into_any_element()
+    }
+}
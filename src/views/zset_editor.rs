@@ -22,16 +22,25 @@
 //! - Removing members
 //! - Filtering members with pattern matching
 //! - Incremental loading of large ZSETs with pagination
+//! - Decoding compressed and structured members
+//! - Inspecting geo indexes by position in a separate tab
 
 use crate::{
-    components::{FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
-    states::{RedisValue, ZedisServerState, i18n_common, i18n_zset_editor},
-    views::{KvTableColumn, ZedisKvTable},
+    components::{DecodeInput, FormDialog, FormField, ZedisKvFetcher, open_add_form_dialog},
+    states::{RedisValue, ServerEvent, ZedisServerState, i18n_common, i18n_zset_editor},
+    views::{KvTableColumn, ZedisGeoEditor, ZedisKvTable},
+};
+use gpui::{App, Entity, SharedString, Subscription, Window, prelude::*};
+use gpui_component::{
+    WindowExt,
+    tab::{Tab, TabBar},
+    v_flex,
 };
-use gpui::{App, Entity, SharedString, Window, div, prelude::*};
-use gpui_component::WindowExt;
 use std::rc::Rc;
 
+/// Index of the geo tab
+const GEO_TAB_INDEX: usize = 1;
+
 /// Data adapter for Redis ZSET values to work with the KV table component.
 ///
 /// This struct implements the `ZedisKvFetcher` trait to provide data access
@@ -57,10 +66,19 @@ impl ZedisKvFetcher for ZedisZsetValues {
         if col_ix == 2 {
             Some(score.to_string().into())
         } else {
-            Some(member.clone())
+            Some(member.display())
         }
     }
 
+    /// Returns the member to decode with the value decoders, scores are shown as is.
+    fn decode_input(&self, row_ix: usize, col_ix: usize, cx: &App) -> Option<DecodeInput> {
+        if col_ix == 2 {
+            return None;
+        }
+        let (member, _) = self.value.zset_value()?.values.get(row_ix)?;
+        DecodeInput::new(&self.server_state, None, member, cx)
+    }
+
     /// Returns the total cardinality of the ZSET (from Redis ZCARD).
     fn count(&self) -> usize {
         self.value.zset_value().map_or(0, |v| v.size)
@@ -185,9 +203,9 @@ impl ZedisKvFetcher for ZedisZsetValues {
     ///
     /// Called when the user edits the score column directly in the table.
     /// Updates the score for the existing member using Redis ZADD.
-    fn handle_update_value(&self, _row_ix: usize, values: Vec<SharedString>, _window: &mut Window, cx: &mut App) {
-        // Extract member name and new score from values
-        let Some(member) = values.first() else {
+    fn handle_update_value(&self, row_ix: usize, values: Vec<SharedString>, _window: &mut Window, cx: &mut App) {
+        // Use the original member bytes of the row, the displayed name may be escaped
+        let Some((member, _score)) = self.value.zset_value().and_then(|zset| zset.values.get(row_ix)) else {
             return;
         };
         let Some(score_str) = values.get(1) else {
@@ -196,8 +214,9 @@ impl ZedisKvFetcher for ZedisZsetValues {
 
         // Parse score and execute update operation
         let score = score_str.parse::<f64>().unwrap_or(0.0);
+        let member = member.clone();
         self.server_state.update(cx, |state, cx| {
-            state.update_zset_value(member.clone(), score, cx);
+            state.update_zset_value(member, score, cx);
         });
     }
 
@@ -211,10 +230,19 @@ impl ZedisKvFetcher for ZedisZsetValues {
 ///
 /// Provides a table-based UI for viewing and managing Redis ZSET values.
 /// Wraps the generic `ZedisKvTable` component with ZSET-specific configuration
-/// including two columns (member name and score).
+/// including two columns (member name and score). Geo indexes are shown by
+/// position in a second tab.
 pub struct ZedisZsetEditor {
+    /// Reference to server state for executing Redis operations
+    server_state: Entity<ZedisServerState>,
     /// The table component that renders the ZSET members and scores
     table_state: Entity<ZedisKvTable<ZedisZsetValues>>,
+    /// Currently selected tab (members or geo)
+    selected_tab: usize,
+    /// Geo tab, created when first opened
+    geo_editor: Option<Entity<ZedisGeoEditor>>,
+    /// Event subscriptions for reactive updates
+    _subscriptions: Vec<Subscription>,
 }
 
 impl ZedisZsetEditor {
@@ -235,19 +263,69 @@ impl ZedisZsetEditor {
                     KvTableColumn::new("Value", None),       // Member name column (flexible width)
                     KvTableColumn::new("Score", Some(150.)), // Score column (fixed 150px width)
                 ],
-                server_state,
+                server_state.clone(),
                 window,
                 cx,
             )
         });
 
-        Self { table_state }
+        // Decode the positions of the newly loaded members on the geo tab
+        let subscriptions = vec![cx.subscribe(&server_state, |this, server_state, event, cx| {
+            if matches!(event, ServerEvent::ValueLoaded | ServerEvent::ValuePaginationFinished)
+                && this.selected_tab == GEO_TAB_INDEX
+            {
+                server_state.update(cx, |state, cx| {
+                    state.load_geo_positions(cx);
+                });
+            }
+        })];
+
+        Self {
+            server_state,
+            table_state,
+            selected_tab: 0,
+            geo_editor: None,
+            _subscriptions: subscriptions,
+        }
     }
 }
 
 impl Render for ZedisZsetEditor {
-    /// Renders the ZSET editor as a full-size container with the table.
-    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        div().size_full().child(self.table_state.clone()).into_any_element()
+    /// Renders the ZSET editor with the tab bar above the members table or the geo view.
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let tab_bar = TabBar::new("zset-editor-tabs")
+            .underline()
+            .px_2()
+            .selected_index(self.selected_tab)
+            .on_click(cx.listener(|this, selected_index: &usize, _window, cx| {
+                this.selected_tab = *selected_index;
+                // Decode the positions every time the tab is opened
+                if this.selected_tab == GEO_TAB_INDEX {
+                    this.server_state.update(cx, |state, cx| {
+                        state.load_geo_positions(cx);
+                    });
+                }
+                cx.notify();
+            }))
+            .child(Tab::new().label(i18n_zset_editor(cx, "members_tab")).p_1())
+            .child(Tab::new().label(i18n_zset_editor(cx, "geo_tab")).p_1());
+
+        if self.selected_tab == GEO_TAB_INDEX {
+            let server_state = self.server_state.clone();
+            let geo_editor = self
+                .geo_editor
+                .get_or_insert_with(|| cx.new(|cx| ZedisGeoEditor::new(server_state, window, cx)));
+            return v_flex()
+                .size_full()
+                .child(tab_bar)
+                .child(v_flex().flex_1().h_0().child(geo_editor.clone()))
+                .into_any_element();
+        }
+
+        v_flex()
+            .size_full()
+            .child(tab_bar)
+            .child(v_flex().flex_1().h_0().child(self.table_state.clone()))
+            .into_any_element()
     }
 }